@@ -24,7 +24,9 @@ pub struct Cli {
     #[command(flatten)]
     pub run: sc_cli::RunCmd,
 
-    /// Defines the RSA key path.
+    /// Path to the weight decryption key used until the node rotates to a key in its keystore, a
+    /// PKCS#1 PEM RSA key, a hex encoded X25519 secret or a committee key share. Rotate keys with
+    /// the `author_rotateDecryptionKey` RPC.
     #[arg(long, value_name = "PATH")]
     pub rsa_path: Option<PathBuf>,
 
@@ -32,6 +34,11 @@ pub struct Cli {
     #[arg(long, value_enum, ignore_case = true)]
     pub sealing: Option<Sealing>,
 
+    /// Index per-module epoch results of finalized blocks and serve them
+    /// through the `subspace_getModuleHistory` RPC.
+    #[arg(long)]
+    pub module_history: bool,
+
     #[cfg(feature = "testnet")]
     #[command(flatten)]
     pub eth: EthConfiguration,
@@ -0,0 +1,51 @@
+//! RPC managing the weight decryption keys of the node.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::{ClientError as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::ErrorObject,
+    Extensions,
+};
+use sp_core::Bytes;
+
+use crate::service::decrypter::DecryptionKeyring;
+
+#[rpc(client, server)]
+pub trait DecryptionKeysApi {
+    /// Generates a new decryption key and returns its public key. The key is announced to the
+    /// chain with the next keep alive, the previous key is kept until the weights encrypted to it
+    /// are decrypted.
+    #[method(name = "author_rotateDecryptionKey", with_extensions)]
+    fn rotate_decryption_key(&self) -> RpcResult<(Bytes, Bytes)>;
+}
+
+pub struct DecryptionKeys {
+    keyring: Arc<DecryptionKeyring>,
+}
+
+impl DecryptionKeys {
+    pub fn new(keyring: Arc<DecryptionKeyring>) -> Self {
+        Self { keyring }
+    }
+}
+
+impl DecryptionKeysApiServer for DecryptionKeys {
+    fn rotate_decryption_key(&self, ext: &Extensions) -> RpcResult<(Bytes, Bytes)> {
+        sc_rpc_api::check_if_safe(ext)?;
+
+        let (tag, public) = self.keyring.rotate().map_err(rotation_error)?;
+        Ok((tag.into(), public.into()))
+    }
+}
+
+const ROTATION_ERROR: i32 = 1;
+
+fn rotation_error(reason: String) -> JsonRpseeError {
+    JsonRpseeError::Call(ErrorObject::owned(
+        ROTATION_ERROR,
+        "Failed to rotate decryption key",
+        Some(reason),
+    ))
+}
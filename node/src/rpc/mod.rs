@@ -26,8 +26,12 @@ use sp_runtime::traits::Block as BlockT;
 // Runtime
 use node_subspace_runtime::{opaque::Block, AccountId, Balance, Hash, Nonce};
 
+use crate::service::decrypter::DecryptionKeyring;
+
+mod decryption_keys;
 #[cfg(feature = "testnet")]
 mod eth;
+mod module_history;
 #[cfg(feature = "testnet")]
 pub use self::eth::{create_eth, EthDeps};
 
@@ -40,6 +44,10 @@ pub struct FullDeps<C, P, A: ChainApi, CT, CIDP> {
     pub pool: Arc<P>,
     /// Manual seal command sink
     pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
+    /// Whether the module history indexer is running
+    pub module_history: bool,
+    /// Weight decryption keys of the node
+    pub decryption_keys: Arc<DecryptionKeyring>,
     /// Ethereum-compatibility specific dependencies.
     pub eth: EthDeps<Block, C, P, A, CT, CIDP>,
 }
@@ -54,6 +62,10 @@ pub struct FullDeps<C, P> {
     /// Whether to deny unsafe calls
     /// Manual seal command sink
     pub command_sink: Option<mpsc::Sender<EngineCommand<Hash>>>,
+    /// Whether the module history indexer is running
+    pub module_history: bool,
+    /// Weight decryption keys of the node
+    pub decryption_keys: Arc<DecryptionKeyring>,
 }
 
 #[cfg(feature = "testnet")]
@@ -92,6 +104,7 @@ where
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
     C: BlockchainEvents<Block> + AuxStore + UsageProvider<Block> + StorageProvider<Block, BE>,
     C::Api: subspace_rpc::SubspaceRuntimeApi<Block>,
+    C::Api: governance_rpc::GovernanceRuntimeApi<Block>,
     BE: Backend<Block> + 'static,
     P: TransactionPool<Block = Block> + 'static,
     A: ChainApi<Block = Block> + 'static,
@@ -100,6 +113,9 @@ where
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
+    use self::decryption_keys::{DecryptionKeys, DecryptionKeysApiServer};
+    use self::module_history::{ModuleHistory, ModuleHistoryApiServer};
+    use governance_rpc::{GovernanceApiServer, GovernancePallet};
     use subspace_rpc::{SubspaceApiServer, SubspacePallet};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -108,12 +124,19 @@ where
         client,
         pool,
         command_sink,
+        module_history,
+        decryption_keys,
         eth,
     } = deps;
 
     io.merge(System::new(client.clone(), pool).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
-    io.merge(SubspacePallet::new(client).into_rpc())?;
+    if module_history {
+        io.merge(ModuleHistory::new(client.clone()).into_rpc())?;
+    }
+    io.merge(SubspacePallet::new(client.clone()).into_rpc())?;
+    io.merge(GovernancePallet::new(client).into_rpc())?;
+    io.merge(DecryptionKeys::new(decryption_keys).into_rpc())?;
 
     if let Some(command_sink) = command_sink {
         io.merge(
@@ -147,11 +170,15 @@ where
     C: HeaderBackend<Block> + HeaderMetadata<Block, Error = BlockChainError> + 'static,
     C: BlockchainEvents<Block> + AuxStore + UsageProvider<Block> + StorageProvider<Block, BE>,
     C::Api: subspace_rpc::SubspaceRuntimeApi<Block>,
+    C::Api: governance_rpc::GovernanceRuntimeApi<Block>,
     BE: Backend<Block> + 'static,
     P: TransactionPool<Block = Block> + 'static,
 {
     use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
     use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
+    use self::decryption_keys::{DecryptionKeys, DecryptionKeysApiServer};
+    use self::module_history::{ModuleHistory, ModuleHistoryApiServer};
+    use governance_rpc::{GovernanceApiServer, GovernancePallet};
     use subspace_rpc::{SubspaceApiServer, SubspacePallet};
     use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -160,11 +187,18 @@ where
         client,
         pool,
         command_sink,
+        module_history,
+        decryption_keys,
     } = deps;
 
     io.merge(System::new(client.clone(), pool).into_rpc())?;
     io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
-    io.merge(SubspacePallet::new(client).into_rpc())?;
+    if module_history {
+        io.merge(ModuleHistory::new(client.clone()).into_rpc())?;
+    }
+    io.merge(SubspacePallet::new(client.clone()).into_rpc())?;
+    io.merge(GovernancePallet::new(client).into_rpc())?;
+    io.merge(DecryptionKeys::new(decryption_keys).into_rpc())?;
 
     if let Some(command_sink) = command_sink {
         io.merge(
@@ -0,0 +1,92 @@
+//! RPC serving the records collected by the module history indexer.
+
+use std::sync::Arc;
+
+use jsonrpsee::{
+    core::{ClientError as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::ErrorObject,
+};
+use node_subspace_runtime::{AccountId, BlockNumber};
+use sc_client_api::AuxStore;
+
+use crate::service::module_history::{bucket_of, read_bucket, ModuleEpochRecord};
+
+/// Maximum amount of buckets a single request may span, around 90 days.
+const MAX_BUCKETS_PER_REQUEST: BlockNumber = 90;
+
+#[rpc(client, server)]
+pub trait ModuleHistoryApi {
+    #[method(name = "subspace_getModuleHistory")]
+    fn get_module_history(
+        &self,
+        key: AccountId,
+        netuid: u16,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> RpcResult<Vec<ModuleEpochRecord>>;
+}
+
+pub struct ModuleHistory<C> {
+    client: Arc<C>,
+}
+
+impl<C> ModuleHistory<C> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self { client }
+    }
+}
+
+impl<C> ModuleHistoryApiServer for ModuleHistory<C>
+where
+    C: AuxStore + Send + Sync + 'static,
+{
+    fn get_module_history(
+        &self,
+        key: AccountId,
+        netuid: u16,
+        from_block: BlockNumber,
+        to_block: BlockNumber,
+    ) -> RpcResult<Vec<ModuleEpochRecord>> {
+        if from_block > to_block {
+            return Err(invalid_params("`from_block` is greater than `to_block`"));
+        }
+
+        let (first, last) = (bucket_of(from_block), bucket_of(to_block));
+        if last.saturating_sub(first) >= MAX_BUCKETS_PER_REQUEST {
+            return Err(invalid_params("requested block range is too large"));
+        }
+
+        let mut history = Vec::new();
+        for bucket in first..=last {
+            let records =
+                read_bucket(&*self.client, netuid, &key, bucket).map_err(storage_error)?;
+            history.extend(
+                records
+                    .into_iter()
+                    .filter(|record| (from_block..=to_block).contains(&record.block)),
+            );
+        }
+
+        Ok(history)
+    }
+}
+
+const INVALID_PARAMS: i32 = 1;
+const STORAGE_ERROR: i32 = 2;
+
+fn invalid_params(reason: &str) -> JsonRpseeError {
+    JsonRpseeError::Call(ErrorObject::owned(
+        INVALID_PARAMS,
+        "Invalid params",
+        Some(reason.to_string()),
+    ))
+}
+
+fn storage_error(err: sp_blockchain::Error) -> JsonRpseeError {
+    JsonRpseeError::Call(ErrorObject::owned(
+        STORAGE_ERROR,
+        "Storage error",
+        Some(err.to_string()),
+    ))
+}
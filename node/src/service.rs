@@ -31,8 +31,10 @@ pub use crate::eth::{
     FrontierBackend, FrontierPartialComponents, StorageOverride, StorageOverrideHandler,
 };
 
-mod decrypter;
+pub mod decrypter;
 mod manual_seal;
+pub mod module_history;
+pub mod offworker_metrics;
 
 type BasicImportQueue = sc_consensus::DefaultImportQueue<Block>;
 type FullPool = sc_transaction_pool::FullPool<Block, Client>;
@@ -344,6 +346,7 @@ pub async fn new_full<N>(
 
     sealing: Option<Sealing>,
     rsa_key: Option<PathBuf>,
+    module_history: bool,
 ) -> Result<TaskManager, ServiceError>
 where
     N: sc_network::NetworkBackend<Block, <Block as BlockT>::Hash>,
@@ -370,6 +373,9 @@ where
         build_import_queue,
     )?;
 
+    let decryption_keys =
+        decrypter::DecryptionKeyring::load(keystore_container.keystore(), client.clone(), rsa_key);
+
     #[cfg(feature = "testnet")]
     let FrontierPartialComponents {
         filter_pool,
@@ -426,6 +432,16 @@ where
         })?;
 
     if other.config.offchain_worker.enabled {
+        let decryption_keys = decryption_keys.clone();
+        let offworker_metrics = other.config.prometheus_registry().and_then(|registry| {
+            match offworker_metrics::OffworkerMetrics::register(registry) {
+                Ok(metrics) => Some(metrics),
+                Err(err) => {
+                    log::warn!("Failed to register offworker metrics: {err}");
+                    None
+                }
+            }
+        });
         task_manager.spawn_handle().spawn(
             "offchain-workers-runner",
             "offchain-worker",
@@ -442,7 +458,8 @@ where
                 custom_extensions: move |_| {
                     vec![
                         Box::new(ow_extensions::OffworkerExt::new(decrypter::Decrypter::new(
-                            rsa_key.clone(),
+                            decryption_keys.clone(),
+                            offworker_metrics.clone(),
                         ))) as Box<_>,
                     ]
                 },
@@ -478,6 +495,7 @@ where
     let rpc_builder = {
         let client = client.clone();
         let pool = transaction_pool.clone();
+        let decryption_keys = decryption_keys.clone();
         #[cfg(feature = "testnet")]
         let network = network.clone();
         #[cfg(feature = "testnet")]
@@ -581,6 +599,8 @@ where
                     } else {
                         None
                     },
+                    module_history,
+                    decryption_keys: decryption_keys.clone(),
                     #[cfg(feature = "testnet")]
                     eth: eth_deps,
                 };
@@ -612,6 +632,14 @@ where
         telemetry: other.telemetry.as_mut(),
     })?;
 
+    if module_history {
+        task_manager.spawn_handle().spawn(
+            "module-history-indexer",
+            None,
+            module_history::run_indexer(client.clone()),
+        );
+    }
+
     #[cfg(feature = "testnet")]
     spawn_frontier_tasks(
         &task_manager,
@@ -774,6 +802,7 @@ pub async fn build_full(
 
     sealing: Option<Sealing>,
     rsa_key: Option<PathBuf>,
+    module_history: bool,
 ) -> Result<TaskManager, ServiceError> {
     new_full::<sc_network::NetworkWorker<_, _>>(
         config,
@@ -781,6 +810,7 @@ pub async fn build_full(
         eth_config,
         sealing,
         rsa_key,
+        module_history,
     )
     .await
 }
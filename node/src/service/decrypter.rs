@@ -1,102 +1,293 @@
 use std::{
-    io::{Cursor, Read},
+    fs,
     path::{Path, PathBuf},
+    sync::{Arc, PoisonError, RwLock},
+    time::Instant,
 };
 
-use rsa::{pkcs1::DecodeRsaPrivateKey, traits::PublicKeyParts, Pkcs1v15Encrypt};
+use ow_extensions::{
+    envelope::{self, DecryptionKey},
+    EncryptionKey, OffworkerMetric,
+};
+use sc_client_api::AuxStore;
+use scale_codec::{Decode, Encode};
+use sp_core::{crypto::KeyTypeId, ed25519, hashing::blake2_256};
+use sp_keystore::KeystorePtr;
 
-pub struct Decrypter {
-    key: Option<rsa::RsaPrivateKey>,
+use super::offworker_metrics::OffworkerMetrics;
+
+/// Key type the weight decryption keys are stored under in the node keystore.
+pub const DECRYPTION_KEY_TYPE: KeyTypeId = KeyTypeId(*b"wdec");
+
+/// Aux entry holding the [`KeyEntry`]s of the keyring, oldest first.
+const KEY_ORDER_KEY: &[u8] = b"subspace_decryption_keys";
+
+/// Message signed with a keystore key to derive the X25519 secret of the decryption key.
+const DERIVATION_CONTEXT: &[u8] = b"subspace weight decryption key";
+
+/// Where the keyring records which keys it holds, in order. On a full node this is the client's
+/// aux storage.
+pub trait KeyOrderStore: Send + Sync {
+    fn get_order(&self) -> Option<Vec<u8>>;
+
+    fn set_order(&self, order: &[u8]) -> Result<(), String>;
 }
 
-impl Decrypter {
-    pub fn new(rsa_key_path: Option<PathBuf>) -> Self {
-        let decryption_key_path = if let Some(rsa_key_path) = rsa_key_path {
-            rsa_key_path
-        } else {
-            Path::new("decryption.pem").to_path_buf()
+impl<C: AuxStore + Send + Sync> KeyOrderStore for C {
+    fn get_order(&self) -> Option<Vec<u8>> {
+        self.get_aux(KEY_ORDER_KEY).ok().flatten()
+    }
+
+    fn set_order(&self, order: &[u8]) -> Result<(), String> {
+        self.insert_aux(&[(KEY_ORDER_KEY, order)], &[]).map_err(|err| err.to_string())
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode)]
+enum KeyEntry {
+    /// An ed25519 key of [`DECRYPTION_KEY_TYPE`] in the keystore, the decryption key is derived
+    /// from its signature of [`DERIVATION_CONTEXT`]
+    Keystore(ed25519::Public),
+    /// The key at `--rsa-path`, by the hash of its encryption key
+    Legacy([u8; 32]),
+}
+
+struct StoredKey {
+    entry: KeyEntry,
+    key: DecryptionKey,
+}
+
+/// Weight decryption keys of the node, oldest first.
+///
+/// The newest key is the one announced to the chain. Older keys stay around after a rotation
+/// until the runtime reports that no weights encrypted to them are left, each one is retired on
+/// its own.
+///
+/// Rotated keys are ed25519 keys generated in the node keystore under [`DECRYPTION_KEY_TYPE`].
+/// Ed25519 signatures are deterministic, so the X25519 secret of a decryption key is derived from
+/// the keystore key's signature of a fixed message and never leaves the node otherwise. The
+/// keystore neither orders its keys nor removes them, so the keys in use are listed in the aux
+/// storage, and a retired key only leaves that list.
+pub struct DecryptionKeyring {
+    keystore: KeystorePtr,
+    order: Arc<dyn KeyOrderStore>,
+    keys: RwLock<Vec<StoredKey>>,
+}
+
+impl DecryptionKeyring {
+    /// Loads the decryption keys listed in the aux storage from the keystore.
+    ///
+    /// The key at `legacy_path` (`decryption.pem` by default), a PKCS#1 PEM RSA key, a hex encoded
+    /// X25519 secret or a committee key share, can not be kept in the keystore. It is read from
+    /// its file as long as it is not retired, and is the only key of a node that never rotated.
+    pub fn load(
+        keystore: KeystorePtr,
+        order: Arc<dyn KeyOrderStore>,
+        legacy_path: Option<PathBuf>,
+    ) -> Arc<Self> {
+        let keyring = Self {
+            keystore,
+            order,
+            keys: Default::default(),
         };
 
-        if !decryption_key_path.exists() {
+        let entries = keyring
+            .order
+            .get_order()
+            .and_then(|order| Vec::<KeyEntry>::decode(&mut &order[..]).ok());
+        let legacy = read_legacy(legacy_path);
+
+        let keys: Vec<StoredKey> = match entries {
+            Some(entries) => entries
+                .into_iter()
+                .filter_map(|entry| {
+                    let key = match &entry {
+                        KeyEntry::Keystore(public) => keyring.derive(public),
+                        KeyEntry::Legacy(hash) => legacy
+                            .clone()
+                            .filter(|key| &encryption_key_hash(&key.public_key()) == hash),
+                    };
+                    if key.is_none() {
+                        eprintln!("failed to load decryption key {}", entry.name());
+                    }
+                    key.map(|key| StoredKey { entry, key })
+                })
+                .collect(),
+            None => legacy
+                .map(|key| StoredKey {
+                    entry: KeyEntry::Legacy(encryption_key_hash(&key.public_key())),
+                    key,
+                })
+                .into_iter()
+                .collect(),
+        };
+        *keyring.keys.write().unwrap_or_else(PoisonError::into_inner) = keys;
+
+        if keyring.is_empty() {
             eprintln!("node does not have a decryption key configured");
-            return Self { key: None };
+        } else {
+            eprintln!("node started with decryption key configured");
         }
 
-        let Ok(content) = std::fs::read_to_string(decryption_key_path) else {
-            eprintln!("node does not have a decryption key configured");
-            return Self { key: None };
-        };
+        Arc::new(keyring)
+    }
 
-        match rsa::RsaPrivateKey::from_pkcs1_pem(&content) {
-            Ok(key) => {
-                eprintln!("node started with decryption key configured");
-                Self { key: Some(key) }
-            }
-            Err(err) => {
-                eprintln!("failed to load decryption key for node: {err:?}");
-                Self { key: None }
+    fn is_empty(&self) -> bool {
+        self.keys.read().unwrap_or_else(PoisonError::into_inner).is_empty()
+    }
+
+    /// Generates a new key to announce to the chain, keeping the current one until it is retired.
+    pub fn rotate(&self) -> Result<EncryptionKey, String> {
+        if matches!(self.current(), Some(DecryptionKey::ThresholdShare(_))) {
+            return Err("committee key shares can not be rotated by a single member".to_string());
+        }
+
+        let public = self
+            .keystore
+            .ed25519_generate_new(DECRYPTION_KEY_TYPE, None)
+            .map_err(|err| format!("failed to generate a key in the keystore: {err}"))?;
+        let key = self.derive(&public).ok_or("failed to derive the decryption key")?;
+        let public_key = key.public_key();
+
+        let mut keys = self.keys.write().unwrap_or_else(PoisonError::into_inner);
+        keys.push(StoredKey {
+            entry: KeyEntry::Keystore(public),
+            key,
+        });
+        if let Err(err) = self.store_order(&keys) {
+            keys.pop();
+            return Err(err);
+        }
+
+        Ok(public_key)
+    }
+
+    /// Drops every key but the current one and the `retained` ones, which still have weights
+    /// encrypted to them.
+    pub fn retire(&self, retained: &[EncryptionKey]) {
+        let mut keys = self.keys.write().unwrap_or_else(PoisonError::into_inner);
+        let current = keys.len().saturating_sub(1);
+
+        let mut retired = Vec::new();
+        let mut position = 0usize;
+        keys.retain(|stored| {
+            let keep = position >= current || retained.contains(&stored.key.public_key());
+            position = position.saturating_add(1);
+            if !keep {
+                retired.push(stored.entry.name());
             }
+            keep
+        });
+        if retired.is_empty() {
+            return;
+        }
+
+        if let Err(err) = self.store_order(&keys) {
+            eprintln!("failed to store the retired decryption keys: {err}");
+        }
+        for name in retired {
+            eprintln!("retired decryption key {name}");
+        }
+    }
+
+    pub fn current(&self) -> Option<DecryptionKey> {
+        let keys = self.keys.read().unwrap_or_else(PoisonError::into_inner);
+        keys.last().map(|stored| stored.key.clone())
+    }
+
+    fn derive(&self, public: &ed25519::Public) -> Option<DecryptionKey> {
+        let signature = self
+            .keystore
+            .ed25519_sign(DECRYPTION_KEY_TYPE, public, DERIVATION_CONTEXT)
+            .ok()??;
+        Some(DecryptionKey::X25519(blake2_256(signature.as_ref()).into()))
+    }
+
+    fn store_order(&self, keys: &[StoredKey]) -> Result<(), String> {
+        let entries: Vec<&KeyEntry> = keys.iter().map(|stored| &stored.entry).collect();
+        self.order
+            .set_order(&entries.encode())
+            .map_err(|err| format!("failed to store the decryption key order: {err}"))
+    }
+}
+
+impl KeyEntry {
+    fn name(&self) -> String {
+        match self {
+            Self::Keystore(public) => hex::encode(public),
+            Self::Legacy(hash) => format!("legacy-{}", hex::encode(hash)),
         }
     }
 }
 
-impl ow_extensions::OffworkerExtension for Decrypter {
-    fn decrypt_weight(&self, encrypted: Vec<u8>) -> Option<(Vec<(u16, u16)>, Vec<u8>)> {
-        let Some(key) = &self.key else {
-            return None;
-        };
+fn encryption_key_hash((tag, public): &EncryptionKey) -> [u8; 32] {
+    blake2_256(&[tag.as_slice(), public.as_slice()].concat())
+}
 
-        let vec = encrypted
-            .chunks(key.size())
-            .map(|chunk| key.decrypt(Pkcs1v15Encrypt, chunk))
-            .collect::<Result<Vec<Vec<u8>>, _>>()
-            .ok()?;
+fn read_legacy(legacy_path: Option<PathBuf>) -> Option<DecryptionKey> {
+    let path = legacy_path.unwrap_or_else(|| Path::new("decryption.pem").to_path_buf());
+    let content = fs::read_to_string(&path).ok()?;
 
-        let decrypted = vec.into_iter().flatten().collect::<Vec<_>>();
+    let key = DecryptionKey::parse(&content);
+    if key.is_none() {
+        eprintln!("failed to load decryption key for node");
+    }
+    key
+}
 
-        let mut cursor = Cursor::new(&decrypted);
-        let length = read_u32(&mut cursor)?;
+pub struct Decrypter {
+    keyring: Arc<DecryptionKeyring>,
+    metrics: Option<OffworkerMetrics>,
+}
 
-        let mut res = Vec::with_capacity(length as usize);
-        for _ in 0..length {
-            let uid = read_u16(&mut cursor)?;
-            let weight = read_u16(&mut cursor)?;
+impl Decrypter {
+    pub fn new(keyring: Arc<DecryptionKeyring>, metrics: Option<OffworkerMetrics>) -> Self {
+        Self { keyring, metrics }
+    }
+}
 
-            res.push((uid, weight));
-        }
+impl ow_extensions::OffworkerExtension for Decrypter {
+    fn decrypt_weight(&self, encrypted: Vec<u8>) -> Option<(Vec<(u16, u16)>, Vec<u8>)> {
+        let started = Instant::now();
+        let keys = self.keyring.keys.read().unwrap_or_else(PoisonError::into_inner);
 
-        let mut key = Vec::new();
-        cursor.read_to_end(&mut key).ok()?;
+        // weights submitted before a rotation are still encrypted to the previous keys
+        let decrypted = keys
+            .iter()
+            .rev()
+            .find_map(|stored| stored.key.open(&encrypted).ok())
+            .and_then(|decrypted| envelope::decode_weights(&decrypted));
 
-        Some((res, key))
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_decryption(started.elapsed(), decrypted.is_some());
+        }
+
+        decrypted
     }
 
     fn is_decryption_node(&self) -> bool {
-        self.key.is_some()
+        !self.keyring.is_empty()
     }
 
     fn get_encryption_key(&self) -> Option<(Vec<u8>, Vec<u8>)> {
-        let Some(key) = &self.key else {
+        self.keyring.current().as_ref().map(DecryptionKey::public_key)
+    }
+
+    fn decryption_share(&self, encrypted: Vec<u8>) -> Option<Vec<u8>> {
+        let Some(DecryptionKey::ThresholdShare(share)) = self.keyring.current() else {
             return None;
         };
 
-        let public = rsa::RsaPublicKey::from(key);
-        Some((public.n().to_bytes_be(), public.e().to_bytes_be()))
+        share.decryption_share(&encrypted).ok()
     }
-}
 
-fn read_u32(cursor: &mut Cursor<&Vec<u8>>) -> Option<u32> {
-    let mut buf: [u8; 4] = [0u8; 4];
-    match cursor.read_exact(&mut buf[..]) {
-        Ok(()) => Some(u32::from_be_bytes(buf)),
-        Err(_) => None,
+    fn retire_decryption_keys(&self, retained: Vec<EncryptionKey>) {
+        self.keyring.retire(&retained);
     }
-}
 
-fn read_u16(cursor: &mut Cursor<&Vec<u8>>) -> Option<u16> {
-    let mut buf = [0u8; 2];
-    match cursor.read_exact(&mut buf[..]) {
-        Ok(()) => Some(u16::from_be_bytes(buf)),
-        Err(_) => None,
+    fn report_metric(&self, metric: OffworkerMetric) {
+        if let Some(metrics) = &self.metrics {
+            metrics.report(metric);
+        }
     }
 }
@@ -0,0 +1,386 @@
+//! Optional indexer recording per-module epoch results into the node's aux
+//! database, so past earnings can be queried without replaying blocks.
+//!
+//! Only finalized blocks are indexed. Records are grouped per module in
+//! buckets of [`BUCKET_SIZE`] blocks to keep range queries cheap. The last
+//! indexed block is stored alongside them, a restarted indexer backfills the
+//! blocks finalized while it was not running.
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use node_subspace_runtime::{
+    opaque::Block, pallet_subnet_emission, pallet_subspace, AccountId, BlockNumber, Hash,
+    Runtime, RuntimeEvent,
+};
+use sc_client_api::{
+    backend::{Backend, StorageProvider},
+    AuxStore, BlockchainEvents,
+};
+use scale_codec::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{hashing::twox_128, storage::StorageKey};
+
+const AUX_PREFIX: &[u8] = b"subspace_module_history";
+const LAST_INDEXED_KEY: &[u8] = b"subspace_module_history_last_indexed";
+
+/// Amount of blocks stored under a single aux entry, roughly one day.
+pub const BUCKET_SIZE: BlockNumber = 10_800;
+
+/// The result of a single epoch for one module.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode, Serialize, Deserialize)]
+pub struct ModuleEpochRecord {
+    pub block: BlockNumber,
+    pub uid: u16,
+    pub emission: u64,
+    pub incentive: u16,
+    pub dividends: u16,
+}
+
+pub fn bucket_of(block: BlockNumber) -> BlockNumber {
+    block.saturating_div(BUCKET_SIZE)
+}
+
+fn bucket_key(netuid: u16, key: &AccountId, bucket: BlockNumber) -> Vec<u8> {
+    let mut aux_key = AUX_PREFIX.to_vec();
+    aux_key.extend(netuid.to_be_bytes());
+    aux_key.extend(key.encode());
+    aux_key.extend(bucket.to_be_bytes());
+    aux_key
+}
+
+/// Reads all records of a module stored in the given bucket.
+pub fn read_bucket<C: AuxStore>(
+    client: &C,
+    netuid: u16,
+    key: &AccountId,
+    bucket: BlockNumber,
+) -> sp_blockchain::Result<Vec<ModuleEpochRecord>> {
+    let Some(raw) = client.get_aux(&bucket_key(netuid, key, bucket))? else {
+        return Ok(Vec::new());
+    };
+
+    Vec::<ModuleEpochRecord>::decode(&mut &raw[..]).map_err(|err| {
+        sp_blockchain::Error::Backend(format!("corrupted module history entry: {err}"))
+    })
+}
+
+/// The last block indexed, if the indexer ever ran.
+pub fn last_indexed<C: AuxStore>(client: &C) -> sp_blockchain::Result<Option<BlockNumber>> {
+    let Some(raw) = client.get_aux(LAST_INDEXED_KEY)? else {
+        return Ok(None);
+    };
+
+    BlockNumber::decode(&mut &raw[..]).map(Some).map_err(|err| {
+        sp_blockchain::Error::Backend(format!("corrupted module history entry: {err}"))
+    })
+}
+
+/// Follows finality and indexes every `EpochFinalized` event, after
+/// backfilling the blocks finalized since the last indexed one. Runs until the
+/// finality stream closes.
+pub async fn run_indexer<C, BE>(client: Arc<C>)
+where
+    C: BlockchainEvents<Block> + StorageProvider<Block, BE> + HeaderBackend<Block> + AuxStore,
+    BE: Backend<Block>,
+{
+    log::info!("module history indexer started");
+
+    // Subscribed before the backfill, so no block finalized meanwhile is missed.
+    let mut finality = client.finality_notification_stream();
+
+    let finalized = client.info().finalized_number;
+    let mut next = match last_indexed(&*client) {
+        Ok(Some(last)) => last.saturating_add(1),
+        // A fresh index starts with the blocks finalized from now on.
+        Ok(None) => finalized.saturating_add(1),
+        Err(err) => {
+            log::error!("module history: failed to read the last indexed block: {err}");
+            return;
+        }
+    };
+
+    if next <= finalized {
+        log::info!("module history: backfilling blocks {next} to {finalized}");
+    }
+    while next <= finalized {
+        match client.hash(next) {
+            Ok(Some(hash)) => index_finalized(&*client, hash, next),
+            Ok(None) => log::warn!("module history: block {next} not found"),
+            Err(err) => log::warn!("module history: failed to read block {next}: {err}"),
+        }
+        next = next.saturating_add(1);
+    }
+
+    while let Some(notification) = finality.next().await {
+        // Blocks finalized implicitly come first, in ascending order.
+        let hashes = notification.tree_route.iter().chain(std::iter::once(&notification.hash));
+
+        for hash in hashes {
+            let block = match client.number(*hash) {
+                Ok(Some(block)) => block,
+                Ok(None) => continue,
+                Err(err) => {
+                    log::warn!("module history: failed to read block {hash:?}: {err}");
+                    continue;
+                }
+            };
+
+            // Already indexed by the backfill
+            if block < next {
+                continue;
+            }
+
+            index_finalized(&*client, *hash, block);
+            next = block.saturating_add(1);
+        }
+    }
+}
+
+fn index_finalized<C, BE>(client: &C, hash: Hash, block: BlockNumber)
+where
+    C: StorageProvider<Block, BE> + AuxStore,
+    BE: Backend<Block>,
+{
+    if let Err(err) = index_block(client, hash, block) {
+        log::warn!("module history: failed to index block {block} ({hash:?}): {err}");
+    }
+}
+
+fn index_block<C, BE>(client: &C, hash: Hash, block: BlockNumber) -> sp_blockchain::Result<()>
+where
+    C: StorageProvider<Block, BE> + AuxStore,
+    BE: Backend<Block>,
+{
+    // `frame_system::Events` is not public, so the key is built by hand.
+    let events_key = [twox_128(b"System"), twox_128(b"Events")].concat();
+    let events: Vec<frame_system::EventRecord<RuntimeEvent, Hash>> =
+        read_storage(client, hash, events_key)?.unwrap_or_default();
+
+    let mut writes = Vec::new();
+    // Epochs without weights emit `EpochSkipped` instead, they did not run.
+    for record in events {
+        if let RuntimeEvent::SubnetEmissionModule(
+            pallet_subnet_emission::Event::EpochFinalized(netuid),
+        ) = record.event
+        {
+            let records = read_epoch(client, hash, block, netuid)?;
+            writes.extend(append_records(client, netuid, records)?);
+        }
+    }
+
+    commit(client, writes, block)
+}
+
+/// Reads the results of the epoch a subnet finished in the block.
+fn read_epoch<C, BE>(
+    client: &C,
+    hash: Hash,
+    block: BlockNumber,
+    netuid: u16,
+) -> sp_blockchain::Result<Vec<(AccountId, ModuleEpochRecord)>>
+where
+    C: StorageProvider<Block, BE> + AuxStore,
+    BE: Backend<Block>,
+{
+    use pallet_subspace::{Dividends, Emission, Incentive, Keys};
+
+    let emission: Vec<u64> =
+        read_storage(client, hash, Emission::<Runtime>::hashed_key_for(netuid))?
+            .unwrap_or_default();
+    let incentive: Vec<u16> =
+        read_storage(client, hash, Incentive::<Runtime>::hashed_key_for(netuid))?
+            .unwrap_or_default();
+    let dividends: Vec<u16> =
+        read_storage(client, hash, Dividends::<Runtime>::hashed_key_for(netuid))?
+            .unwrap_or_default();
+
+    let mut records = Vec::with_capacity(emission.len());
+    for (idx, emission) in emission.iter().enumerate() {
+        let uid = idx as u16;
+        let Some(key) = read_storage::<_, _, AccountId>(
+            client,
+            hash,
+            Keys::<Runtime>::hashed_key_for(netuid, uid),
+        )?
+        else {
+            continue;
+        };
+
+        records.push((
+            key,
+            ModuleEpochRecord {
+                block,
+                uid,
+                emission: *emission,
+                incentive: incentive.get(idx).copied().unwrap_or_default(),
+                dividends: dividends.get(idx).copied().unwrap_or_default(),
+            },
+        ));
+    }
+
+    Ok(records)
+}
+
+/// Appends the records of a subnet's modules to their buckets, returning the
+/// aux entries to write.
+fn append_records<C: AuxStore>(
+    client: &C,
+    netuid: u16,
+    records: Vec<(AccountId, ModuleEpochRecord)>,
+) -> sp_blockchain::Result<Vec<(Vec<u8>, Vec<u8>)>> {
+    let mut writes = Vec::with_capacity(records.len());
+
+    for (key, record) in records {
+        let bucket = bucket_of(record.block);
+        let mut bucket_records = read_bucket(client, netuid, &key, bucket)?;
+        // Re-indexing the same block after a restart must not duplicate entries.
+        if bucket_records.iter().any(|stored| stored.block == record.block) {
+            continue;
+        }
+
+        bucket_records.push(record);
+        writes.push((bucket_key(netuid, &key, bucket), bucket_records.encode()));
+    }
+
+    Ok(writes)
+}
+
+/// Writes the records of a block together with the block as the last one
+/// indexed, so an interrupted indexer resumes right after it.
+fn commit<C: AuxStore>(
+    client: &C,
+    mut writes: Vec<(Vec<u8>, Vec<u8>)>,
+    block: BlockNumber,
+) -> sp_blockchain::Result<()> {
+    writes.push((LAST_INDEXED_KEY.to_vec(), block.encode()));
+
+    let writes: Vec<(&[u8], &[u8])> =
+        writes.iter().map(|(key, value)| (key.as_slice(), value.as_slice())).collect();
+    client.insert_aux(writes.iter(), &[])
+}
+
+fn read_storage<C, BE, V: Decode>(
+    client: &C,
+    hash: Hash,
+    key: Vec<u8>,
+) -> sp_blockchain::Result<Option<V>>
+where
+    C: StorageProvider<Block, BE>,
+    BE: Backend<Block>,
+{
+    let Some(data) = client.storage(hash, &StorageKey(key))? else {
+        return Ok(None);
+    };
+
+    V::decode(&mut &data.0[..]).map(Some).map_err(|err| {
+        sp_blockchain::Error::Backend(format!("failed to decode storage value: {err}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, sync::Mutex};
+
+    use sp_core::crypto::AccountId32;
+
+    use super::*;
+
+    /// In-memory aux store, standing in for the client's aux database.
+    #[derive(Default)]
+    struct MemoryAux(Mutex<HashMap<Vec<u8>, Vec<u8>>>);
+
+    impl AuxStore for MemoryAux {
+        fn insert_aux<
+            'a,
+            'b: 'a,
+            'c: 'a,
+            I: IntoIterator<Item = &'a (&'c [u8], &'c [u8])>,
+            D: IntoIterator<Item = &'a &'b [u8]>,
+        >(
+            &self,
+            insert: I,
+            delete: D,
+        ) -> sp_blockchain::Result<()> {
+            let mut entries = self.0.lock().unwrap();
+            for (key, value) in insert {
+                entries.insert(key.to_vec(), value.to_vec());
+            }
+            for key in delete {
+                entries.remove(*key);
+            }
+            Ok(())
+        }
+
+        fn get_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            Ok(self.0.lock().unwrap().get(key).cloned())
+        }
+    }
+
+    fn record(block: BlockNumber, emission: u64) -> ModuleEpochRecord {
+        ModuleEpochRecord {
+            block,
+            uid: 0,
+            emission,
+            incentive: 1,
+            dividends: 2,
+        }
+    }
+
+    fn index(aux: &MemoryAux, netuid: u16, key: &AccountId, block: BlockNumber, emission: u64) {
+        let writes =
+            append_records(aux, netuid, vec![(key.clone(), record(block, emission))]).unwrap();
+        commit(aux, writes, block).unwrap();
+    }
+
+    #[test]
+    fn records_are_grouped_in_buckets() {
+        let aux = MemoryAux::default();
+        let key = AccountId32::new([1; 32]);
+
+        index(&aux, 0, &key, 100, 10);
+        index(&aux, 0, &key, 200, 20);
+        index(&aux, 0, &key, BUCKET_SIZE.saturating_add(100), 30);
+
+        assert_eq!(
+            read_bucket(&aux, 0, &key, 0).unwrap(),
+            vec![record(100, 10), record(200, 20)]
+        );
+        assert_eq!(
+            read_bucket(&aux, 0, &key, 1).unwrap(),
+            vec![record(BUCKET_SIZE.saturating_add(100), 30)]
+        );
+        // Other subnets and modules are kept apart
+        assert!(read_bucket(&aux, 1, &key, 0).unwrap().is_empty());
+        assert!(read_bucket(&aux, 0, &AccountId32::new([2; 32]), 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn reindexing_a_block_keeps_a_single_record() {
+        let aux = MemoryAux::default();
+        let key = AccountId32::new([1; 32]);
+
+        index(&aux, 0, &key, 100, 10);
+        index(&aux, 0, &key, 100, 10);
+
+        assert_eq!(
+            read_bucket(&aux, 0, &key, 0).unwrap(),
+            vec![record(100, 10)]
+        );
+    }
+
+    #[test]
+    fn last_indexed_block_is_stored_with_the_records() {
+        let aux = MemoryAux::default();
+        let key = AccountId32::new([1; 32]);
+        assert_eq!(last_indexed(&aux).unwrap(), None);
+
+        index(&aux, 0, &key, 100, 10);
+        assert_eq!(last_indexed(&aux).unwrap(), Some(100));
+
+        // Blocks without epochs move the marker as well
+        commit(&aux, Vec::new(), 101).unwrap();
+        assert_eq!(last_indexed(&aux).unwrap(), Some(101));
+    }
+}
@@ -0,0 +1,175 @@
+//! Prometheus metrics of the offchain worker and the weight decryption pipeline.
+//!
+//! The runtime reports its progress through the offworker host functions, decryptions with the
+//! node's keys are measured by the [`Decrypter`](super::decrypter::Decrypter) itself.
+
+use std::time::Duration;
+
+use ow_extensions::OffworkerMetric;
+use prometheus_endpoint::{
+    register, Counter, Gauge, GaugeVec, Histogram, HistogramOpts, Opts, PrometheusError, Registry,
+    F64, U64,
+};
+
+#[derive(Clone)]
+pub struct OffworkerMetrics {
+    subnets_assigned: Gauge<U64>,
+    blocks_behind: GaugeVec<U64>,
+    decryption_failures: Counter<U64>,
+    decryption_time: Histogram,
+    last_ping_block: Gauge<U64>,
+    irrationality_delta: GaugeVec<F64>,
+    submission_errors: Counter<U64>,
+}
+
+impl OffworkerMetrics {
+    pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+        Ok(Self {
+            subnets_assigned: register(
+                Gauge::new(
+                    "subspace_offworker_subnets_assigned",
+                    "Subnets with encrypted weights assigned to the decryption node",
+                )?,
+                registry,
+            )?,
+            blocks_behind: register(
+                GaugeVec::new(
+                    Opts::new(
+                        "subspace_offworker_blocks_behind",
+                        "Blocks since the oldest epoch of the subnet still waiting for decryption",
+                    ),
+                    &["subnet"],
+                )?,
+                registry,
+            )?,
+            decryption_failures: register(
+                Counter::new(
+                    "subspace_offworker_decryption_failures_total",
+                    "Encrypted weights the node failed to decrypt",
+                )?,
+                registry,
+            )?,
+            decryption_time: register(
+                Histogram::with_opts(
+                    HistogramOpts::new(
+                        "subspace_offworker_decryption_seconds",
+                        "Time taken to decrypt the weights of a module",
+                    )
+                    .buckets(vec![0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1, 0.5]),
+                )?,
+                registry,
+            )?,
+            last_ping_block: register(
+                Gauge::new(
+                    "subspace_offworker_last_ping_block",
+                    "Block of the last keep alive ping sent by the node",
+                )?,
+                registry,
+            )?,
+            irrationality_delta: register(
+                GaugeVec::new(
+                    Opts::new(
+                        "subspace_offworker_irrationality_delta",
+                        "Simulated irrationality delta of copying the subnet's consensus",
+                    ),
+                    &["subnet"],
+                )?,
+                registry,
+            )?,
+            submission_errors: register(
+                Counter::new(
+                    "subspace_offworker_submission_errors_total",
+                    "Unsigned transactions of the offchain worker that could not be submitted",
+                )?,
+                registry,
+            )?,
+        })
+    }
+
+    pub fn report(&self, metric: OffworkerMetric) {
+        match metric {
+            OffworkerMetric::SubnetsAssigned(count) => self.subnets_assigned.set(count.into()),
+            OffworkerMetric::BlocksBehind { subnet_id, blocks } => {
+                self.blocks_behind.with_label_values(&[&subnet_id.to_string()]).set(blocks)
+            }
+            OffworkerMetric::Ping { block } => self.last_ping_block.set(block),
+            OffworkerMetric::IrrationalityDelta { subnet_id, delta } => {
+                self.irrationality_delta.with_label_values(&[&subnet_id.to_string()]).set(delta)
+            }
+            OffworkerMetric::SubmissionError => self.submission_errors.inc(),
+            OffworkerMetric::Decryption { elapsed, succeeded } => {
+                self.observe_decryption(elapsed, succeeded)
+            }
+        }
+    }
+
+    pub fn observe_decryption(&self, elapsed: Duration, succeeded: bool) {
+        self.decryption_time.observe(elapsed.as_secs_f64());
+        if !succeeded {
+            self.decryption_failures.inc();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reported_metrics_update_their_series() {
+        let registry = Registry::new();
+        let metrics = OffworkerMetrics::register(&registry).expect("metrics should register");
+
+        metrics.report(OffworkerMetric::SubnetsAssigned(3));
+        metrics.report(OffworkerMetric::BlocksBehind {
+            subnet_id: 2,
+            blocks: 40,
+        });
+        metrics.report(OffworkerMetric::Ping { block: 1_000 });
+        metrics.report(OffworkerMetric::IrrationalityDelta {
+            subnet_id: 2,
+            delta: -0.5,
+        });
+        metrics.report(OffworkerMetric::SubmissionError);
+
+        assert_eq!(metrics.subnets_assigned.get(), 3);
+        assert_eq!(metrics.blocks_behind.with_label_values(&["2"]).get(), 40);
+        assert_eq!(metrics.last_ping_block.get(), 1_000);
+        assert_eq!(
+            metrics.irrationality_delta.with_label_values(&["2"]).get(),
+            -0.5
+        );
+        assert_eq!(metrics.submission_errors.get(), 1);
+
+        // subnets are labelled on their own
+        metrics.report(OffworkerMetric::BlocksBehind {
+            subnet_id: 3,
+            blocks: 0,
+        });
+        assert_eq!(metrics.blocks_behind.with_label_values(&["2"]).get(), 40);
+        assert_eq!(metrics.blocks_behind.with_label_values(&["3"]).get(), 0);
+    }
+
+    #[test]
+    fn decryptions_are_timed_and_failures_counted() {
+        let registry = Registry::new();
+        let metrics = OffworkerMetrics::register(&registry).expect("metrics should register");
+
+        metrics.report(OffworkerMetric::Decryption {
+            elapsed: Duration::from_millis(2),
+            succeeded: true,
+        });
+        metrics.observe_decryption(Duration::from_millis(3), false);
+
+        assert_eq!(metrics.decryption_time.get_sample_count(), 2);
+        assert_eq!(metrics.decryption_failures.get(), 1);
+    }
+
+    #[test]
+    fn metrics_are_registered_once() {
+        let registry = Registry::new();
+        OffworkerMetrics::register(&registry).expect("metrics should register");
+
+        assert!(OffworkerMetrics::register(&registry).is_err());
+    }
+}
@@ -3,6 +3,9 @@
 #[cfg(feature = "std")]
 use sp_externalities::ExternalitiesExt;
 
+#[cfg(feature = "std")]
+pub use subspace_weights_crypto::{envelope, threshold};
+
 #[cfg(feature = "std")]
 sp_externalities::decl_extension! {
     pub struct OffworkerExt(Box<dyn OffworkerExtension>);
@@ -16,11 +19,7 @@ impl OffworkerExt {
 }
 
 #[cfg(feature = "std")]
-/// (Decrypted Weights, Key)
-pub type DecryptedWeights = (Vec<(u16, u16)>, Vec<u8>);
-
-#[cfg(feature = "std")]
-pub type EncryptionKey = (Vec<u8>, Vec<u8>);
+pub use subspace_weights_crypto::{DecryptedWeights, EncryptionKey};
 
 #[cfg(not(feature = "std"))]
 /// (Decrypted Weights, Key)
@@ -29,6 +28,27 @@ pub type DecryptedWeights = (sp_std::vec::Vec<(u16, u16)>, sp_std::vec::Vec<u8>)
 #[cfg(not(feature = "std"))]
 pub type EncryptionKey = (sp_std::vec::Vec<u8>, sp_std::vec::Vec<u8>);
 
+/// Progress of the offchain worker, reported to the node for its metrics.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum OffworkerMetric {
+    /// Subnets assigned to the node with encrypted weights to process
+    SubnetsAssigned(u32),
+    /// Blocks since the oldest epoch of the subnet still waiting for decryption
+    BlocksBehind { subnet_id: u16, blocks: u64 },
+    /// The node sent a keep alive ping
+    Ping { block: u64 },
+    /// Simulated irrationality delta of copying the subnet's consensus
+    IrrationalityDelta { subnet_id: u16, delta: f64 },
+    /// An unsigned transaction could not be submitted
+    SubmissionError,
+    /// Weights were decrypted or combined from committee shares
+    Decryption {
+        elapsed: std::time::Duration,
+        succeeded: bool,
+    },
+}
+
 #[cfg(feature = "std")]
 pub trait OffworkerExtension: Send + 'static {
     fn decrypt_weight(&self, encrypted: Vec<u8>) -> Option<DecryptedWeights>;
@@ -36,6 +56,17 @@ pub trait OffworkerExtension: Send + 'static {
     fn is_decryption_node(&self) -> bool;
 
     fn get_encryption_key(&self) -> Option<EncryptionKey>;
+
+    /// Decryption share of a committee envelope, if the node holds a committee key share.
+    fn decryption_share(&self, _encrypted: Vec<u8>) -> Option<Vec<u8>> {
+        None
+    }
+
+    /// Drops the keys the node used before its current one, except the `retained` ones that
+    /// weights are still encrypted to.
+    fn retire_decryption_keys(&self, _retained: Vec<EncryptionKey>) {}
+
+    fn report_metric(&self, _metric: OffworkerMetric) {}
 }
 
 #[sp_runtime_interface::runtime_interface]
@@ -57,4 +88,78 @@ pub trait Offworker {
             .expect("missing offworker ext")
             .get_encryption_key()
     }
+
+    fn decryption_share(
+        &mut self,
+        encrypted: sp_std::vec::Vec<u8>,
+    ) -> Option<sp_std::vec::Vec<u8>> {
+        self.extension::<OffworkerExt>()
+            .expect("missing offworker ext")
+            .decryption_share(encrypted)
+    }
+
+    fn retire_decryption_keys(&mut self, retained: sp_std::vec::Vec<EncryptionKey>) {
+        self.extension::<OffworkerExt>()
+            .expect("missing offworker ext")
+            .retire_decryption_keys(retained)
+    }
+
+    /// Combines the decryption shares of committee members, given with the key each member
+    /// announced, into the decrypted weights. Shares not matching their member's key are
+    /// dropped. Needs no key, so any node can do it.
+    fn combine_decryption_shares(
+        &mut self,
+        committee_key: EncryptionKey,
+        encrypted: sp_std::vec::Vec<u8>,
+        shares: sp_std::vec::Vec<(EncryptionKey, sp_std::vec::Vec<u8>)>,
+        threshold: u16,
+    ) -> Option<DecryptedWeights> {
+        let started = std::time::Instant::now();
+        let decrypted = threshold::combine(&committee_key, &encrypted, &shares, threshold)
+            .ok()
+            .and_then(|decrypted| envelope::decode_weights(&decrypted));
+
+        if let Some(ext) = self.extension::<OffworkerExt>() {
+            ext.report_metric(OffworkerMetric::Decryption {
+                elapsed: started.elapsed(),
+                succeeded: decrypted.is_some(),
+            });
+        }
+
+        decrypted
+    }
+
+    // Metrics are best effort, they are dropped when the node registered no offworker extension
+
+    fn report_subnets_assigned(&mut self, count: u32) {
+        if let Some(ext) = self.extension::<OffworkerExt>() {
+            ext.report_metric(OffworkerMetric::SubnetsAssigned(count));
+        }
+    }
+
+    fn report_blocks_behind(&mut self, subnet_id: u16, blocks: u64) {
+        if let Some(ext) = self.extension::<OffworkerExt>() {
+            ext.report_metric(OffworkerMetric::BlocksBehind { subnet_id, blocks });
+        }
+    }
+
+    fn report_ping(&mut self, block: u64) {
+        if let Some(ext) = self.extension::<OffworkerExt>() {
+            ext.report_metric(OffworkerMetric::Ping { block });
+        }
+    }
+
+    /// `delta_bits` are the bits of the delta as `f64`, floats can not be passed to the host.
+    fn report_irrationality_delta(&mut self, subnet_id: u16, delta_bits: u64) {
+        let delta = f64::from_bits(delta_bits);
+        if let Some(ext) = self.extension::<OffworkerExt>() {
+            ext.report_metric(OffworkerMetric::IrrationalityDelta { subnet_id, delta });
+        }
+    }
+
+    fn report_submission_error(&mut self) {
+        if let Some(ext) = self.extension::<OffworkerExt>() {
+            ext.report_metric(OffworkerMetric::SubmissionError);
+        }
+    }
 }
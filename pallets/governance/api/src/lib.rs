@@ -11,6 +11,50 @@ pub enum VoteMode {
     Vote = 1,
 }
 
+/// Share of the participating stake that has to vote in favor for a proposal to pass,
+/// for each kind of proposal.
+#[derive(Clone, Copy, Debug, TypeInfo, Decode, Encode, PartialEq, Eq, MaxEncodedLen)]
+pub struct ApprovalThresholds {
+    pub global_custom: Percent,
+    pub global_params: Percent,
+    pub subnet_custom: Percent,
+    pub subnet_params: Percent,
+    pub transfer_dao_treasury: Percent,
+    pub emission_curve: Percent,
+    pub grant: Percent,
+}
+
+impl ApprovalThresholds {
+    pub fn iter(&self) -> impl Iterator<Item = Percent> {
+        [
+            self.global_custom,
+            self.global_params,
+            self.subnet_custom,
+            self.subnet_params,
+            self.transfer_dao_treasury,
+            self.emission_curve,
+            self.grant,
+        ]
+        .into_iter()
+    }
+}
+
+impl Default for ApprovalThresholds {
+    fn default() -> Self {
+        let majority = Percent::from_percent(50);
+        let supermajority = Percent::from_percent(66);
+        Self {
+            global_custom: majority,
+            global_params: supermajority,
+            subnet_custom: majority,
+            subnet_params: majority,
+            transfer_dao_treasury: supermajority,
+            emission_curve: supermajority,
+            grant: supermajority,
+        }
+    }
+}
+
 #[derive(Clone, TypeInfo, Decode, Encode, PartialEq, Eq, DebugNoBound, MaxEncodedLen)]
 pub struct GovernanceConfiguration {
     pub proposal_cost: u64,
@@ -19,6 +63,11 @@ pub struct GovernanceConfiguration {
     pub proposal_reward_treasury_allocation: Percent,
     pub max_proposal_reward_treasury_allocation: u64,
     pub proposal_reward_interval: u64,
+    /// Minimum share of the stake that has to take part in a vote for its outcome to count.
+    /// Subnet proposals measure it against the stake of the subnet, the rest against the
+    /// total stake.
+    pub quorum: Percent,
+    pub approval_thresholds: ApprovalThresholds,
 }
 
 impl Default for GovernanceConfiguration {
@@ -32,6 +81,8 @@ impl Default for GovernanceConfiguration {
             // Maximum reward allocation 10K.
             max_proposal_reward_treasury_allocation: 10_000_000_000_000,
             proposal_reward_interval: 75_600,
+            quorum: Percent::from_percent(5),
+            approval_thresholds: ApprovalThresholds::default(),
         }
     }
 }
@@ -0,0 +1,178 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+
+use sp_runtime::{
+    sp_std::prelude::Vec,
+    traits::{IdentifyAccount, Verify},
+    MultiSignature,
+};
+
+type Signature = MultiSignature;
+pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct SenateMember {
+    pub account: AccountId,
+    /// Block at which the member's term ends
+    pub term_end: u64,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct GrantInfo {
+    pub id: u64,
+    pub proposal_id: u64,
+    pub recipient: AccountId,
+    /// Amount of each milestone and whether it was already released
+    pub milestones: Vec<(u64, bool)>,
+    /// Amount still owed to the recipient
+    pub remaining: u64,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo, Serialize, Deserialize)]
+pub enum ProposalState {
+    Open,
+    Accepted,
+    Refused,
+    Expired,
+    FailedQuorum,
+    Cancelled,
+    EnactmentCancelled,
+}
+
+/// Most proposals `list_proposals` returns at once.
+pub const MAX_LISTED_PROPOSALS: u32 = 100;
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub enum ProposalKind<AccountId> {
+    GlobalCustom,
+    SubnetCustom,
+    GlobalParams,
+    SubnetParams,
+    TransferDaoTreasury { account: AccountId, amount: u64 },
+    EmissionCurve,
+    Grant { recipient: AccountId, amount: u64 },
+    SubnetTreasurySpend { recipient: AccountId, amount: u64 },
+    FounderShareRouting { to_treasury: bool },
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct ProposalInfo<AccountId> {
+    pub id: u64,
+    pub proposer: AccountId,
+    pub kind: ProposalKind<AccountId>,
+    /// Subnet of subnet-scoped proposals, None for global ones
+    pub subnet_id: Option<u16>,
+    pub state: ProposalState,
+    /// Whether the senate decided the proposal instead of the stake vote
+    pub decided_by_senate: bool,
+    /// Block at which the proposal left the open state
+    pub finalized_at: Option<u64>,
+    /// Block at which an accepted proposal takes effect, if it is not enacted yet
+    pub enactment_block: Option<u64>,
+    pub title: Vec<u8>,
+    pub link: Vec<u8>,
+    pub votes_for: Vec<AccountId>,
+    pub votes_against: Vec<AccountId>,
+    pub stake_for: u64,
+    pub stake_against: u64,
+    pub creation_block: u64,
+    pub expiration_block: u64,
+    pub proposal_cost: u64,
+}
+
+/// Criteria a proposal has to match to be listed. Unset criteria match every proposal.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct ProposalFilter<AccountId> {
+    pub state: Option<ProposalState>,
+    pub proposer: Option<AccountId>,
+    pub subnet_id: Option<u16>,
+}
+
+impl<AccountId> Default for ProposalFilter<AccountId> {
+    fn default() -> Self {
+        Self {
+            state: None,
+            proposer: None,
+            subnet_id: None,
+        }
+    }
+}
+
+impl<AccountId: PartialEq> ProposalFilter<AccountId> {
+    pub fn matches(&self, proposal: &ProposalInfo<AccountId>) -> bool {
+        self.state.map_or(true, |state| state == proposal.state)
+            && self.proposer.as_ref().map_or(true, |proposer| *proposer == proposal.proposer)
+            && self.subnet_id.map_or(true, |subnet_id| Some(subnet_id) == proposal.subnet_id)
+    }
+}
+
+/// Stake an account votes with on the global or a subnet governance.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct VotingPower<AccountId> {
+    /// Stake counted for the account's own votes
+    pub stake: u64,
+    /// Stake of the delegators represented by the account
+    pub represented_stake: u64,
+    /// Representative of the account, if it delegated its votes
+    pub representative: Option<AccountId>,
+    /// Senate members vote without stake
+    pub senate_member: bool,
+}
+
+/// Proposal rewards an account will receive on the next distribution of a governance.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct PendingReward {
+    /// Governance paying the reward, None for the global one
+    pub subnet_id: Option<u16>,
+    /// Unrewarded proposals the account voted on
+    pub proposals: Vec<u64>,
+    /// Stake the account voted with, summed over the proposals
+    pub stake: u64,
+    /// Reward the account would receive if the rewards were distributed now
+    pub estimated_reward: u64,
+}
+
+/// Funds of the DAO treasury and what they are committed to.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct TreasuryState<AccountId> {
+    pub address: AccountId,
+    pub balance: u64,
+    /// Amount owed to the payment schedules
+    pub payment_liability: u64,
+    /// Amount accepted proposals will take from the treasury once enacted
+    pub pending_spends: u64,
+    /// Amount held by the grants for their unreleased milestones
+    pub grant_escrow: u64,
+    pub active_payment_schedules: u32,
+    pub active_grants: u32,
+}
+
+sp_api::decl_runtime_apis! {
+    #[api_version(2)]
+    pub trait GovernanceRuntimeApi {
+        fn get_senate_members() -> Vec<SenateMember>;
+
+        fn get_active_grants() -> Vec<GrantInfo>;
+
+        fn get_proposal(id: u64) -> Option<ProposalInfo<AccountId>>;
+
+        /// Proposals matching the filter, skipping the first `offset` matches. At most
+        /// `limit` are returned, capped at `MAX_LISTED_PROPOSALS`.
+        fn list_proposals(
+            filter: ProposalFilter<AccountId>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<ProposalInfo<AccountId>>;
+
+        /// Voting power of the account on the given subnet governance, or the global one if
+        /// no subnet is given.
+        fn get_voting_power(account: AccountId, subnet_id: Option<u16>) -> VotingPower<AccountId>;
+
+        fn get_pending_rewards(account: AccountId) -> Vec<PendingReward>;
+
+        fn get_treasury_state() -> TreasuryState<AccountId>;
+    }
+}
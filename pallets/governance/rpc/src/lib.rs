@@ -0,0 +1,176 @@
+pub use governance_runtime_api::GovernanceRuntimeApi;
+use governance_runtime_api::{
+    GrantInfo, PendingReward, ProposalFilter, ProposalInfo, SenateMember, TreasuryState,
+    VotingPower, MAX_LISTED_PROPOSALS,
+};
+use jsonrpsee::{
+    core::{ClientError as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::ErrorObject,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{
+    traits::{Block as BlockT, IdentifyAccount, Verify},
+    MultiSignature,
+};
+use std::sync::Arc;
+
+type Signature = MultiSignature;
+type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
+
+#[rpc(client, server)]
+pub trait GovernanceApi<BlockHash> {
+    #[method(name = "governance_getProposal")]
+    fn get_proposal(
+        &self,
+        id: u64,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ProposalInfo<AccountId>>>;
+
+    /// Lists the proposals matching the filter, `limit` is capped at `MAX_LISTED_PROPOSALS`.
+    #[method(name = "governance_listProposals")]
+    fn list_proposals(
+        &self,
+        filter: Option<ProposalFilter<AccountId>>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<ProposalInfo<AccountId>>>;
+
+    #[method(name = "governance_getVotingPower")]
+    fn get_voting_power(
+        &self,
+        account: AccountId,
+        subnet_id: Option<u16>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<VotingPower<AccountId>>;
+
+    #[method(name = "governance_getPendingRewards")]
+    fn get_pending_rewards(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PendingReward>>;
+
+    #[method(name = "governance_getTreasuryState")]
+    fn get_treasury_state(&self, at: Option<BlockHash>) -> RpcResult<TreasuryState<AccountId>>;
+
+    #[method(name = "governance_getSenateMembers")]
+    fn get_senate_members(&self, at: Option<BlockHash>) -> RpcResult<Vec<SenateMember>>;
+
+    #[method(name = "governance_getActiveGrants")]
+    fn get_active_grants(&self, at: Option<BlockHash>) -> RpcResult<Vec<GrantInfo>>;
+}
+
+pub struct GovernancePallet<C, Block> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> GovernancePallet<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> GovernanceApiServer<<Block as BlockT>::Hash> for GovernancePallet<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: GovernanceRuntimeApi<Block>,
+{
+    fn get_proposal(
+        &self,
+        id: u64,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<ProposalInfo<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_proposal(at, id).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn list_proposals(
+        &self,
+        filter: Option<ProposalFilter<AccountId>>,
+        offset: Option<u32>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ProposalInfo<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.list_proposals(
+            at,
+            filter.unwrap_or_default(),
+            offset.unwrap_or_default(),
+            limit.unwrap_or(MAX_LISTED_PROPOSALS),
+        )
+        .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_voting_power(
+        &self,
+        account: AccountId,
+        subnet_id: Option<u16>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<VotingPower<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_voting_power(at, account, subnet_id).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_pending_rewards(
+        &self,
+        account: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<PendingReward>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_pending_rewards(at, account).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_treasury_state(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<TreasuryState<AccountId>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_treasury_state(at).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_senate_members(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<SenateMember>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_senate_members(at).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_active_grants(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<GrantInfo>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_active_grants(at).map_err(runtime_error_into_rpc_err)
+    }
+}
+
+const RUNTIME_ERROR: i32 = 1;
+
+/// Converts a runtime trap into an RPC error.
+fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> JsonRpseeError {
+    JsonRpseeError::Call(ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Runtime error",
+        Some(format!("{:?}", err)),
+    ))
+}
@@ -2,10 +2,13 @@
 
 use crate::{Pallet as GovernanceMod, *};
 use frame_benchmarking::{account, benchmarks};
+use frame_support::{BoundedBTreeSet, BoundedVec};
 use frame_system::RawOrigin;
 pub use pallet::*;
+use pallet_subnet_emission_api::{EmissionCurve, MAX_EMISSION_CURVE_STEPS};
 use pallet_subspace::{Pallet as SubspaceMod, SubnetBurn};
-use sp_std::vec::Vec;
+use sp_runtime::Perbill;
+use sp_std::{vec, vec::Vec};
 
 fn submit_dao_application<T: Config>() -> Result<(), &'static str> {
     // First add the application
@@ -54,6 +57,19 @@ fn register_mock<T: Config>(
     SubspaceMod::<T>::increase_stake(&key, &module_key, enough_stake);
     Ok(())
 }
+fn insert_payment_schedule<T: Config>(paused: bool) {
+    let mut schedule = ScheduledPayment::<T>::new(
+        account("Bob", 0, 2),
+        1_000_000_000,
+        1u32.into(),
+        100u32.into(),
+        10,
+        frame_system::Pallet::<T>::block_number(),
+    );
+    schedule.paused = paused;
+    PaymentSchedules::<T>::insert(0, schedule);
+}
+
 benchmarks! {
     //---------------------------------
     //Adding proposals
@@ -131,7 +147,10 @@ benchmarks! {
             params.max_allowed_validators,
             params.use_weights_encryption,
             params.copier_margin,
-            params.max_encryption_period
+            params.max_encryption_period,
+            params.copier_stake_fraction,
+            params.copier_bonds_averaging,
+            params.copier_delegation_fee
         )?;
 
         // add balance to submit the proposal
@@ -164,7 +183,10 @@ benchmarks! {
         params.max_allowed_validators,
         params.use_weights_encryption,
         params.copier_margin,
-        params.max_encryption_period
+        params.max_encryption_period,
+        params.copier_stake_fraction,
+        params.copier_bonds_averaging,
+        params.copier_delegation_fee
     )
 
     // 2
@@ -307,4 +329,417 @@ benchmarks! {
         GovernanceMod::<T>::add_senate_member(RawOrigin::Root.into(), senate_member_key.clone())?;
         GovernanceMod::<T>::remove_senate_member(RawOrigin::Root.into(), senate_member_key.clone())?;
     }: remove_senate_member(RawOrigin::Root, senate_member_key)
+
+    // 15
+    add_emission_curve_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        let data = "test".as_bytes().to_vec();
+        // the piecewise curve with the most steps is the most expensive to validate
+        let steps = (0..u64::from(MAX_EMISSION_CURVE_STEPS))
+            .map(|step| (step.saturating_mul(1_000_000_000_000), Perbill::from_percent(50)))
+            .collect::<Vec<_>>();
+        let curve = EmissionCurve::Piecewise {
+            steps: BoundedVec::truncate_from(steps),
+            max_supply: 1_000_000_000_000_000_000,
+        };
+    }: add_emission_curve_proposal(RawOrigin::Signed(caller), data, curve)
+
+    // 16
+    set_senate_configuration {
+        let config = SenateConfiguration {
+            max_members: 12,
+            ..Default::default()
+        };
+    }: set_senate_configuration(RawOrigin::Root, config)
+
+    // 17
+    submit_senate_candidacy {
+        let candidate: T::AccountId = account("Alice", 0, 1);
+    }: submit_senate_candidacy(RawOrigin::Signed(candidate))
+
+    // 18
+    vote_senate_candidate {
+        let candidate: T::AccountId = account("Alice", 0, 1);
+        let voter: T::AccountId = account("Bob", 0, 2);
+        let module_key: T::AccountId = account("Charlie", 0, 3);
+        register_mock::<T>(voter.clone(), module_key, "test".as_bytes().to_vec())?;
+        GovernanceMod::<T>::update_delegating_voting_power(&voter, false)?;
+        GovernanceMod::<T>::submit_senate_candidacy(RawOrigin::Signed(candidate.clone()).into())?;
+    }: vote_senate_candidate(RawOrigin::Signed(voter), candidate)
+
+    run_senate_election {
+        let c in 1 .. crate::senate::MAX_SENATE_CANDIDATES;
+        let v in 1 .. crate::senate::MAX_SENATE_VOTERS;
+
+        let config = SenateConfiguration {
+            max_members: c as u16,
+            ..Default::default()
+        };
+        for i in 0..c {
+            GovernanceMod::<T>::submit_senate_candidacy(
+                RawOrigin::Signed(account("Candidate", i, 1)).into(),
+            )?;
+        }
+        // every voter backs a staked candidate, so all of them take a seat
+        let mut name = b"module".to_vec();
+        for i in 0..v {
+            let voter: T::AccountId = account("Voter", i, 2);
+            pallet_subspace::RegistrationsPerBlock::<T>::set(0);
+            pallet_subspace::RegistrationsThisInterval::<T>::remove(0);
+            name.truncate(6);
+            name.extend(i.to_le_bytes());
+            register_mock::<T>(voter.clone(), account("Module", i, 3), name.clone())?;
+            if let Some(netuid) = SubspaceMod::<T>::get_netuid_for_name(b"testnet") {
+                pallet_subspace::MaxAllowedUids::<T>::insert(netuid, u16::MAX);
+            }
+            GovernanceMod::<T>::update_delegating_voting_power(&voter, false)?;
+            GovernanceMod::<T>::vote_senate_candidate(
+                RawOrigin::Signed(voter).into(),
+                account("Candidate", i % c, 1),
+            )?;
+        }
+    }: {
+        crate::senate::run_senate_election::<T>(0, &config);
+    }
+    verify {
+        assert_eq!(SenateMembers::<T>::iter_keys().count(), c.min(v) as usize);
+    }
+
+    tick_enactments {
+        let p in 0 .. 100;
+        let e in 0 .. 100;
+
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        SubspaceMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap(),
+        );
+        // the due proposals transfer from the treasury, the others wait for a later block
+        for i in 0..p.saturating_add(e) {
+            GovernanceMod::<T>::add_transfer_dao_treasury_proposal(
+                RawOrigin::Signed(caller.clone()).into(),
+                "test".as_bytes().to_vec(),
+                1_000_000_000,
+                recipient.clone(),
+            )?;
+            PendingEnactments::<T>::insert(u64::from(i), if i < e { 0 } else { u64::MAX });
+        }
+    }: {
+        crate::enactment::tick_enactments::<T>(1);
+    }
+    verify {
+        assert_eq!(PendingEnactments::<T>::iter().count(), p as usize);
+    }
+
+    tick_applications {
+        let a in 1 .. 100;
+        let v in 0 .. dao::MAX_APPLICATION_VOTERS;
+
+        let curator: T::AccountId = account("Alice", 0, 1);
+        Curator::<T>::set(curator.clone());
+        for i in 0..a {
+            CuratorApplications::<T>::insert(u64::from(i), dao::CuratorApplication::<T> {
+                id: u64::from(i),
+                user_id: account("Applicant", i, 2),
+                paying_for: curator.clone(),
+                data: BoundedVec::truncate_from("test".as_bytes().to_vec()),
+                status: dao::ApplicationStatus::Pending,
+                application_cost: 0,
+                block_number: 0,
+            });
+        }
+        // every application is decided by a vote that ended, the first one has all the voters
+        let mut votes_for = BoundedBTreeSet::new();
+        let mut name = b"module".to_vec();
+        for i in 0..v {
+            let voter: T::AccountId = account("Voter", i, 3);
+            pallet_subspace::RegistrationsPerBlock::<T>::set(0);
+            pallet_subspace::RegistrationsThisInterval::<T>::remove(0);
+            name.truncate(6);
+            name.extend(i.to_le_bytes());
+            register_mock::<T>(voter.clone(), account("Module", i, 4), name.clone())?;
+            if let Some(netuid) = SubspaceMod::<T>::get_netuid_for_name(b"testnet") {
+                pallet_subspace::MaxAllowedUids::<T>::insert(netuid, u16::MAX);
+            }
+            GovernanceMod::<T>::update_delegating_voting_power(&voter, false)?;
+            votes_for.try_insert(voter).map_err(|_| "too many voters")?;
+        }
+        for i in 0..a {
+            let votes_for = if i == 0 { votes_for.clone() } else { BoundedBTreeSet::new() };
+            ApplicationVotes::<T>::insert(u64::from(i), dao::ApplicationVote::<T> {
+                votes_for,
+                votes_against: BoundedBTreeSet::new(),
+                expiration_block: 0,
+            });
+        }
+    }: {
+        crate::dao::tick_applications::<T>(100);
+    }
+    verify {
+        assert_eq!(ApplicationVotes::<T>::iter().count(), 0);
+    }
+
+    // 19
+    delegate_votes {
+        let delegator: T::AccountId = account("Alice", 0, 1);
+        let representative: T::AccountId = account("Bob", 0, 2);
+        let module_key: T::AccountId = account("Charlie", 0, 3);
+        register_mock::<T>(delegator.clone(), module_key, "test".as_bytes().to_vec())?;
+
+        // the delegator moves from a previous representative to one that is almost full
+        let previous: T::AccountId = account("Dave", 0, 4);
+        GovernanceMod::<T>::delegate_votes(
+            RawOrigin::Signed(delegator.clone()).into(),
+            previous,
+            DelegationScope::Subnet(0),
+        )?;
+        for i in 1..crate::voting::MAX_DELEGATORS {
+            GovernanceMod::<T>::delegate_votes(
+                RawOrigin::Signed(account("Delegator", i, 5)).into(),
+                representative.clone(),
+                DelegationScope::Subnet(0),
+            )?;
+        }
+    }: delegate_votes(RawOrigin::Signed(delegator), representative, DelegationScope::Subnet(0))
+
+    // 20
+    undelegate_votes {
+        let delegator: T::AccountId = account("Alice", 0, 1);
+        let representative: T::AccountId = account("Bob", 0, 2);
+        GovernanceMod::<T>::delegate_votes(
+            RawOrigin::Signed(delegator.clone()).into(),
+            representative,
+            DelegationScope::Global,
+        )?;
+    }: undelegate_votes(RawOrigin::Signed(delegator), DelegationScope::Global)
+
+    // 21
+    cancel_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        GovernanceMod::<T>::add_global_custom_proposal(
+            RawOrigin::Signed(caller.clone()).into(),
+            "test".as_bytes().to_vec(),
+        )?;
+    }: cancel_proposal(RawOrigin::Signed(caller), 0)
+
+    // 22
+    amend_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        let params = SubspaceMod::<T>::global_params();
+        GovernanceMod::<T>::do_add_global_params_proposal(
+            RawOrigin::Signed(caller.clone()).into(),
+            "test".as_bytes().to_vec(),
+            params.clone(),
+        )?;
+        let metadata = ProposalMetadata {
+            title: BoundedVec::truncate_from("title".as_bytes().to_vec()),
+            link: BoundedVec::truncate_from("link".as_bytes().to_vec()),
+        };
+        let params = pallet_subspace::GlobalParams::<T> {
+            max_name_length: params.max_name_length.saturating_add(1),
+            ..params
+        };
+    }: amend_proposal(RawOrigin::Signed(caller), 0, Some(ProposalData::GlobalParams(params)), Some(metadata))
+
+    // 23
+    set_enactment_delays {
+        let delays = EnactmentDelays {
+            global_params: 1,
+            ..Default::default()
+        };
+    }: set_enactment_delays(RawOrigin::Root, delays)
+
+    // 24
+    fast_track_enactment {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let member: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        GovernanceMod::<T>::add_global_custom_proposal(
+            RawOrigin::Signed(caller).into(),
+            "test".as_bytes().to_vec(),
+        )?;
+        PendingEnactments::<T>::insert(0, u64::MAX);
+        GovernanceMod::<T>::add_senate_member(RawOrigin::Root.into(), member.clone())?;
+    }: fast_track_enactment(RawOrigin::Signed(member), 0)
+
+    // 25
+    cancel_enactment {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let member: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        GovernanceMod::<T>::add_global_custom_proposal(
+            RawOrigin::Signed(caller).into(),
+            "test".as_bytes().to_vec(),
+        )?;
+        PendingEnactments::<T>::insert(0, u64::MAX);
+        GovernanceMod::<T>::add_senate_member(RawOrigin::Root.into(), member.clone())?;
+        GovernanceMod::<T>::add_senate_member(
+            RawOrigin::Root.into(),
+            account("Charlie", 0, 3),
+        )?;
+        GovernanceMod::<T>::fast_track_enactment(RawOrigin::Signed(member).into(), 0)?;
+    }: cancel_enactment(RawOrigin::Root, 0)
+
+    // 26
+    open_dao_application_vote {
+        submit_dao_application::<T>()?;
+        let caller: T::AccountId = account("Alice", 0, 1);
+    }: open_dao_application_vote(RawOrigin::Signed(caller), 0)
+
+    // 27
+    vote_dao_application {
+        submit_dao_application::<T>()?;
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let voter: T::AccountId = account("Charlie", 0, 3);
+        let module_key: T::AccountId = account("Dave", 0, 4);
+        GovernanceMod::<T>::open_dao_application_vote(RawOrigin::Signed(caller).into(), 0)?;
+        register_mock::<T>(voter.clone(), module_key, "test".as_bytes().to_vec())?;
+        GovernanceMod::<T>::update_delegating_voting_power(&voter, false)?;
+    }: vote_dao_application(RawOrigin::Signed(voter), 0, true)
+
+    // 28
+    add_grant_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        SubspaceMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap(),
+        );
+        let data = "test".as_bytes().to_vec();
+        let milestones = vec![1_000_000_000_000; grants::MAX_GRANT_MILESTONES as usize];
+    }: add_grant_proposal(RawOrigin::Signed(caller), data, recipient, milestones)
+
+    // 29
+    attest_grant_milestone {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap(),
+        );
+        GovernanceMod::<T>::create_grant(0, recipient, BoundedVec::truncate_from(vec![
+            1_000_000_000_000,
+            1_000_000_000_000,
+        ]))?;
+        Curator::<T>::set(caller.clone());
+    }: attest_grant_milestone(RawOrigin::Signed(caller), 0, 0)
+
+    // 30
+    create_payment_schedule {
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap(),
+        );
+        insert_payment_schedule::<T>(false);
+    }: create_payment_schedule(RawOrigin::Root, recipient, 1_000_000_000, 1u32.into(), 100u32.into(), 10)
+
+    // 32
+    cancel_grant {
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap(),
+        );
+        GovernanceMod::<T>::create_grant(0, recipient, BoundedVec::truncate_from(vec![
+            1_000_000_000_000,
+            1_000_000_000_000,
+        ]))?;
+    }: cancel_grant(RawOrigin::Root, 0)
+
+    // 33
+    pause_payment_schedule {
+        insert_payment_schedule::<T>(false);
+    }: pause_payment_schedule(RawOrigin::Root, 0)
+
+    // 34
+    resume_payment_schedule {
+        insert_payment_schedule::<T>(true);
+    }: resume_payment_schedule(RawOrigin::Root, 0)
+
+    // 35
+    set_payment_oracle {
+        let oracle: T::AccountId = account("Alice", 0, 1);
+    }: set_payment_oracle(RawOrigin::Root, Some(oracle))
+
+    // 36
+    submit_reference_price {
+        let oracle: T::AccountId = account("Alice", 0, 1);
+        GovernanceMod::<T>::set_payment_oracle(RawOrigin::Root.into(), Some(oracle.clone()))?;
+    }: submit_reference_price(RawOrigin::Signed(oracle), 1_000_000)
+
+    // 37
+    add_subnet_treasury_spend_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        SubnetGovernanceConfig::<T>::mutate(0, |config| config.vote_mode = VoteMode::Vote);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        SubspaceMod::<T>::add_balance_to_account(
+            &SubspaceMod::<T>::subnet_treasury_account(0),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap(),
+        );
+        let data = "test".as_bytes().to_vec();
+    }: add_subnet_treasury_spend_proposal(RawOrigin::Signed(caller), 0, data, recipient, 1_000_000_000)
+
+    // 38
+    remove_from_whitelist_with_reason {
+        submit_dao_application::<T>()?;
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let application_key: T::AccountId = account("Bob", 0, 2);
+        Curator::<T>::set(caller.clone());
+        GovernanceMod::<T>::add_to_whitelist(
+            RawOrigin::Signed(caller.clone()).into(),
+            application_key.clone(),
+        )?;
+    }: remove_from_whitelist_with_reason(
+        RawOrigin::Signed(caller),
+        application_key,
+        RemovalReason::Misbehavior
+    )
+
+    // 39
+    set_application_vote_configuration {
+        let config = ApplicationVoteConfiguration {
+            quorum: Percent::from_percent(10),
+            ..Default::default()
+        };
+    }: set_application_vote_configuration(RawOrigin::Root, config)
+
+    // 40
+    add_founder_share_routing_proposal {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        register_mock::<T>(caller.clone(), caller.clone(), "test".as_bytes().to_vec())?;
+        SubnetGovernanceConfig::<T>::mutate(0, |config| config.vote_mode = VoteMode::Vote);
+        SubspaceMod::<T>::add_balance_to_account(&caller,
+        SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap());
+        let data = "test".as_bytes().to_vec();
+    }: add_founder_share_routing_proposal(RawOrigin::Signed(caller), 0, data, true)
+
+    // 41
+    create_reference_payment_schedule {
+        let oracle: T::AccountId = account("Alice", 0, 1);
+        let recipient: T::AccountId = account("Bob", 0, 2);
+        SubspaceMod::<T>::add_balance_to_account(
+            &DaoTreasuryAddress::<T>::get(),
+            SubspaceMod::<T>::u64_to_balance(1_000_000_000_000_000).unwrap(),
+        );
+        GovernanceMod::<T>::set_payment_oracle(RawOrigin::Root.into(), Some(oracle.clone()))?;
+        GovernanceMod::<T>::submit_reference_price(RawOrigin::Signed(oracle).into(), 1_000_000)?;
+        insert_payment_schedule::<T>(false);
+    }: create_reference_payment_schedule(RawOrigin::Root, recipient, 1_000, 1u32.into(), 100u32.into(), 10)
 }
@@ -1,6 +1,10 @@
-use crate::*;
-use frame_support::pallet_prelude::{BoundedVec, ConstU32, DispatchResult};
-use frame_system::ensure_signed;
+use crate::{proposal::calc_stake, *};
+use frame_support::{
+    pallet_prelude::{BoundedVec, ConstU32, DispatchResult},
+    weights::Weight,
+    BoundedBTreeSet, DebugNoBound,
+};
+use frame_system::{ensure_root, ensure_signed};
 use pallet_subspace::Pallet as PalletSubspace;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -23,7 +27,55 @@ pub enum ApplicationStatus {
     Pending,
     Accepted,
     Refused,
-    Removed,
+    /// The key was removed from the whitelist after being accepted.
+    Removed {
+        reason: RemovalReason,
+    },
+    /// The application was not decided on in time.
+    Expired,
+}
+
+/// Why a key was removed from the whitelist.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, MaxEncodedLen, TypeInfo, Decode, Encode)]
+pub enum RemovalReason {
+    #[default]
+    Unspecified,
+    Misbehavior,
+    Inactivity,
+    /// The owner of the key asked for the removal.
+    Requested,
+    /// Application specific reason code.
+    Other(u16),
+}
+
+/// Most accounts voting on a single application.
+pub const MAX_APPLICATION_VOTERS: u32 = 1024;
+
+/// A stake-weighted vote deciding on an application instead of the curator.
+#[derive(Clone, TypeInfo, Decode, Encode, MaxEncodedLen, DebugNoBound, PartialEq, Eq)]
+#[scale_info(skip_type_params(T))]
+pub struct ApplicationVote<T: Config> {
+    pub votes_for: BoundedBTreeSet<T::AccountId, ConstU32<MAX_APPLICATION_VOTERS>>,
+    pub votes_against: BoundedBTreeSet<T::AccountId, ConstU32<MAX_APPLICATION_VOTERS>>,
+    pub expiration_block: u64,
+}
+
+/// How a stake-weighted vote on an application is decided.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct ApplicationVoteConfiguration {
+    /// Share of the total stake that has to take part for the vote to count
+    pub quorum: Percent,
+    /// Share of the participating stake that has to agree for the application to be accepted
+    pub approval_threshold: Percent,
+}
+
+impl Default for ApplicationVoteConfiguration {
+    fn default() -> Self {
+        Self {
+            quorum: Percent::from_percent(5),
+            approval_threshold: Percent::from_percent(50),
+        }
+    }
 }
 
 impl<T: Config> Pallet<T> {
@@ -34,9 +86,29 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Expired applications were never decided on, the key can apply again.
     #[must_use]
     fn can_add_application_status_based(key: &T::AccountId) -> bool {
-        !CuratorApplications::<T>::iter().any(|(_, app)| app.user_id == *key)
+        !CuratorApplications::<T>::iter()
+            .any(|(_, app)| app.user_id == *key && app.status != ApplicationStatus::Expired)
+    }
+
+    /// The pending application of the key. Keys whose application expired can apply again, so
+    /// older applications of the same key are skipped.
+    fn pending_application_of(key: &T::AccountId) -> Result<CuratorApplication<T>, Error<T>> {
+        let mut found = false;
+        for app in CuratorApplications::<T>::iter_values().filter(|app| app.user_id == *key) {
+            if app.status == ApplicationStatus::Pending {
+                return Ok(app);
+            }
+            found = true;
+        }
+
+        Err(if found {
+            Error::<T>::ApplicationNotPending
+        } else {
+            Error::<T>::ApplicationNotFound
+        })
     }
 
     pub fn add_application(
@@ -106,7 +178,9 @@ impl<T: Config> Pallet<T> {
             Some(_) => Err(Error::<T>::ApplicationNotPending),
             None => Err(Error::<T>::ApplicationNotFound),
         })?;
+        ApplicationVotes::<T>::remove(application_id);
 
+        Self::deposit_event(Event::ApplicationRefused(application_id));
         Ok(())
     }
 
@@ -132,9 +206,7 @@ impl<T: Config> Pallet<T> {
         // Perform actions based on the application data type
         // The owners will handle the off-chain logic
 
-        let mut application = CuratorApplications::<T>::iter_values()
-            .find(|app| app.user_id == *user_id)
-            .ok_or(Error::<T>::ApplicationNotFound)?;
+        let mut application = Self::pending_application_of(user_id)?;
 
         // Give the proposer back his tokens, if the application passed
         PalletSubspace::<T>::add_balance_to_account(
@@ -161,19 +233,14 @@ impl<T: Config> Pallet<T> {
             Error::<T>::AlreadyWhitelisted
         );
 
-        let application = CuratorApplications::<T>::iter_values()
-            .find(|app| app.user_id == module_key)
-            .ok_or(Error::<T>::ApplicationNotFound)?;
-
-        ensure!(
-            application.status == ApplicationStatus::Pending,
-            Error::<T>::ApplicationNotPending
-        );
+        let application = Self::pending_application_of(&module_key)?;
 
         LegitWhitelist::<T>::insert(&module_key, ());
+        ApplicationVotes::<T>::remove(application.id);
 
         T::execute_application(&module_key)?;
 
+        Self::deposit_event(Event::ApplicationAccepted(application.id));
         Self::deposit_event(Event::WhitelistModuleAdded(module_key.clone()));
 
         Ok(())
@@ -182,6 +249,7 @@ impl<T: Config> Pallet<T> {
     pub fn do_remove_from_whitelist(
         origin: T::RuntimeOrigin,
         module_key: T::AccountId,
+        reason: RemovalReason,
     ) -> DispatchResult {
         let key = ensure_signed(origin)?;
         ensure!(Curator::<T>::get() == key, Error::<T>::NotCurator);
@@ -195,11 +263,107 @@ impl<T: Config> Pallet<T> {
         CuratorApplications::<T>::iter()
             .filter(|(_, app)| app.user_id == module_key)
             .for_each(|(id, mut app)| {
-                app.status = ApplicationStatus::Removed;
+                app.status = ApplicationStatus::Removed { reason };
                 CuratorApplications::<T>::insert(id, app);
             });
 
-        Self::deposit_event(Event::WhitelistModuleRemoved(module_key));
+        Self::deposit_event(Event::WhitelistModuleRemoved(module_key, reason));
+
+        Ok(())
+    }
+
+    pub fn do_set_application_vote_configuration(
+        origin: T::RuntimeOrigin,
+        config: ApplicationVoteConfiguration,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+        ensure!(
+            config.approval_threshold >= Percent::from_percent(50),
+            Error::<T>::InvalidApprovalThreshold
+        );
+
+        ApplicationVoteConfig::<T>::set(config);
+        Self::deposit_event(Event::ApplicationVoteConfigurationUpdated);
+
+        Ok(())
+    }
+
+    /// Lets the stakers decide on a pending application. Can be requested by the curator or by
+    /// the account that paid for the application.
+    pub fn do_open_dao_application_vote(
+        origin: T::RuntimeOrigin,
+        application_id: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let application =
+            CuratorApplications::<T>::get(application_id).ok_or(Error::<T>::ApplicationNotFound)?;
+        ensure!(
+            Curator::<T>::get() == key || application.paying_for == key,
+            Error::<T>::NotCurator
+        );
+        ensure!(
+            application.status == ApplicationStatus::Pending,
+            Error::<T>::ApplicationNotPending
+        );
+        ensure!(
+            !ApplicationVotes::<T>::contains_key(application_id),
+            Error::<T>::ApplicationVoteAlreadyOpen
+        );
+
+        let proposal_expiration = GlobalGovernanceConfig::<T>::get().proposal_expiration;
+        let expiration_block = PalletSubspace::<T>::get_current_block_number()
+            .saturating_add(proposal_expiration as u64);
+
+        ApplicationVotes::<T>::insert(
+            application_id,
+            ApplicationVote {
+                votes_for: BoundedBTreeSet::new(),
+                votes_against: BoundedBTreeSet::new(),
+                expiration_block,
+            },
+        );
+        Self::deposit_event(Event::ApplicationVoteOpened(application_id));
+
+        Ok(())
+    }
+
+    pub fn do_vote_dao_application(
+        origin: T::RuntimeOrigin,
+        application_id: u64,
+        agree: bool,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let Some(mut vote) = ApplicationVotes::<T>::get(application_id) else {
+            return Err(Error::<T>::ApplicationVoteNotOpen.into());
+        };
+        ensure!(
+            !vote.votes_for.contains(&key) && !vote.votes_against.contains(&key),
+            Error::<T>::AlreadyVoted
+        );
+
+        let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
+        ensure!(
+            calc_stake::<T>(&not_delegating, &key) > 0,
+            Error::<T>::InsufficientStake
+        );
+
+        let votes = if agree {
+            &mut vote.votes_for
+        } else {
+            &mut vote.votes_against
+        };
+        votes
+            .try_insert(key.clone())
+            .map_err(|_| Error::<T>::TooManyApplicationVoters)?;
+
+        ApplicationVotes::<T>::insert(application_id, vote);
+        Self::deposit_event(Event::ApplicationVoted {
+            application_id,
+            voter: key,
+            agree,
+        });
 
         Ok(())
     }
@@ -216,3 +380,82 @@ impl<T: Config> Pallet<T> {
         LegitWhitelist::<T>::contains_key(account_id)
     }
 }
+
+/// Closes the application votes that ended and expires the applications nobody decided on.
+pub fn tick_applications<T: Config>(block_number: u64) -> Weight {
+    if block_number % 100 != 0 {
+        return Weight::zero();
+    }
+
+    let application_expiration = ApplicationExpiration::<T>::get();
+    let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
+
+    let mut applications: u32 = 0;
+    let pending: Vec<_> = CuratorApplications::<T>::iter_values()
+        .inspect(|_| applications = applications.saturating_add(1))
+        .filter(|app| app.status == ApplicationStatus::Pending)
+        .collect();
+
+    let mut voters: u32 = 0;
+    for mut application in pending {
+        let id = application.id;
+
+        match ApplicationVotes::<T>::get(id) {
+            Some(vote) if vote.expiration_block <= block_number => {
+                ApplicationVotes::<T>::remove(id);
+                voters = voters
+                    .saturating_add(vote.votes_for.len() as u32)
+                    .saturating_add(vote.votes_against.len() as u32);
+                if let Err(err) = resolve_application_vote::<T>(application, vote, &not_delegating)
+                {
+                    log::error!("failed to resolve vote on application {id}: {err:?}");
+                }
+            }
+            Some(_) => {}
+            None if application.block_number.saturating_add(application_expiration)
+                <= block_number =>
+            {
+                application.status = ApplicationStatus::Expired;
+                CuratorApplications::<T>::insert(id, application);
+                Pallet::<T>::deposit_event(Event::ApplicationExpired(id));
+            }
+            None => {}
+        }
+    }
+
+    <T as Config>::WeightInfo::tick_applications(applications, voters)
+}
+
+fn resolve_application_vote<T: Config>(
+    mut application: CuratorApplication<T>,
+    vote: ApplicationVote<T>,
+    not_delegating: &sp_std::collections::btree_set::BTreeSet<T::AccountId>,
+) -> DispatchResult {
+    let tally = |voters: &BoundedBTreeSet<T::AccountId, ConstU32<MAX_APPLICATION_VOTERS>>| -> u64 {
+        voters
+            .iter()
+            .map(|voter| calc_stake::<T>(not_delegating, voter))
+            .fold(0u64, |acc, stake| acc.saturating_add(stake))
+    };
+    let stake_for = tally(&vote.votes_for);
+    let stake_against = tally(&vote.votes_against);
+
+    let config = ApplicationVoteConfig::<T>::get();
+    let participation = stake_for.saturating_add(stake_against);
+    let quorum = config.quorum.mul_ceil(pallet_subspace::TotalStake::<T>::get());
+    let threshold = config.approval_threshold.mul_ceil(participation);
+
+    if participation >= quorum && stake_for > 0 && stake_for >= threshold {
+        LegitWhitelist::<T>::insert(&application.user_id, ());
+        T::execute_application(&application.user_id)?;
+        Pallet::<T>::deposit_event(Event::ApplicationAccepted(application.id));
+        Pallet::<T>::deposit_event(Event::WhitelistModuleAdded(application.user_id));
+    } else {
+        application.status = ApplicationStatus::Refused;
+        let id = application.id;
+        CuratorApplications::<T>::insert(id, application);
+        Pallet::<T>::deposit_event(Event::ApplicationRefused(id));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use crate::{proposal::ProposalStatus, *};
+use frame_support::{pallet_prelude::DispatchResult, storage::with_storage_layer, weights::Weight};
+use frame_system::{ensure_root, ensure_signed};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+
+/// Amount of blocks accepted proposals wait before taking effect, for each kind of proposal with
+/// on-chain effects.
+#[derive(Clone, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct EnactmentDelays {
+    pub global_params: u64,
+    pub subnet_params: u64,
+    pub transfer_dao_treasury: u64,
+    pub emission_curve: u64,
+    pub grant: u64,
+    pub subnet_treasury_spend: u64,
+}
+
+impl Default for EnactmentDelays {
+    fn default() -> Self {
+        Self {
+            // ~1 day
+            global_params: 10_800,
+            subnet_params: 10_800,
+            transfer_dao_treasury: 10_800,
+            grant: 10_800,
+            subnet_treasury_spend: 10_800,
+            // ~2 days
+            emission_curve: 21_600,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    pub fn do_set_enactment_delays(
+        origin: OriginFor<T>,
+        delays: EnactmentDelays,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+
+        EnactmentDelayConfig::<T>::set(delays);
+        Self::deposit_event(Event::<T>::EnactmentDelaysUpdated);
+
+        Ok(())
+    }
+
+    /// Votes to enact a pending proposal right away. The proposal is enacted once the share of
+    /// the senate required to approve proposals agrees. A veto-only senate can't fast-track.
+    pub fn do_fast_track_enactment(
+        origin: OriginFor<T>,
+        proposal_id: ProposalId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(
+            Self::is_senate_member(key.clone()),
+            Error::<T>::NotSenateMember
+        );
+        let config = SenateConfig::<T>::get();
+        ensure!(!config.veto_only, Error::<T>::SenateVetoOnly);
+        ensure!(
+            PendingEnactments::<T>::contains_key(proposal_id),
+            Error::<T>::NotPendingEnactment
+        );
+
+        let mut votes = FastTrackVotes::<T>::get(proposal_id);
+        ensure!(!votes.contains(&key), Error::<T>::AlreadyVoted);
+        votes.try_insert(key.clone()).map_err(|_| Error::<T>::InternalError)?;
+
+        Self::deposit_event(Event::<T>::ProposalFastTrackVoted {
+            proposal_id,
+            member: key,
+        });
+
+        let members = votes.iter().filter(|id| SenateMembers::<T>::contains_key(id)).count();
+        let total_members = SenateMembers::<T>::iter_keys().count() as u32;
+        let threshold = config.approval_ratio.mul_ceil(total_members).max(1);
+
+        if members as u32 >= threshold {
+            enact_proposal::<T>(proposal_id);
+        } else {
+            FastTrackVotes::<T>::insert(proposal_id, votes);
+        }
+
+        Ok(())
+    }
+
+    /// Stops a pending proposal from taking effect, e.g. when a critical bug is found in it.
+    pub fn do_cancel_enactment(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+        ensure_root(origin)?;
+
+        ensure!(
+            PendingEnactments::<T>::contains_key(proposal_id),
+            Error::<T>::NotPendingEnactment
+        );
+        PendingEnactments::<T>::remove(proposal_id);
+        FastTrackVotes::<T>::remove(proposal_id);
+
+        let block = pallet_subspace::Pallet::<T>::get_current_block_number();
+        Proposals::<T>::mutate(proposal_id, |proposal| {
+            if let Some(proposal) = proposal {
+                proposal.status = ProposalStatus::EnactmentCancelled { block };
+            }
+        });
+        Self::deposit_event(Event::<T>::ProposalEnactmentCancelled(proposal_id));
+
+        Ok(())
+    }
+}
+
+/// Enacts the pending proposals whose delay is over.
+pub fn tick_enactments<T: Config>(block_number: u64) -> Weight {
+    let mut pending: u32 = 0;
+    let due: Vec<_> = PendingEnactments::<T>::iter()
+        .inspect(|_| pending = pending.saturating_add(1))
+        .filter(|(_, enactment_block)| *enactment_block <= block_number)
+        .map(|(proposal_id, _)| proposal_id)
+        .collect();
+
+    let enacted = due.len() as u32;
+    for proposal_id in due {
+        enact_proposal::<T>(proposal_id);
+    }
+
+    <T as Config>::WeightInfo::tick_enactments(pending.saturating_sub(enacted), enacted)
+}
+
+fn enact_proposal<T: Config>(proposal_id: ProposalId) {
+    PendingEnactments::<T>::remove(proposal_id);
+    FastTrackVotes::<T>::remove(proposal_id);
+
+    let Some(proposal) = Proposals::<T>::get(proposal_id) else {
+        log::error!("pending proposal {proposal_id} does not exist, skipping...");
+        return;
+    };
+
+    match with_storage_layer(|| proposal.execute_proposal()) {
+        Ok(()) => Pallet::<T>::deposit_event(Event::<T>::ProposalEnacted(proposal_id)),
+        Err(err) => {
+            log::error!("failed to enact proposal {proposal_id}: {err:?}");
+            Pallet::<T>::deposit_event(Event::<T>::ProposalEnactmentFailed(proposal_id));
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use crate::*;
+use frame_support::{
+    pallet_prelude::{DispatchResult, MaxEncodedLen},
+    traits::{ConstU32, Currency, ExistenceRequirement},
+    BoundedVec, DebugNoBound,
+};
+use frame_system::{ensure_root, ensure_signed};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::AccountIdConversion;
+
+/// Maximum amount of milestones a grant can be split into.
+pub const MAX_GRANT_MILESTONES: u32 = 16;
+
+/// A part of a grant, paid out once its delivery is attested.
+#[derive(Clone, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub struct Milestone {
+    pub amount: u64,
+    pub released: bool,
+}
+
+/// Treasury funds granted by an accepted proposal. The funds are held in the grant's own account
+/// and paid out milestone by milestone.
+#[derive(Clone, DebugNoBound, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct Grant<T: Config> {
+    /// The proposal that created the grant.
+    pub proposal_id: ProposalId,
+    pub recipient: T::AccountId,
+    pub milestones: BoundedVec<Milestone, ConstU32<MAX_GRANT_MILESTONES>>,
+}
+
+impl<T: Config> Grant<T> {
+    /// Amount still held for the milestones that were not released yet.
+    pub fn remaining(&self) -> u64 {
+        self.milestones
+            .iter()
+            .filter(|milestone| !milestone.released)
+            .fold(0u64, |acc, milestone| acc.saturating_add(milestone.amount))
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.milestones.iter().all(|milestone| milestone.released)
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// The account holding the funds of the given grant.
+    pub fn grant_account(grant_id: u64) -> T::AccountId {
+        <T as Config>::PalletId::get().into_sub_account_truncating(("grant", grant_id))
+    }
+
+    /// Checks that the milestones are valid and that the treasury can fund all of them on top
+    /// of the payment schedules.
+    pub fn validate_grant(milestones: &[u64]) -> DispatchResult {
+        ensure!(
+            !milestones.is_empty() && milestones.iter().all(|amount| *amount > 0),
+            Error::<T>::InvalidGrant
+        );
+
+        let total = milestones.iter().fold(0u64, |acc, amount| acc.saturating_add(*amount));
+        Self::ensure_treasury_covers_spend(total)
+    }
+
+    /// Moves the funds of an accepted grant proposal from the treasury to the grant account.
+    pub(crate) fn create_grant(
+        proposal_id: ProposalId,
+        recipient: T::AccountId,
+        milestones: BoundedVec<u64, ConstU32<MAX_GRANT_MILESTONES>>,
+    ) -> DispatchResult {
+        let total = milestones.iter().fold(0u64, |acc, amount| acc.saturating_add(*amount));
+        let milestones = milestones
+            .into_iter()
+            .map(|amount| Milestone {
+                amount,
+                released: false,
+            })
+            .collect::<Vec<_>>();
+
+        let grant_id = NextGrantId::<T>::mutate(|id| {
+            let current = *id;
+            *id = id.saturating_add(1);
+            current
+        });
+
+        Self::transfer(
+            &DaoTreasuryAddress::<T>::get(),
+            &Self::grant_account(grant_id),
+            total,
+        )?;
+
+        Grants::<T>::insert(
+            grant_id,
+            Grant {
+                proposal_id,
+                recipient,
+                milestones: BoundedVec::truncate_from(milestones),
+            },
+        );
+        Self::deposit_event(Event::<T>::GrantCreated {
+            grant_id,
+            proposal_id,
+        });
+
+        Ok(())
+    }
+
+    /// Releases a milestone of a grant. Only the curator and senate members can attest that a
+    /// milestone was delivered.
+    pub fn do_attest_grant_milestone(
+        origin: T::RuntimeOrigin,
+        grant_id: u64,
+        milestone: u32,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+        ensure!(
+            Curator::<T>::get() == key || Self::is_senate_member(key),
+            Error::<T>::NotCuratorOrSenateMember
+        );
+
+        let mut grant = Grants::<T>::get(grant_id).ok_or(Error::<T>::GrantNotFound)?;
+        let entry = grant
+            .milestones
+            .get_mut(milestone as usize)
+            .ok_or(Error::<T>::InvalidMilestone)?;
+        ensure!(!entry.released, Error::<T>::MilestoneAlreadyReleased);
+        entry.released = true;
+        let amount = entry.amount;
+
+        Self::transfer(&Self::grant_account(grant_id), &grant.recipient, amount)?;
+        Self::deposit_event(Event::<T>::GrantMilestoneReleased {
+            grant_id,
+            milestone,
+            amount,
+        });
+
+        if grant.is_completed() {
+            Grants::<T>::remove(grant_id);
+            Self::deposit_event(Event::<T>::GrantCompleted(grant_id));
+        } else {
+            Grants::<T>::insert(grant_id, grant);
+        }
+
+        Ok(())
+    }
+
+    /// Cancels a grant, sending the funds of the unreleased milestones back to the treasury.
+    pub fn do_cancel_grant(origin: T::RuntimeOrigin, grant_id: u64) -> DispatchResult {
+        ensure_root(origin)?;
+
+        let grant = Grants::<T>::get(grant_id).ok_or(Error::<T>::GrantNotFound)?;
+        let clawback = grant.remaining();
+
+        Self::transfer(
+            &Self::grant_account(grant_id),
+            &DaoTreasuryAddress::<T>::get(),
+            clawback,
+        )?;
+
+        Grants::<T>::remove(grant_id);
+        Self::deposit_event(Event::<T>::GrantCancelled { grant_id, clawback });
+
+        Ok(())
+    }
+
+    fn transfer(from: &T::AccountId, to: &T::AccountId, amount: u64) -> DispatchResult {
+        <T as Config>::Currency::transfer(from, to, amount.into(), ExistenceRequirement::AllowDeath)
+    }
+}
@@ -6,9 +6,12 @@
 mod benchmarking;
 
 pub mod dao;
+pub mod enactment;
+pub mod grants;
 pub mod migrations;
 pub mod payments;
 pub mod proposal;
+pub mod rpc;
 pub mod senate;
 pub mod voting;
 pub mod weights; // Weight benchmarks
@@ -24,14 +27,22 @@ use substrate_fixed::types::I64F64;
 
 pub use pallet::*;
 pub use pallet_governance_api::*;
+use pallet_subnet_emission_api::EmissionCurve;
 use pallet_subspace::{
     self, define_subnet_includes,
     params::{burn::GeneralBurnConfiguration, subnet::SubnetChangeset},
-    DefaultKey,
+    BondsAveraging, DefaultKey,
 };
 
-pub use payments::ScheduledPayment;
-pub use proposal::{Proposal, ProposalData, ProposalId, ProposalStatus, UnrewardedProposal};
+pub use dao::{ApplicationVoteConfiguration, RemovalReason};
+pub use enactment::EnactmentDelays;
+pub use grants::{Grant, Milestone};
+pub use payments::{PaymentDenomination, ReferenceUnitPrice, ScheduledPayment};
+pub use proposal::{
+    Proposal, ProposalData, ProposalId, ProposalMetadata, ProposalStatus, UnrewardedProposal,
+};
+pub use senate::SenateConfiguration;
+pub use voting::DelegationScope;
 
 type SubnetId = u16;
 
@@ -40,7 +51,10 @@ pub mod pallet {
     #![allow(clippy::too_many_arguments)]
 
     pub use crate::weights::WeightInfo;
-    use crate::{dao::CuratorApplication, *};
+    use crate::{
+        dao::{ApplicationVote, CuratorApplication},
+        *,
+    };
     use frame_support::{
         pallet_prelude::{ValueQuery, *},
         traits::StorageInstance,
@@ -50,10 +64,10 @@ pub mod pallet {
     use sp_runtime::traits::{AccountIdConversion, Zero};
 
     #[cfg(feature = "testnet")]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(14);
 
     #[cfg(not(feature = "testnet"))]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(10);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -87,9 +101,11 @@ pub mod pallet {
 
             proposal::tick_proposals::<T>(block_number_u64);
             proposal::tick_proposal_rewards::<T>(block_number_u64);
+            let mut total_weight = senate::tick_senate::<T>(block_number_u64)
+                .saturating_add(enactment::tick_enactments::<T>(block_number_u64))
+                .saturating_add(dao::tick_applications::<T>(block_number_u64));
 
             let treasury = DaoTreasuryAddress::<T>::get();
-            let mut total_weight = Weight::zero();
 
             // Process each payment schedule
             PaymentSchedules::<T>::iter().for_each(|(schedule_id, mut schedule)| {
@@ -112,12 +128,16 @@ pub mod pallet {
                         total_weight = total_weight.saturating_add(T::DbWeight::get().reads(1));
                     }
                     Err(e) => {
-                        // Payment failed
+                        // Payment failed, retry later or pause the schedule
+                        let retry_at = schedule.schedule_retry(block_number);
+                        PaymentSchedules::<T>::insert(schedule_id, schedule);
                         Self::deposit_event(Event::PaymentFailed {
                             schedule_id,
                             error: e,
+                            retry_at,
                         });
-                        total_weight = total_weight.saturating_add(T::DbWeight::get().reads(1));
+                        total_weight =
+                            total_weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
                     }
                 }
             });
@@ -165,6 +185,33 @@ pub mod pallet {
     #[pallet::storage]
     pub type Proposals<T: Config> = StorageMap<_, Identity, ProposalId, Proposal<T>>;
 
+    #[pallet::type_value]
+    pub fn DefaultProposalCancellationRefund<T: Config>() -> Percent {
+        Percent::from_percent(50)
+    }
+
+    /// Share of the proposal cost refunded when a proposal is cancelled before any vote.
+    #[pallet::storage]
+    pub type ProposalCancellationRefund<T: Config> =
+        StorageValue<_, Percent, ValueQuery, DefaultProposalCancellationRefund<T>>;
+
+    #[pallet::storage]
+    pub type EnactmentDelayConfig<T: Config> = StorageValue<_, EnactmentDelays, ValueQuery>;
+
+    /// Accepted proposals waiting to take effect, with the block they will be enacted at.
+    #[pallet::storage]
+    pub type PendingEnactments<T: Config> = StorageMap<_, Identity, ProposalId, u64>;
+
+    /// Senate members that voted to enact a pending proposal right away.
+    #[pallet::storage]
+    pub type FastTrackVotes<T: Config> = StorageMap<
+        _,
+        Identity,
+        ProposalId,
+        BoundedBTreeSet<T::AccountId, ConstU32<{ senate::MAX_SENATE_MEMBERS }>>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     pub type UnrewardedProposals<T: Config> =
         StorageMap<_, Identity, ProposalId, UnrewardedProposal<T>>;
@@ -180,6 +227,24 @@ pub mod pallet {
     pub type NotDelegatingVotingPower<T: Config> =
         StorageValue<_, BoundedBTreeSet<T::AccountId, ConstU32<{ u32::MAX }>>, ValueQuery>;
 
+    /// Explicit vote delegations, indexed by the delegator and the scope of proposals they
+    /// apply to. The value is the representative voting on the delegator's behalf.
+    #[pallet::storage]
+    pub type VoteDelegations<T: Config> =
+        StorageDoubleMap<_, Identity, T::AccountId, Twox64Concat, DelegationScope, T::AccountId>;
+
+    /// Reverse index of `VoteDelegations`, the delegators of each representative per scope.
+    #[pallet::storage]
+    pub type VoteDelegators<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        T::AccountId,
+        Twox64Concat,
+        DelegationScope,
+        BoundedBTreeSet<T::AccountId, ConstU32<{ voting::MAX_DELEGATORS }>>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     pub type GlobalGovernanceConfig<T: Config> =
         StorageValue<_, GovernanceConfiguration, ValueQuery>;
@@ -193,6 +258,14 @@ pub mod pallet {
     pub type DaoTreasuryAddress<T: Config> =
         StorageValue<_, T::AccountId, ValueQuery, DefaultDaoTreasuryAddress<T>>;
 
+    /// Grants created by accepted proposals that still have unreleased milestones.
+    #[pallet::storage]
+    pub type Grants<T: Config> = StorageMap<_, Identity, u64, Grant<T>>;
+
+    /// Counter for generating unique grant IDs
+    #[pallet::storage]
+    pub type NextGrantId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
     /// Storage for payment schedules, indexed by a unique identifier
     #[pallet::storage]
     pub type PaymentSchedules<T: Config> =
@@ -202,6 +275,14 @@ pub mod pallet {
     #[pallet::storage]
     pub type NextPaymentScheduleId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Account allowed to feed the reference unit price
+    #[pallet::storage]
+    pub type PaymentOracle<T: Config> = StorageValue<_, T::AccountId>;
+
+    /// Latest price of a reference unit, used by schedules denominated in reference units
+    #[pallet::storage]
+    pub type ReferencePrice<T: Config> = StorageValue<_, ReferenceUnitPrice<BlockNumberFor<T>>>;
+
     #[pallet::type_value]
     pub fn DefaultGeneralSubnetApplicationCost<T: Config>() -> u64 {
         1_000_000_000_000 // 1_000 $COMAI
@@ -220,6 +301,25 @@ pub mod pallet {
     #[pallet::storage]
     pub type CuratorApplications<T: Config> = StorageMap<_, Identity, u64, CuratorApplication<T>>;
 
+    /// Open stake-weighted votes on curator applications, indexed by the application ID.
+    #[pallet::storage]
+    pub type ApplicationVotes<T: Config> = StorageMap<_, Identity, u64, ApplicationVote<T>>;
+
+    #[pallet::type_value]
+    pub fn DefaultApplicationExpiration<T: Config>() -> u64 {
+        324_000 // ~30 days
+    }
+
+    /// Amount of blocks a pending application waits for a decision before expiring.
+    #[pallet::storage]
+    pub type ApplicationExpiration<T: Config> =
+        StorageValue<_, u64, ValueQuery, DefaultApplicationExpiration<T>>;
+
+    /// Quorum and approval threshold of the stake-weighted votes on applications.
+    #[pallet::storage]
+    pub type ApplicationVoteConfig<T: Config> =
+        StorageValue<_, ApplicationVoteConfiguration, ValueQuery>;
+
     #[pallet::storage]
     pub type LegitWhitelist<T: Config> = StorageMap<_, Identity, T::AccountId, (), ValueQuery>;
 
@@ -227,8 +327,21 @@ pub mod pallet {
     pub type Curator<T: Config> = StorageValue<_, T::AccountId, ValueQuery, DefaultKey<T>>;
 
     // --- Senate Members ---
+
+    /// Current senate members and the block their term ends at.
+    #[pallet::storage]
+    pub type SenateMembers<T: Config> = StorageMap<_, Identity, T::AccountId, u64, ValueQuery>;
+
     #[pallet::storage]
-    pub type SenateMembers<T: Config> = StorageMap<_, Identity, T::AccountId, (), ValueQuery>;
+    pub type SenateConfig<T: Config> = StorageValue<_, SenateConfiguration, ValueQuery>;
+
+    /// Accounts running in the next senate election, at most `MAX_SENATE_CANDIDATES`.
+    #[pallet::storage]
+    pub type SenateCandidates<T: Config> = CountedStorageMap<_, Identity, T::AccountId, ()>;
+
+    /// The candidate each voter backs in the next senate election, at most `MAX_SENATE_VOTERS`.
+    #[pallet::storage]
+    pub type SenateVotes<T: Config> = CountedStorageMap<_, Identity, T::AccountId, T::AccountId>;
 
     // --- Extrinsics ---
 
@@ -306,6 +419,9 @@ pub mod pallet {
             use_weights_encryption: bool,
             copier_margin: I64F64,
             max_encryption_period: Option<u64>,
+            copier_stake_fraction: Percent,
+            copier_bonds_averaging: BondsAveraging,
+            copier_delegation_fee: Option<Percent>,
         ) -> DispatchResult {
             let mut params = pallet_subspace::Pallet::subnet_params(netuid);
             params.founder = founder;
@@ -328,6 +444,9 @@ pub mod pallet {
             params.use_weights_encryption = use_weights_encryption;
             params.copier_margin = copier_margin;
             params.max_encryption_period = max_encryption_period;
+            params.copier_stake_fraction = copier_stake_fraction;
+            params.copier_bonds_averaging = copier_bonds_averaging;
+            params.copier_delegation_fee = copier_delegation_fee;
             Self::do_add_subnet_params_proposal(origin, netuid, data, params)
         }
 
@@ -421,7 +540,7 @@ pub mod pallet {
             origin: OriginFor<T>,
             module_key: T::AccountId,
         ) -> DispatchResult {
-            Self::do_remove_from_whitelist(origin, module_key)
+            Self::do_remove_from_whitelist(origin, module_key, RemovalReason::Unspecified)
         }
 
         #[pallet::call_index(13)]
@@ -442,8 +561,132 @@ pub mod pallet {
             Self::do_remove_senate_member(origin, senate_member_key)
         }
 
+        #[pallet::call_index(15)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_emission_curve_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_emission_curve_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            curve: EmissionCurve,
+        ) -> DispatchResult {
+            Self::do_add_emission_curve_proposal(origin, data, curve)
+        }
+
+        #[pallet::call_index(16)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::set_senate_configuration(), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_senate_configuration(
+            origin: OriginFor<T>,
+            config: SenateConfiguration,
+        ) -> DispatchResult {
+            Self::do_set_senate_configuration(origin, config)
+        }
+
+        #[pallet::call_index(17)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::submit_senate_candidacy(), DispatchClass::Normal, Pays::Yes))]
+        pub fn submit_senate_candidacy(origin: OriginFor<T>) -> DispatchResult {
+            Self::do_submit_senate_candidacy(origin)
+        }
+
+        #[pallet::call_index(18)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::vote_senate_candidate(), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_senate_candidate(
+            origin: OriginFor<T>,
+            candidate: T::AccountId,
+        ) -> DispatchResult {
+            Self::do_vote_senate_candidate(origin, candidate)
+        }
+
+        #[pallet::call_index(19)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::delegate_votes(), DispatchClass::Normal, Pays::Yes))]
+        pub fn delegate_votes(
+            origin: OriginFor<T>,
+            to: T::AccountId,
+            scope: DelegationScope,
+        ) -> DispatchResult {
+            Self::do_delegate_votes(origin, to, scope)
+        }
+
+        #[pallet::call_index(20)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::undelegate_votes(), DispatchClass::Normal, Pays::Yes))]
+        pub fn undelegate_votes(origin: OriginFor<T>, scope: DelegationScope) -> DispatchResult {
+            Self::do_undelegate_votes(origin, scope)
+        }
+
+        #[pallet::call_index(21)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::cancel_proposal(), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_proposal(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            Self::do_cancel_proposal(origin, proposal_id)
+        }
+
+        #[pallet::call_index(22)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::amend_proposal(), DispatchClass::Normal, Pays::Yes))]
+        pub fn amend_proposal(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+            data: Option<ProposalData<T>>,
+            metadata: Option<ProposalMetadata>,
+        ) -> DispatchResult {
+            Self::do_amend_proposal(origin, proposal_id, data, metadata)
+        }
+
+        #[pallet::call_index(23)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::set_enactment_delays(), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_enactment_delays(
+            origin: OriginFor<T>,
+            delays: EnactmentDelays,
+        ) -> DispatchResult {
+            Self::do_set_enactment_delays(origin, delays)
+        }
+
+        #[pallet::call_index(24)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::fast_track_enactment(), DispatchClass::Normal, Pays::No))]
+        pub fn fast_track_enactment(
+            origin: OriginFor<T>,
+            proposal_id: ProposalId,
+        ) -> DispatchResult {
+            Self::do_fast_track_enactment(origin, proposal_id)
+        }
+
+        #[pallet::call_index(25)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::cancel_enactment(), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_enactment(origin: OriginFor<T>, proposal_id: ProposalId) -> DispatchResult {
+            Self::do_cancel_enactment(origin, proposal_id)
+        }
+
+        #[pallet::call_index(26)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::open_dao_application_vote(), DispatchClass::Normal, Pays::No))]
+        pub fn open_dao_application_vote(origin: OriginFor<T>, id: u64) -> DispatchResult {
+            Self::do_open_dao_application_vote(origin, id)
+        }
+
+        #[pallet::call_index(27)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::vote_dao_application(), DispatchClass::Normal, Pays::Yes))]
+        pub fn vote_dao_application(origin: OriginFor<T>, id: u64, agree: bool) -> DispatchResult {
+            Self::do_vote_dao_application(origin, id, agree)
+        }
+
+        #[pallet::call_index(28)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_grant_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_grant_proposal(
+            origin: OriginFor<T>,
+            data: Vec<u8>,
+            recipient: T::AccountId,
+            milestones: Vec<u64>,
+        ) -> DispatchResult {
+            Self::do_add_grant_proposal(origin, data, recipient, milestones)
+        }
+
+        #[pallet::call_index(29)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::attest_grant_milestone(), DispatchClass::Normal, Pays::No))]
+        pub fn attest_grant_milestone(
+            origin: OriginFor<T>,
+            grant_id: u64,
+            milestone: u32,
+        ) -> DispatchResult {
+            Self::do_attest_grant_milestone(origin, grant_id, milestone)
+        }
+
         #[pallet::call_index(30)]
-        #[pallet::weight(<T as Config>::WeightInfo::add_transfer_dao_treasury_proposal())]
+        #[pallet::weight(<T as Config>::WeightInfo::create_payment_schedule())]
         pub fn create_payment_schedule(
             origin: OriginFor<T>,
             recipient: T::AccountId,
@@ -452,29 +695,15 @@ pub mod pallet {
             payment_interval: BlockNumberFor<T>,
             remaining_payments: u32,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-            ensure!(
-                !payment_interval.is_zero(),
-                Error::<T>::InvalidPaymentInterval
-            );
-
-            let schedule = ScheduledPayment::new(
-                recipient.clone(),
+            Self::do_create_payment_schedule(
+                origin,
+                recipient,
                 amount,
                 first_payment_in_blocks,
                 payment_interval,
                 remaining_payments,
-                frame_system::Pallet::<T>::block_number(),
-            );
-            let schedule_id = NextPaymentScheduleId::<T>::mutate(|id| {
-                let current = *id;
-                *id = id.saturating_add(1);
-                current
-            });
-
-            PaymentSchedules::<T>::insert(schedule_id, schedule);
-            Self::deposit_event(Event::PaymentScheduleCreated { schedule_id });
-            Ok(())
+                PaymentDenomination::Native,
+            )
         }
 
         #[pallet::call_index(31)]
@@ -490,6 +719,105 @@ pub mod pallet {
             Self::deposit_event(Event::PaymentScheduleCancelled { schedule_id });
             Ok(())
         }
+
+        #[pallet::call_index(32)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::cancel_grant(), DispatchClass::Normal, Pays::Yes))]
+        pub fn cancel_grant(origin: OriginFor<T>, grant_id: u64) -> DispatchResult {
+            Self::do_cancel_grant(origin, grant_id)
+        }
+
+        #[pallet::call_index(33)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::pause_payment_schedule(), DispatchClass::Normal, Pays::Yes))]
+        pub fn pause_payment_schedule(origin: OriginFor<T>, schedule_id: u64) -> DispatchResult {
+            Self::do_pause_payment_schedule(origin, schedule_id)
+        }
+
+        #[pallet::call_index(34)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::resume_payment_schedule(), DispatchClass::Normal, Pays::Yes))]
+        pub fn resume_payment_schedule(origin: OriginFor<T>, schedule_id: u64) -> DispatchResult {
+            Self::do_resume_payment_schedule(origin, schedule_id)
+        }
+
+        #[pallet::call_index(35)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::set_payment_oracle(), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_payment_oracle(
+            origin: OriginFor<T>,
+            oracle: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::do_set_payment_oracle(origin, oracle)
+        }
+
+        #[pallet::call_index(36)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::submit_reference_price(), DispatchClass::Operational, Pays::Yes))]
+        pub fn submit_reference_price(
+            origin: OriginFor<T>,
+            nanos_per_unit: u64,
+        ) -> DispatchResultWithPostInfo {
+            Self::do_submit_reference_price(origin, nanos_per_unit)
+        }
+
+        #[pallet::call_index(37)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_subnet_treasury_spend_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_subnet_treasury_spend_proposal(
+            origin: OriginFor<T>,
+            subnet_id: u16,
+            data: Vec<u8>,
+            recipient: T::AccountId,
+            amount: u64,
+        ) -> DispatchResult {
+            Self::do_add_subnet_treasury_spend_proposal(origin, subnet_id, data, recipient, amount)
+        }
+
+        #[pallet::call_index(38)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::remove_from_whitelist_with_reason(), DispatchClass::Normal, Pays::No))]
+        pub fn remove_from_whitelist_with_reason(
+            origin: OriginFor<T>,
+            module_key: T::AccountId,
+            reason: RemovalReason,
+        ) -> DispatchResult {
+            Self::do_remove_from_whitelist(origin, module_key, reason)
+        }
+
+        #[pallet::call_index(39)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::set_application_vote_configuration(), DispatchClass::Normal, Pays::Yes))]
+        pub fn set_application_vote_configuration(
+            origin: OriginFor<T>,
+            config: ApplicationVoteConfiguration,
+        ) -> DispatchResult {
+            Self::do_set_application_vote_configuration(origin, config)
+        }
+
+        #[pallet::call_index(40)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::add_founder_share_routing_proposal(), DispatchClass::Normal, Pays::No))]
+        pub fn add_founder_share_routing_proposal(
+            origin: OriginFor<T>,
+            subnet_id: u16,
+            data: Vec<u8>,
+            to_treasury: bool,
+        ) -> DispatchResult {
+            Self::do_add_founder_share_routing_proposal(origin, subnet_id, data, to_treasury)
+        }
+
+        #[pallet::call_index(41)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_reference_payment_schedule())]
+        pub fn create_reference_payment_schedule(
+            origin: OriginFor<T>,
+            recipient: T::AccountId,
+            amount: u64,
+            first_payment_in_blocks: BlockNumberFor<T>,
+            payment_interval: BlockNumberFor<T>,
+            remaining_payments: u32,
+        ) -> DispatchResult {
+            Self::do_create_payment_schedule(
+                origin,
+                recipient,
+                amount,
+                first_payment_in_blocks,
+                payment_interval,
+                remaining_payments,
+                PaymentDenomination::Reference,
+            )
+        }
     }
 
     // --- Events ---
@@ -524,6 +852,25 @@ pub mod pallet {
             schedule_id: u64,
             /// Error that caused the payment to fail
             error: DispatchError,
+            /// Block of the next attempt, None if the schedule was paused
+            retry_at: Option<BlockNumberFor<T>>,
+        },
+        /// A payment schedule was paused
+        PaymentSchedulePaused {
+            /// ID of the paused schedule
+            schedule_id: u64,
+        },
+        /// A payment schedule was resumed
+        PaymentScheduleResumed {
+            /// ID of the resumed schedule
+            schedule_id: u64,
+        },
+        /// The payment oracle was changed
+        PaymentOracleSet(Option<T::AccountId>),
+        /// The payment oracle submitted a new reference unit price
+        ReferencePriceUpdated {
+            /// Amount of nanos worth one reference unit
+            nanos_per_unit: u64,
         },
         /// A new proposal has been created.
         ProposalCreated(ProposalId),
@@ -537,6 +884,42 @@ pub mod pallet {
         ProposalRefusedBySenate(ProposalId),
         /// A proposal has expired.
         ProposalExpired(ProposalId),
+        /// A proposal did not reach the participation quorum.
+        ProposalFailedQuorum(ProposalId),
+        /// A proposal has been cancelled by its proposer.
+        ProposalCancelled(ProposalId),
+        /// A proposal has been amended by its proposer, discarding its votes.
+        ProposalAmended(ProposalId),
+        /// An accepted proposal will take effect at the given block.
+        ProposalEnactmentScheduled { proposal_id: ProposalId, block: u64 },
+        /// An accepted proposal took effect.
+        ProposalEnacted(ProposalId),
+        /// An accepted proposal could not be applied.
+        ProposalEnactmentFailed(ProposalId),
+        /// The enactment of an accepted proposal was cancelled.
+        ProposalEnactmentCancelled(ProposalId),
+        /// A senate member voted to enact a pending proposal right away.
+        ProposalFastTrackVoted {
+            proposal_id: ProposalId,
+            member: T::AccountId,
+        },
+        /// The enactment delays were updated
+        EnactmentDelaysUpdated,
+        /// An accepted proposal created a grant
+        GrantCreated {
+            grant_id: u64,
+            proposal_id: ProposalId,
+        },
+        /// A grant milestone was attested and paid out
+        GrantMilestoneReleased {
+            grant_id: u64,
+            milestone: u32,
+            amount: u64,
+        },
+        /// Every milestone of a grant was paid out
+        GrantCompleted(u64),
+        /// A grant was cancelled and its unreleased funds sent back to the treasury
+        GrantCancelled { grant_id: u64, clawback: u64 },
         /// A vote has been cast on a proposal.
         ProposalVoted(u64, T::AccountId, bool),
         /// A vote has been unregistered from a proposal.
@@ -544,14 +927,54 @@ pub mod pallet {
         /// A module account has been added to the whitelist.
         WhitelistModuleAdded(T::AccountId),
         /// A module account has been removed from the whitelist.
-        WhitelistModuleRemoved(T::AccountId),
+        WhitelistModuleRemoved(T::AccountId, RemovalReason),
         /// A new application has been created.
         ApplicationCreated(u64),
+        /// An application has been accepted.
+        ApplicationAccepted(u64),
+        /// An application has been refused.
+        ApplicationRefused(u64),
+        /// An application expired before a decision was made.
+        ApplicationExpired(u64),
+        /// An application is now decided by a stake-weighted vote.
+        ApplicationVoteOpened(u64),
+        /// A vote has been cast on an application.
+        ApplicationVoted {
+            application_id: u64,
+            voter: T::AccountId,
+            agree: bool,
+        },
+        /// The quorum and threshold of application votes were updated.
+        ApplicationVoteConfigurationUpdated,
 
         /// A new senate member has been added
         SenateMemberAdded(T::AccountId),
         /// A senate member was removed
         SenateMemberRemoved(T::AccountId),
+        /// The term of a senate member ended
+        SenateMemberTermEnded(T::AccountId),
+        /// The senate configuration was updated
+        SenateConfigurationUpdated,
+        /// An account is running in the next senate election
+        SenateCandidacySubmitted(T::AccountId),
+        /// A voter backed a senate candidate
+        SenateCandidateVoted {
+            voter: T::AccountId,
+            candidate: T::AccountId,
+        },
+        /// A senate election finished, filling the open seats with the elected accounts
+        SenateElectionFinished { elected: Vec<T::AccountId> },
+        /// An account delegated its votes on the given scope to a representative
+        VotesDelegated {
+            delegator: T::AccountId,
+            representative: T::AccountId,
+            scope: DelegationScope,
+        },
+        /// An account removed its vote delegation on the given scope
+        VotesUndelegated {
+            delegator: T::AccountId,
+            scope: DelegationScope,
+        },
     }
 
     // ---  Errors ---
@@ -562,6 +985,18 @@ pub mod pallet {
         InvalidPaymentInterval,
         /// The payment schedule with the given ID was not found
         PaymentScheduleNotFound,
+        /// The payment schedule is not paused
+        PaymentScheduleNotPaused,
+        /// The operation can only be performed by the payment oracle
+        NotPaymentOracle,
+        /// The reference unit price must be greater than zero
+        InvalidReferencePrice,
+        /// No reference unit price was submitted yet
+        ReferencePriceNotSet,
+        /// The reference unit price is too old to be used for payments
+        ReferencePriceStale,
+        /// The subnet treasury does not have enough funds for the spend
+        InsufficientSubnetTreasuryFunds,
         /// The proposal is already finished. Do not retry.
         ProposalIsFinished,
         /// Invalid parameters were provided to the finalization process.
@@ -572,6 +1007,8 @@ pub mod pallet {
         InvalidProposalCost,
         /// Negative expiration when setting global or subnet governance configuration.
         InvalidProposalExpiration,
+        /// Approval thresholds must require at least half of the participating stake.
+        InvalidApprovalThreshold,
         /// Key doesn't have enough tokens to create a proposal.
         NotEnoughBalanceToPropose,
         /// Proposal data is empty.
@@ -631,6 +1068,59 @@ pub mod pallet {
         SenateMemberExists,
         /// Senate Member doesn't exist so can't be removed
         SenateMemberNotFound,
+        /// All senate seats are taken
+        SenateFull,
+        /// The senate configuration is invalid
+        InvalidSenateConfiguration,
+        /// The account is already running in the next senate election
+        AlreadySenateCandidate,
+        /// The account is not running in the next senate election
+        NotSenateCandidate,
+        /// An account can't delegate its votes to itself
+        SelfDelegation,
+        /// The representative is delegating its own votes on the scope, or the account is
+        /// already a representative on it. Delegations are limited to a single hop.
+        DelegationChainTooLong,
+        /// The delegation scope refers to a subnet that doesn't exist
+        InvalidDelegationScope,
+        /// The account is not delegating its votes on the given scope
+        VoteDelegationNotFound,
+        /// The representative already has the maximum number of delegators on the scope
+        TooManyDelegators,
+        /// Only the proposer can cancel or amend a proposal
+        NotProposer,
+        /// An amendment can't change the kind or the subnet of a proposal
+        ProposalKindMismatch,
+        /// An amendment has to change the data or the metadata of the proposal
+        ProposalNotAmended,
+        /// The operation can only be performed by a senate member
+        NotSenateMember,
+        /// The proposal is not waiting to be enacted
+        NotPendingEnactment,
+        /// The application is already decided by a vote
+        ApplicationVoteAlreadyOpen,
+        /// The application is not decided by a vote
+        ApplicationVoteNotOpen,
+        /// A grant needs between one and 16 milestones, none of them empty
+        InvalidGrant,
+        /// The grant with the given ID was not found
+        GrantNotFound,
+        /// The grant has no milestone with the given index
+        InvalidMilestone,
+        /// The milestone was already paid out
+        MilestoneAlreadyReleased,
+        /// The operation can only be performed by the curator or a senate member
+        NotCuratorOrSenateMember,
+        /// The next senate election has the maximum number of candidates
+        TooManySenateCandidates,
+        /// The next senate election has the maximum number of voters
+        TooManySenateVoters,
+        /// A veto-only senate can't fast-track proposals
+        SenateVetoOnly,
+        /// The emission curve is malformed, see `EmissionCurve::validate`
+        InvalidEmissionCurve,
+        /// The application vote has the maximum number of voters
+        TooManyApplicationVoters,
     }
 }
 
@@ -645,6 +1135,13 @@ impl<T: Config> Pallet<T> {
             config.proposal_expiration > 0,
             Error::<T>::InvalidProposalExpiration
         );
+        ensure!(
+            config
+                .approval_thresholds
+                .iter()
+                .all(|threshold| threshold >= Percent::from_percent(50)),
+            Error::<T>::InvalidApprovalThreshold
+        );
         Ok(config)
     }
 }
@@ -131,6 +131,10 @@ pub mod v3 {
 
         #[storage_alias]
         pub type Proposals<T: Config> = StorageMap<Pallet<T>, Identity, ProposalId, Proposal<T>>;
+
+        #[storage_alias]
+        pub type SenateMembers<T: Config> =
+            StorageMap<Pallet<T>, Identity, <T as frame_system::Config>::AccountId, (), ValueQuery>;
     }
 
     pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
@@ -196,7 +200,7 @@ pub mod v3 {
                         },
                         v3::old_storage::ProposalStatus::<T>::Expired => ProposalStatus::Expired,
                     },
-                    metadata: old.metadata,
+                    metadata: ProposalMetadata::from_link(old.metadata.into_inner()),
                     proposal_cost: old.proposal_cost,
                     creation_block: old.creation_block,
                 })
@@ -255,7 +259,7 @@ pub mod v3 {
                 let bytes = Vec::from(&key[..]);
                 match <T::AccountId as Decode>::decode(&mut &bytes[..]) {
                     Ok(account_id) => {
-                        crate::SenateMembers::<T>::insert(account_id, ());
+                        old_storage::SenateMembers::<T>::insert(account_id, ());
                     }
                     Err(_) => {
                         log::error!("Failed to decode account ID");
@@ -268,3 +272,740 @@ pub mod v3 {
         }
     }
 }
+
+pub mod v4 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::{pallet_prelude::TypeInfo, BoundedVec, DebugNoBound};
+        use pallet_subspace::params::burn::GeneralBurnConfiguration;
+        use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+        use proposal::{ProposalId, ProposalStatus};
+        use sp_runtime::Percent;
+
+        #[derive(Clone, TypeInfo, Decode, Encode, MaxEncodedLen)]
+        pub struct GovernanceConfiguration {
+            pub proposal_cost: u64,
+            pub proposal_expiration: u32,
+            pub vote_mode: VoteMode,
+            pub proposal_reward_treasury_allocation: Percent,
+            pub max_proposal_reward_treasury_allocation: u64,
+            pub proposal_reward_interval: u64,
+        }
+
+        impl From<GovernanceConfiguration> for crate::GovernanceConfiguration {
+            fn from(old: GovernanceConfiguration) -> Self {
+                Self {
+                    proposal_cost: old.proposal_cost,
+                    proposal_expiration: old.proposal_expiration,
+                    vote_mode: old.vote_mode,
+                    proposal_reward_treasury_allocation: old.proposal_reward_treasury_allocation,
+                    max_proposal_reward_treasury_allocation: old
+                        .max_proposal_reward_treasury_allocation,
+                    proposal_reward_interval: old.proposal_reward_interval,
+                    // A disabled quorum would let proposals nobody voted on pass
+                    quorum: Self::default().quorum,
+                    approval_thresholds: Default::default(),
+                }
+            }
+        }
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct GlobalParams<T: Config> {
+            pub max_name_length: u16,
+            pub min_name_length: u16,
+            pub max_allowed_subnets: u16,
+            pub max_allowed_modules: u16,
+            pub max_registrations_per_block: u16,
+            pub max_allowed_weights: u16,
+            pub floor_stake_delegation_fee: Percent,
+            pub floor_validator_weight_fee: Percent,
+            pub floor_founder_share: u8,
+            pub min_weight_stake: u64,
+            pub curator: T::AccountId,
+            pub general_subnet_application_cost: u64,
+            pub subnet_immunity_period: u64,
+            pub governance_config: GovernanceConfiguration,
+            pub kappa: u16,
+            pub rho: u16,
+        }
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct SubnetParams<T: Config> {
+            pub founder: T::AccountId,
+            pub founder_share: u16,
+            pub immunity_period: u16,
+            pub incentive_ratio: u16,
+            pub max_allowed_uids: u16,
+            pub max_allowed_weights: u16,
+            pub min_allowed_weights: u16,
+            pub max_weight_age: u64,
+            pub name: BoundedVec<u8, ConstU32<256>>,
+            pub metadata: Option<BoundedVec<u8, ConstU32<120>>>,
+            pub tempo: u16,
+            pub maximum_set_weight_calls_per_epoch: Option<u16>,
+            pub bonds_ma: u64,
+            pub module_burn_config: GeneralBurnConfiguration<T>,
+            pub min_validator_stake: u64,
+            pub max_allowed_validators: Option<u16>,
+            pub governance_config: GovernanceConfiguration,
+            pub use_weights_encryption: bool,
+            pub copier_margin: I64F64,
+            pub max_encryption_period: Option<u64>,
+        }
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub enum ProposalData<T: Config> {
+            GlobalCustom,
+            GlobalParams(GlobalParams<T>),
+            SubnetCustom {
+                subnet_id: SubnetId,
+            },
+            SubnetParams {
+                subnet_id: SubnetId,
+                params: SubnetParams<T>,
+            },
+            TransferDaoTreasury {
+                account: T::AccountId,
+                amount: u64,
+            },
+        }
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct Proposal<T: Config> {
+            pub id: ProposalId,
+            pub proposer: T::AccountId,
+            pub expiration_block: u64,
+            pub data: ProposalData<T>,
+            pub status: ProposalStatus<T>,
+            pub metadata: BoundedVec<u8, ConstU32<256>>,
+            pub proposal_cost: u64,
+            pub creation_block: u64,
+        }
+    }
+
+    /// Proposal data in the layout read by the v9 migration, before the copier parameters.
+    fn migrate_data<T: Config>(
+        old: old_storage::ProposalData<T>,
+    ) -> v9::old_storage::ProposalData<T> {
+        use v9::old_storage::{ProposalData, SubnetParams};
+
+        match old {
+            old_storage::ProposalData::GlobalCustom => ProposalData::GlobalCustom,
+            old_storage::ProposalData::GlobalParams(p) => {
+                ProposalData::GlobalParams(pallet_subspace::GlobalParams {
+                    max_name_length: p.max_name_length,
+                    min_name_length: p.min_name_length,
+                    max_allowed_subnets: p.max_allowed_subnets,
+                    max_allowed_modules: p.max_allowed_modules,
+                    max_registrations_per_block: p.max_registrations_per_block,
+                    max_allowed_weights: p.max_allowed_weights,
+                    floor_stake_delegation_fee: p.floor_stake_delegation_fee,
+                    floor_validator_weight_fee: p.floor_validator_weight_fee,
+                    floor_founder_share: p.floor_founder_share,
+                    min_weight_stake: p.min_weight_stake,
+                    curator: p.curator,
+                    general_subnet_application_cost: p.general_subnet_application_cost,
+                    subnet_immunity_period: p.subnet_immunity_period,
+                    governance_config: p.governance_config.into(),
+                    kappa: p.kappa,
+                    rho: p.rho,
+                })
+            }
+            old_storage::ProposalData::SubnetCustom { subnet_id } => {
+                ProposalData::SubnetCustom { subnet_id }
+            }
+            old_storage::ProposalData::SubnetParams {
+                subnet_id,
+                params: p,
+            } => ProposalData::SubnetParams {
+                subnet_id,
+                params: SubnetParams {
+                    founder: p.founder,
+                    founder_share: p.founder_share,
+                    immunity_period: p.immunity_period,
+                    incentive_ratio: p.incentive_ratio,
+                    max_allowed_uids: p.max_allowed_uids,
+                    max_allowed_weights: p.max_allowed_weights,
+                    min_allowed_weights: p.min_allowed_weights,
+                    max_weight_age: p.max_weight_age,
+                    name: p.name,
+                    metadata: p.metadata,
+                    tempo: p.tempo,
+                    maximum_set_weight_calls_per_epoch: p.maximum_set_weight_calls_per_epoch,
+                    bonds_ma: p.bonds_ma,
+                    module_burn_config: p.module_burn_config,
+                    min_validator_stake: p.min_validator_stake,
+                    max_allowed_validators: p.max_allowed_validators,
+                    governance_config: p.governance_config.into(),
+                    use_weights_encryption: p.use_weights_encryption,
+                    copier_margin: p.copier_margin,
+                    max_encryption_period: p.max_encryption_period,
+                },
+            },
+            old_storage::ProposalData::TransferDaoTreasury { account, amount } => {
+                ProposalData::TransferDaoTreasury { account, amount }
+            }
+        }
+    }
+
+    /// Adds the quorum and approval thresholds to every stored governance configuration.
+    pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (3, 4);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (7, 8);
+
+            if on_chain_version != from {
+                log::info!("Storage v4 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let _ = GlobalGovernanceConfig::<T>::translate(
+                |old: Option<old_storage::GovernanceConfiguration>| old.map(Into::into),
+            );
+
+            let mut count: u64 = 1;
+            SubnetGovernanceConfig::<T>::translate(
+                |_key, old: old_storage::GovernanceConfiguration| {
+                    count = count.saturating_add(1);
+                    Some(old.into())
+                },
+            );
+
+            // Written in the layout expected by the v6 migration
+            v6::old_storage::Proposals::<T>::translate(|_key, old: old_storage::Proposal<T>| {
+                count = count.saturating_add(1);
+                Some(v6::old_storage::Proposal {
+                    id: old.id,
+                    proposer: old.proposer,
+                    expiration_block: old.expiration_block,
+                    data: migrate_data(old.data),
+                    status: old.status,
+                    metadata: old.metadata,
+                    proposal_cost: old.proposal_cost,
+                    creation_block: old.creation_block,
+                })
+            });
+
+            log::info!("Migrated to v4");
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
+
+pub mod v5 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    /// Gives every existing senate member a full term, starting at the upgrade.
+    pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (4, 5);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (8, 9);
+
+            if on_chain_version != from {
+                log::info!("Storage v5 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let block_number: u64 = <frame_system::Pallet<T>>::block_number()
+                .try_into()
+                .ok()
+                .expect("blockchain won't pass 2 ^ 64 blocks");
+            let term_end = block_number.saturating_add(SenateConfig::<T>::get().term_length);
+
+            let mut count: u64 = 1;
+            SenateMembers::<T>::translate(|_key, ()| {
+                count = count.saturating_add(1);
+                Some(term_end)
+            });
+
+            log::info!("Migrated to v5");
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
+
+pub mod v6 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+    use proposal::ProposalMetadata;
+
+    use super::*;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::{
+            pallet_prelude::{Identity, TypeInfo},
+            storage_alias, BoundedVec, DebugNoBound,
+        };
+        use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+        use proposal::{ProposalId, ProposalStatus};
+        // The proposal data keeps its layout until the v9 migration
+        use v9::old_storage::ProposalData;
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct Proposal<T: Config> {
+            pub id: ProposalId,
+            pub proposer: T::AccountId,
+            pub expiration_block: u64,
+            pub data: ProposalData<T>,
+            pub status: ProposalStatus<T>,
+            pub metadata: BoundedVec<u8, ConstU32<256>>,
+            pub proposal_cost: u64,
+            pub creation_block: u64,
+        }
+
+        #[storage_alias]
+        pub type Proposals<T: Config> = StorageMap<Pallet<T>, Identity, ProposalId, Proposal<T>>;
+    }
+
+    /// Moves the raw proposal metadata into the link of the structured metadata.
+    pub struct MigrateToV6<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV6<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (5, 6);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (9, 10);
+
+            if on_chain_version != from {
+                log::info!("Storage v6 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let mut count: u64 = 1;
+            // Written in the layout expected by the v9 migration
+            v9::old_storage::Proposals::<T>::translate(|_key, old: old_storage::Proposal<T>| {
+                count = count.saturating_add(1);
+                Some(v9::old_storage::Proposal {
+                    id: old.id,
+                    proposer: old.proposer,
+                    expiration_block: old.expiration_block,
+                    data: old.data,
+                    status: old.status,
+                    metadata: ProposalMetadata::from_link(old.metadata.into_inner()),
+                    proposal_cost: old.proposal_cost,
+                    creation_block: old.creation_block,
+                })
+            });
+
+            log::info!("Migrated to v6");
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
+
+pub mod v7 {
+    use dao::{ApplicationStatus, CuratorApplication, RemovalReason};
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::{pallet_prelude::TypeInfo, BoundedVec};
+        use parity_scale_codec::{Decode, Encode};
+
+        #[derive(Encode, Decode, TypeInfo)]
+        pub enum ApplicationStatus {
+            Pending,
+            Accepted,
+            Refused,
+            Removed,
+        }
+
+        #[derive(Encode, Decode, TypeInfo)]
+        pub struct CuratorApplication<T: Config> {
+            pub id: u64,
+            pub user_id: T::AccountId,
+            pub paying_for: T::AccountId,
+            pub data: BoundedVec<u8, ConstU32<256>>,
+            pub status: ApplicationStatus,
+            pub application_cost: u64,
+            pub block_number: u64,
+        }
+    }
+
+    /// Gives the removed applications an unspecified removal reason.
+    pub struct MigrateToV7<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV7<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (6, 7);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (10, 11);
+
+            if on_chain_version != from {
+                log::info!("Storage v7 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let mut count: u64 = 1;
+            CuratorApplications::<T>::translate(|_key, old: old_storage::CuratorApplication<T>| {
+                count = count.saturating_add(1);
+                Some(CuratorApplication {
+                    id: old.id,
+                    user_id: old.user_id,
+                    paying_for: old.paying_for,
+                    data: old.data,
+                    status: match old.status {
+                        old_storage::ApplicationStatus::Pending => ApplicationStatus::Pending,
+                        old_storage::ApplicationStatus::Accepted => ApplicationStatus::Accepted,
+                        old_storage::ApplicationStatus::Refused => ApplicationStatus::Refused,
+                        old_storage::ApplicationStatus::Removed => ApplicationStatus::Removed {
+                            reason: RemovalReason::Unspecified,
+                        },
+                    },
+                    application_cost: old.application_cost,
+                    block_number: old.block_number,
+                })
+            });
+
+            log::info!("Migrated to v7");
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
+
+pub mod v8 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+    use payments::PaymentDenomination;
+
+    use super::*;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_system::pallet_prelude::BlockNumberFor;
+        use parity_scale_codec::{Decode, Encode};
+
+        #[derive(Encode, Decode)]
+        pub struct ScheduledPayment<T: Config> {
+            pub recipient: T::AccountId,
+            pub amount: u64,
+            pub next_payment_block: BlockNumberFor<T>,
+            pub payment_interval: BlockNumberFor<T>,
+            pub remaining_payments: u32,
+        }
+    }
+
+    /// Denominates the existing payment schedules in native tokens and starts them with a clean
+    /// retry record.
+    pub struct MigrateToV8<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV8<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (7, 8);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (11, 12);
+
+            if on_chain_version != from {
+                log::info!("Storage v8 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let mut count: u64 = 1;
+            PaymentSchedules::<T>::translate(|_key, old: old_storage::ScheduledPayment<T>| {
+                count = count.saturating_add(1);
+                Some(ScheduledPayment {
+                    recipient: old.recipient,
+                    amount: old.amount,
+                    next_payment_block: old.next_payment_block,
+                    payment_interval: old.payment_interval,
+                    remaining_payments: old.remaining_payments,
+                    denomination: PaymentDenomination::Native,
+                    failed_attempts: 0,
+                    paused: false,
+                })
+            });
+
+            log::info!("Migrated to v8");
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
+
+pub mod v9 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+    use proposal::{Proposal, ProposalData};
+
+    use super::*;
+
+    pub mod old_storage {
+        use super::*;
+        use frame_support::{
+            pallet_prelude::{Identity, TypeInfo},
+            storage_alias, BoundedVec, DebugNoBound,
+        };
+        use grants::MAX_GRANT_MILESTONES;
+        use pallet_subnet_emission_api::EmissionCurve;
+        use pallet_subspace::params::burn::GeneralBurnConfiguration;
+        use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+        use proposal::{ProposalId, ProposalMetadata, ProposalStatus};
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct SubnetParams<T: Config> {
+            pub founder: T::AccountId,
+            pub founder_share: u16,
+            pub immunity_period: u16,
+            pub incentive_ratio: u16,
+            pub max_allowed_uids: u16,
+            pub max_allowed_weights: u16,
+            pub min_allowed_weights: u16,
+            pub max_weight_age: u64,
+            pub name: BoundedVec<u8, ConstU32<256>>,
+            pub metadata: Option<BoundedVec<u8, ConstU32<120>>>,
+            pub tempo: u16,
+            pub maximum_set_weight_calls_per_epoch: Option<u16>,
+            pub bonds_ma: u64,
+            pub module_burn_config: GeneralBurnConfiguration<T>,
+            pub min_validator_stake: u64,
+            pub max_allowed_validators: Option<u16>,
+            pub governance_config: GovernanceConfiguration,
+            pub use_weights_encryption: bool,
+            pub copier_margin: I64F64,
+            pub max_encryption_period: Option<u64>,
+        }
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub enum ProposalData<T: Config> {
+            GlobalCustom,
+            GlobalParams(pallet_subspace::GlobalParams<T>),
+            SubnetCustom {
+                subnet_id: SubnetId,
+            },
+            SubnetParams {
+                subnet_id: SubnetId,
+                params: SubnetParams<T>,
+            },
+            TransferDaoTreasury {
+                account: T::AccountId,
+                amount: u64,
+            },
+            EmissionCurve(EmissionCurve),
+            Grant {
+                recipient: T::AccountId,
+                milestones: BoundedVec<u64, ConstU32<MAX_GRANT_MILESTONES>>,
+            },
+            SubnetTreasurySpend {
+                subnet_id: u16,
+                recipient: T::AccountId,
+                amount: u64,
+            },
+        }
+
+        #[derive(Decode, Encode, TypeInfo, DebugNoBound, MaxEncodedLen)]
+        #[scale_info(skip_type_params(T))]
+        pub struct Proposal<T: Config> {
+            pub id: ProposalId,
+            pub proposer: T::AccountId,
+            pub expiration_block: u64,
+            pub data: ProposalData<T>,
+            pub status: ProposalStatus<T>,
+            pub metadata: ProposalMetadata,
+            pub proposal_cost: u64,
+            pub creation_block: u64,
+        }
+
+        #[storage_alias]
+        pub type Proposals<T: Config> = StorageMap<Pallet<T>, Identity, ProposalId, Proposal<T>>;
+    }
+
+    fn migrate_data<T: Config>(old: old_storage::ProposalData<T>) -> ProposalData<T> {
+        match old {
+            old_storage::ProposalData::GlobalCustom => ProposalData::GlobalCustom,
+            old_storage::ProposalData::GlobalParams(params) => ProposalData::GlobalParams(params),
+            old_storage::ProposalData::SubnetCustom { subnet_id } => {
+                ProposalData::SubnetCustom { subnet_id }
+            }
+            old_storage::ProposalData::SubnetParams {
+                subnet_id,
+                params: p,
+            } => ProposalData::SubnetParams {
+                subnet_id,
+                params: pallet_subspace::SubnetParams {
+                    founder: p.founder,
+                    founder_share: p.founder_share,
+                    immunity_period: p.immunity_period,
+                    incentive_ratio: p.incentive_ratio,
+                    max_allowed_uids: p.max_allowed_uids,
+                    max_allowed_weights: p.max_allowed_weights,
+                    min_allowed_weights: p.min_allowed_weights,
+                    max_weight_age: p.max_weight_age,
+                    name: p.name,
+                    metadata: p.metadata,
+                    tempo: p.tempo,
+                    maximum_set_weight_calls_per_epoch: p.maximum_set_weight_calls_per_epoch,
+                    bonds_ma: p.bonds_ma,
+                    module_burn_config: p.module_burn_config,
+                    min_validator_stake: p.min_validator_stake,
+                    max_allowed_validators: p.max_allowed_validators,
+                    governance_config: p.governance_config,
+                    use_weights_encryption: p.use_weights_encryption,
+                    copier_margin: p.copier_margin,
+                    max_encryption_period: p.max_encryption_period,
+                    // proposals made before keep the subnet's current simulation parameters
+                    copier_stake_fraction: pallet_subspace::CopierStakeFraction::<T>::get(
+                        subnet_id,
+                    ),
+                    copier_bonds_averaging: pallet_subspace::CopierBondsAveraging::<T>::get(
+                        subnet_id,
+                    ),
+                    copier_delegation_fee: pallet_subspace::CopierDelegationFee::<T>::get(
+                        subnet_id,
+                    ),
+                },
+            },
+            old_storage::ProposalData::TransferDaoTreasury { account, amount } => {
+                ProposalData::TransferDaoTreasury { account, amount }
+            }
+            old_storage::ProposalData::EmissionCurve(curve) => ProposalData::EmissionCurve(curve),
+            old_storage::ProposalData::Grant {
+                recipient,
+                milestones,
+            } => ProposalData::Grant {
+                recipient,
+                milestones,
+            },
+            old_storage::ProposalData::SubnetTreasurySpend {
+                subnet_id,
+                recipient,
+                amount,
+            } => ProposalData::SubnetTreasurySpend {
+                subnet_id,
+                recipient,
+                amount,
+            },
+        }
+    }
+
+    /// Adds the copier simulation parameters to the subnet params of stored proposals.
+    pub struct MigrateToV9<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV9<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (8, 9);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (12, 13);
+
+            if on_chain_version != from {
+                log::info!("Storage v9 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let mut count: u64 = 1;
+            Proposals::<T>::translate(|_key, old: old_storage::Proposal<T>| {
+                count = count.saturating_add(1);
+                Some(Proposal {
+                    id: old.id,
+                    proposer: old.proposer,
+                    expiration_block: old.expiration_block,
+                    data: migrate_data(old.data),
+                    status: old.status,
+                    metadata: old.metadata,
+                    proposal_cost: old.proposal_cost,
+                    creation_block: old.creation_block,
+                })
+            });
+
+            log::info!("Migrated to v9");
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
+
+pub mod v10 {
+    use frame_support::{traits::OnRuntimeUpgrade, weights::Weight};
+
+    use super::*;
+
+    /// Builds the `VoteDelegators` index of the existing vote delegations. Delegations past
+    /// `MAX_DELEGATORS` of a representative are dropped.
+    pub struct MigrateToV10<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV10<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (9, 10);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (13, 14);
+
+            if on_chain_version != from {
+                log::info!("Storage v10 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let mut count: u64 = 1;
+            let mut dropped = Vec::new();
+            for (delegator, scope, representative) in VoteDelegations::<T>::iter() {
+                count = count.saturating_add(1);
+                let inserted = VoteDelegators::<T>::mutate(&representative, scope, |delegators| {
+                    delegators.try_insert(delegator.clone()).is_ok()
+                });
+                if !inserted {
+                    dropped.push((delegator, scope));
+                }
+            }
+
+            for (delegator, scope) in &dropped {
+                VoteDelegations::<T>::remove(delegator, scope);
+            }
+
+            log::info!(
+                "Migrated to v10, dropped {} vote delegations",
+                dropped.len()
+            );
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
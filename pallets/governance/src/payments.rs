@@ -20,6 +20,7 @@
 
 // ### 3. New Dispatchables
 // - `create_payment_schedule`: Create new payment schedules
+// - `create_reference_payment_schedule`: Create schedules paid in reference units
 // - `cancel_payment_schedule`: Cancel existing schedules
 
 // ### 4. New Events
@@ -71,23 +72,56 @@
 
 // All tests pass successfully, demonstrating the system's reliability and correctness.
 
-use crate::{Config, Event};
+use crate::*;
 use frame_support::{
     pallet_prelude::*,
     traits::{Currency, DefensiveSaturating, ExistenceRequirement},
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{ensure_root, ensure_signed, pallet_prelude::BlockNumberFor};
+use sp_runtime::traits::{Saturating, Zero};
 
 /// Default payment interval in blocks (10 days worth of blocks at ~8s block time)
 pub const BLOCKS_PER_PAYMENT_CYCLE: u32 = 108000;
 
+/// Amount of consecutive failed attempts after which a schedule is paused
+pub const MAX_PAYMENT_ATTEMPTS: u8 = 5;
+
+/// Blocks to wait before retrying a failed payment, doubled after every failed attempt
+pub const PAYMENT_RETRY_BACKOFF: u32 = 100;
+
+/// Age in blocks after which the reference price can no longer be used for payments
+/// (1 day worth of blocks at ~8s block time)
+pub const MAX_REFERENCE_PRICE_AGE: u32 = 10_800;
+
+/// Unit in which the amount of a payment schedule is denominated
+#[derive(
+    Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub enum PaymentDenomination {
+    /// The amount is paid as is
+    #[default]
+    Native,
+    /// The amount is expressed in reference units (e.g. USD cents) and converted using the price
+    /// fed by the payment oracle at the time of each payment
+    Reference,
+}
+
+/// Price of one reference unit, as fed by the payment oracle
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ReferenceUnitPrice<BlockNumber> {
+    /// Amount of native tokens, in nanos, worth one reference unit
+    pub nanos_per_unit: u64,
+    /// Block at which the price was submitted
+    pub updated_at: BlockNumber,
+}
+
 /// A scheduled payment that will be executed at regular block intervals
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct ScheduledPayment<T: Config> {
     /// Account that will receive the payment
     pub recipient: T::AccountId,
-    /// Amount to be paid
+    /// Amount to be paid, in the schedule's denomination
     pub amount: u64,
     /// Block number when the next payment should be made
     pub next_payment_block: BlockNumberFor<T>,
@@ -95,6 +129,12 @@ pub struct ScheduledPayment<T: Config> {
     pub payment_interval: BlockNumberFor<T>,
     /// Number of payments remaining (0 means indefinite)
     pub remaining_payments: u32,
+    /// Unit the amount is denominated in
+    pub denomination: PaymentDenomination,
+    /// Number of consecutive failed attempts of the current payment
+    pub failed_attempts: u8,
+    /// Paused schedules are skipped until resumed
+    pub paused: bool,
 }
 
 impl<T: Config> ScheduledPayment<T> {
@@ -113,9 +153,18 @@ impl<T: Config> ScheduledPayment<T> {
             next_payment_block: current_block.defensive_saturating_add(first_payment_in_blocks),
             payment_interval,
             remaining_payments,
+            denomination: PaymentDenomination::Native,
+            failed_attempts: 0,
+            paused: false,
         }
     }
 
+    /// Sets the unit the amount is denominated in
+    pub fn with_denomination(mut self, denomination: PaymentDenomination) -> Self {
+        self.denomination = denomination;
+        self
+    }
+
     /// Process payment if due, returns Some(Event) if payment was processed
     pub fn process_if_due(
         &mut self,
@@ -123,20 +172,34 @@ impl<T: Config> ScheduledPayment<T> {
         treasury: &T::AccountId,
         schedule_id: u64,
     ) -> Result<Option<Event<T>>, DispatchError> {
-        if current_block < self.next_payment_block {
+        if self.paused || current_block < self.next_payment_block {
             return Ok(None);
         }
 
+        let amount = match self.denomination {
+            PaymentDenomination::Native => self.amount,
+            PaymentDenomination::Reference => {
+                let price = ReferencePrice::<T>::get().ok_or(Error::<T>::ReferencePriceNotSet)?;
+                ensure!(
+                    current_block.saturating_sub(price.updated_at)
+                        <= MAX_REFERENCE_PRICE_AGE.into(),
+                    Error::<T>::ReferencePriceStale
+                );
+                self.amount.saturating_mul(price.nanos_per_unit)
+            }
+        };
+
         // Execute payment from treasury to recipient
         <T as Config>::Currency::transfer(
             treasury,
             &self.recipient,
-            self.amount.into(),
+            amount.into(),
             ExistenceRequirement::KeepAlive,
         )?;
 
         // Update next payment block
         self.next_payment_block = current_block.defensive_saturating_add(self.payment_interval);
+        self.failed_attempts = 0;
 
         // Update remaining payments
         if self.remaining_payments > 0 {
@@ -146,17 +209,178 @@ impl<T: Config> ScheduledPayment<T> {
         Ok(Some(Event::PaymentExecuted {
             schedule_id,
             recipient: self.recipient.clone(),
-            amount: self.amount,
+            amount,
             next_payment_block: self.next_payment_block,
         }))
     }
 
+    /// Records a failed payment attempt and postpones the payment with an exponential backoff.
+    /// Returns the block of the next attempt, or None if the schedule was paused because it ran
+    /// out of attempts.
+    pub fn schedule_retry(
+        &mut self,
+        current_block: BlockNumberFor<T>,
+    ) -> Option<BlockNumberFor<T>> {
+        self.failed_attempts = self.failed_attempts.saturating_add(1);
+        if self.failed_attempts >= MAX_PAYMENT_ATTEMPTS {
+            self.paused = true;
+            return None;
+        }
+
+        let backoff = PAYMENT_RETRY_BACKOFF
+            .saturating_mul(2u32.saturating_pow(u32::from(self.failed_attempts.saturating_sub(1))));
+        self.next_payment_block = current_block.defensive_saturating_add(backoff.into());
+        Some(self.next_payment_block)
+    }
+
+    /// Total amount the treasury still owes to this schedule, in nanos. Indefinite schedules
+    /// commit only to their next payment. Reference amounts are converted with the latest price.
+    pub fn liability(&self) -> Result<u64, DispatchError> {
+        let per_payment = match self.denomination {
+            PaymentDenomination::Native => self.amount,
+            PaymentDenomination::Reference => {
+                let price = ReferencePrice::<T>::get().ok_or(Error::<T>::ReferencePriceNotSet)?;
+                self.amount.saturating_mul(price.nanos_per_unit)
+            }
+        };
+
+        Ok(per_payment.saturating_mul(u64::from(self.remaining_payments.max(1))))
+    }
+
     /// Returns true if this schedule should be removed (all payments completed)
     pub fn is_completed(&self) -> bool {
         self.remaining_payments == 0
     }
 }
 
+impl<T: Config> Pallet<T> {
+    /// Total amount committed to the payment schedules, in nanos.
+    pub fn total_payment_liability() -> u64 {
+        PaymentSchedules::<T>::iter_values()
+            .filter_map(|schedule| schedule.liability().ok())
+            .fold(0u64, |acc, liability| acc.saturating_add(liability))
+    }
+
+    /// Ensures the treasury can cover the schedules already committed to plus the new one.
+    pub fn ensure_treasury_covers(schedule: &ScheduledPayment<T>) -> DispatchResult {
+        Self::ensure_treasury_covers_spend(schedule.liability()?)
+    }
+
+    /// Ensures the treasury can spend `amount` without leaving the payment schedules uncovered.
+    pub fn ensure_treasury_covers_spend(amount: u64) -> DispatchResult {
+        let liability = Self::total_payment_liability().saturating_add(amount);
+        ensure!(
+            pallet_subspace::Pallet::<T>::has_enough_balance(
+                &DaoTreasuryAddress::<T>::get(),
+                liability
+            ),
+            Error::<T>::InsufficientDaoTreasuryFunds
+        );
+
+        Ok(())
+    }
+
+    pub fn do_create_payment_schedule(
+        origin: T::RuntimeOrigin,
+        recipient: T::AccountId,
+        amount: u64,
+        first_payment_in_blocks: BlockNumberFor<T>,
+        payment_interval: BlockNumberFor<T>,
+        remaining_payments: u32,
+        denomination: PaymentDenomination,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+        ensure!(
+            !payment_interval.is_zero(),
+            Error::<T>::InvalidPaymentInterval
+        );
+
+        let schedule = ScheduledPayment::new(
+            recipient,
+            amount,
+            first_payment_in_blocks,
+            payment_interval,
+            remaining_payments,
+            frame_system::Pallet::<T>::block_number(),
+        )
+        .with_denomination(denomination);
+        Self::ensure_treasury_covers(&schedule)?;
+
+        let schedule_id = NextPaymentScheduleId::<T>::mutate(|id| {
+            let current = *id;
+            *id = id.saturating_add(1);
+            current
+        });
+
+        PaymentSchedules::<T>::insert(schedule_id, schedule);
+        Self::deposit_event(Event::PaymentScheduleCreated { schedule_id });
+        Ok(())
+    }
+
+    pub fn do_pause_payment_schedule(origin: T::RuntimeOrigin, schedule_id: u64) -> DispatchResult {
+        ensure_root(origin)?;
+
+        PaymentSchedules::<T>::try_mutate(schedule_id, |schedule| {
+            let schedule = schedule.as_mut().ok_or(Error::<T>::PaymentScheduleNotFound)?;
+            schedule.paused = true;
+            Ok::<(), DispatchError>(())
+        })?;
+
+        Self::deposit_event(Event::PaymentSchedulePaused { schedule_id });
+        Ok(())
+    }
+
+    /// Resumes a paused schedule. Payments missed while paused are made right away.
+    pub fn do_resume_payment_schedule(
+        origin: T::RuntimeOrigin,
+        schedule_id: u64,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+
+        PaymentSchedules::<T>::try_mutate(schedule_id, |schedule| {
+            let schedule = schedule.as_mut().ok_or(Error::<T>::PaymentScheduleNotFound)?;
+            ensure!(schedule.paused, Error::<T>::PaymentScheduleNotPaused);
+            schedule.paused = false;
+            schedule.failed_attempts = 0;
+            Ok::<(), DispatchError>(())
+        })?;
+
+        Self::deposit_event(Event::PaymentScheduleResumed { schedule_id });
+        Ok(())
+    }
+
+    pub fn do_set_payment_oracle(
+        origin: T::RuntimeOrigin,
+        oracle: Option<T::AccountId>,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+
+        PaymentOracle::<T>::set(oracle.clone());
+        Self::deposit_event(Event::PaymentOracleSet(oracle));
+        Ok(())
+    }
+
+    /// Updates the reference price. The fee is refunded when the caller is the oracle.
+    pub fn do_submit_reference_price(
+        origin: T::RuntimeOrigin,
+        nanos_per_unit: u64,
+    ) -> DispatchResultWithPostInfo {
+        let key = ensure_signed(origin)?;
+        ensure!(
+            PaymentOracle::<T>::get() == Some(key),
+            Error::<T>::NotPaymentOracle
+        );
+        ensure!(nanos_per_unit > 0, Error::<T>::InvalidReferencePrice);
+
+        ReferencePrice::<T>::put(ReferenceUnitPrice {
+            nanos_per_unit,
+            updated_at: frame_system::Pallet::<T>::block_number(),
+        });
+        Self::deposit_event(Event::ReferencePriceUpdated { nanos_per_unit });
+        Ok(Pays::No.into())
+    }
+}
+
 /// Type alias for the Currency balance type
 pub type BalanceOf<T> =
     <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
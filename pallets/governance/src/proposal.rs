@@ -1,14 +1,24 @@
-use crate::*;
+use crate::{
+    grants::MAX_GRANT_MILESTONES,
+    voting::{delegators_of, representative_of},
+    *,
+};
 use frame_support::{
-    dispatch::DispatchResult, ensure, sp_runtime::DispatchError, storage::with_storage_layer,
-    traits::ConstU32, BoundedBTreeMap, BoundedBTreeSet, BoundedVec, DebugNoBound,
+    dispatch::DispatchResult,
+    ensure,
+    sp_runtime::DispatchError,
+    storage::with_storage_layer,
+    traits::{ConstU32, Currency, ExistenceRequirement},
+    BoundedBTreeMap, BoundedBTreeSet, BoundedVec, DebugNoBound,
 };
 use frame_system::ensure_signed;
+use pallet_subnet_emission_api::{EmissionCurve, SubnetEmissionApi};
 use pallet_subspace::{
     Event as SubspaceEvent, GlobalParams, Pallet as PalletSubspace, SubnetParams,
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_runtime::Percent;
 use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 use substrate_fixed::types::I92F36;
 
@@ -22,11 +32,37 @@ pub struct Proposal<T: Config> {
     pub expiration_block: u64,
     pub data: ProposalData<T>,
     pub status: ProposalStatus<T>,
-    pub metadata: BoundedVec<u8, ConstU32<256>>,
+    pub metadata: ProposalMetadata,
     pub proposal_cost: u64,
     pub creation_block: u64,
 }
 
+/// Discussion metadata of a proposal, pointing to the document where it is described.
+#[derive(Clone, Debug, Default, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+pub struct ProposalMetadata {
+    /// Short human readable title.
+    pub title: BoundedVec<u8, ConstU32<128>>,
+    /// Link to the full proposal, usually an IPFS CID.
+    pub link: BoundedVec<u8, ConstU32<256>>,
+}
+
+impl ProposalMetadata {
+    /// Untitled metadata pointing to the given link.
+    pub fn from_link(link: Vec<u8>) -> Self {
+        Self {
+            title: BoundedVec::new(),
+            link: BoundedVec::truncate_from(link),
+        }
+    }
+
+    pub fn validate<T: Config>(&self) -> DispatchResult {
+        ensure!(!self.link.is_empty(), Error::<T>::ProposalDataTooSmall);
+        sp_std::str::from_utf8(&self.title).map_err(|_| Error::<T>::InvalidProposalData)?;
+        sp_std::str::from_utf8(&self.link).map_err(|_| Error::<T>::InvalidProposalData)?;
+        Ok(())
+    }
+}
+
 impl<T: Config> Proposal<T> {
     /// Whether the proposal is still active.
     #[must_use]
@@ -37,11 +73,7 @@ impl<T: Config> Proposal<T> {
     /// Returns the subnet ID that this proposal impact.s
     #[must_use]
     pub fn subnet_id(&self) -> Option<u16> {
-        match &self.data {
-            ProposalData::SubnetParams { subnet_id, .. }
-            | ProposalData::SubnetCustom { subnet_id, .. } => Some(*subnet_id),
-            _ => None,
-        }
+        self.data.subnet_id()
     }
 
     /// Marks a proposal as accepted and overrides the storage value.
@@ -57,9 +89,7 @@ impl<T: Config> Proposal<T> {
         Proposals::<T>::insert(self.id, &self);
         Pallet::<T>::deposit_event(Event::ProposalAccepted(self.id));
 
-        self.execute_proposal()?;
-
-        Ok(())
+        self.schedule_enactment(block)
     }
 
     /// Marks a proposal as accepted by the senate
@@ -71,17 +101,34 @@ impl<T: Config> Proposal<T> {
         Proposals::<T>::insert(self.id, &self);
         Pallet::<T>::deposit_event(Event::ProposalAcceptedBySenate(self.id));
 
-        self.execute_proposal()?;
-
-        Ok(())
+        self.schedule_enactment(block)
     }
 
-    fn execute_proposal(self) -> DispatchResult {
+    /// Refunds the proposer and schedules the proposal's changes after the enactment delay of
+    /// its kind, applying them right away when there is no delay.
+    fn schedule_enactment(self, block: u64) -> DispatchResult {
         PalletSubspace::<T>::add_balance_to_account(
             &self.proposer,
             PalletSubspace::<T>::u64_to_balance(self.proposal_cost).unwrap(),
         );
 
+        let delay = self.data.enactment_delay(&EnactmentDelayConfig::<T>::get());
+        if delay == 0 {
+            return self.execute_proposal();
+        }
+
+        let enactment_block = block.saturating_add(delay);
+        PendingEnactments::<T>::insert(self.id, enactment_block);
+        Pallet::<T>::deposit_event(Event::ProposalEnactmentScheduled {
+            proposal_id: self.id,
+            block: enactment_block,
+        });
+
+        Ok(())
+    }
+
+    /// Applies the changes of an accepted proposal.
+    pub(crate) fn execute_proposal(self) -> DispatchResult {
         match self.data {
             ProposalData::GlobalCustom | ProposalData::SubnetCustom { .. } => {
                 // No specific action needed for custom proposals
@@ -97,12 +144,42 @@ impl<T: Config> Proposal<T> {
                 PalletSubspace::<T>::deposit_event(SubspaceEvent::SubnetParamsUpdated(subnet_id));
             }
             ProposalData::TransferDaoTreasury { account, amount } => {
+                // the treasury may have been drawn from since the proposal was accepted
+                Pallet::<T>::ensure_treasury_covers_spend(amount)?;
                 PalletSubspace::<T>::transfer_balance_to_account(
                     &DaoTreasuryAddress::<T>::get(),
                     &account,
                     amount,
                 )?;
             }
+            ProposalData::EmissionCurve(curve) => {
+                <T as SubnetEmissionApi<T::AccountId>>::set_emission_curve(curve)?;
+            }
+            ProposalData::Grant {
+                recipient,
+                milestones,
+            } => {
+                Pallet::<T>::validate_grant(&milestones)?;
+                Pallet::<T>::create_grant(self.id, recipient, milestones)?;
+            }
+            ProposalData::SubnetTreasurySpend {
+                subnet_id,
+                recipient,
+                amount,
+            } => {
+                <T as Config>::Currency::transfer(
+                    &PalletSubspace::<T>::subnet_treasury_account(subnet_id),
+                    &recipient,
+                    amount.into(),
+                    ExistenceRequirement::AllowDeath,
+                )?;
+            }
+            ProposalData::FounderShareRouting {
+                subnet_id,
+                to_treasury,
+            } => {
+                PalletSubspace::<T>::set_founder_share_routing(subnet_id, to_treasury)?;
+            }
         }
 
         Ok(())
@@ -136,6 +213,41 @@ impl<T: Config> Proposal<T> {
         Ok(())
     }
 
+    /// Marks a proposal whose participation was below the quorum as failed.
+    pub fn fail_quorum(mut self, block: u64, stake_for: u64, stake_against: u64) -> DispatchResult {
+        ensure!(self.is_active(), Error::<T>::ProposalIsFinished);
+
+        self.status = ProposalStatus::FailedQuorum {
+            block,
+            stake_for,
+            stake_against,
+        };
+
+        Proposals::<T>::insert(self.id, &self);
+        Pallet::<T>::deposit_event(Event::ProposalFailedQuorum(self.id));
+
+        Ok(())
+    }
+
+    /// Marks a proposal as cancelled by its proposer, refunding the given amount.
+    pub fn cancel(mut self, block: u64, refund: u64) -> DispatchResult {
+        ensure!(self.is_active(), Error::<T>::ProposalIsFinished);
+
+        self.status = ProposalStatus::Cancelled { block };
+
+        if refund > 0 {
+            let Some(refund) = PalletSubspace::<T>::u64_to_balance(refund) else {
+                return Err(Error::<T>::InvalidCurrencyConversionValue.into());
+            };
+            PalletSubspace::<T>::add_balance_to_account(&self.proposer, refund);
+        }
+
+        Proposals::<T>::insert(self.id, &self);
+        Pallet::<T>::deposit_event(Event::ProposalCancelled(self.id));
+
+        Ok(())
+    }
+
     /// Marks a proposal as expired and overrides the storage value.
     pub fn expire(mut self, block_number: u64) -> DispatchResult {
         ensure!(self.is_active(), Error::<T>::ProposalIsFinished);
@@ -179,9 +291,23 @@ pub enum ProposalStatus<T: Config> {
         block: u64,
     },
     Expired,
+    /// Not enough stake took part in the vote.
+    FailedQuorum {
+        block: u64,
+        stake_for: u64,
+        stake_against: u64,
+    },
+    /// The proposer withdrew the proposal.
+    Cancelled {
+        block: u64,
+    },
+    /// The proposal was accepted, but its enactment was cancelled before it took effect.
+    EnactmentCancelled {
+        block: u64,
+    },
 }
 
-#[derive(DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
+#[derive(Clone, DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
 #[scale_info(skip_type_params(T))]
 pub enum ProposalData<T: Config> {
     GlobalCustom,
@@ -197,6 +323,69 @@ pub enum ProposalData<T: Config> {
         account: T::AccountId,
         amount: u64,
     },
+    EmissionCurve(EmissionCurve),
+    /// Treasury funds paid out to the recipient as each milestone is attested.
+    Grant {
+        recipient: T::AccountId,
+        milestones: BoundedVec<u64, ConstU32<MAX_GRANT_MILESTONES>>,
+    },
+    /// Subnet custom proposal that spends funds from the subnet treasury.
+    SubnetTreasurySpend {
+        subnet_id: u16,
+        recipient: T::AccountId,
+        amount: u64,
+    },
+    /// Whether the subnet founder share is paid into the subnet treasury instead of the founder.
+    FounderShareRouting {
+        subnet_id: u16,
+        to_treasury: bool,
+    },
+}
+
+impl<T: Config> ProposalData<T> {
+    /// Returns the subnet ID that this proposal data targets.
+    #[must_use]
+    pub fn subnet_id(&self) -> Option<u16> {
+        match self {
+            ProposalData::SubnetParams { subnet_id, .. }
+            | ProposalData::SubnetCustom { subnet_id, .. }
+            | ProposalData::SubnetTreasurySpend { subnet_id, .. }
+            | ProposalData::FounderShareRouting { subnet_id, .. } => Some(*subnet_id),
+            _ => None,
+        }
+    }
+
+    /// The share of participating stake required to accept this kind of proposal.
+    #[must_use]
+    pub fn approval_threshold(&self, thresholds: &ApprovalThresholds) -> Percent {
+        match self {
+            ProposalData::GlobalCustom => thresholds.global_custom,
+            ProposalData::GlobalParams(_) => thresholds.global_params,
+            ProposalData::SubnetCustom { .. } => thresholds.subnet_custom,
+            ProposalData::SubnetParams { .. } => thresholds.subnet_params,
+            ProposalData::TransferDaoTreasury { .. } => thresholds.transfer_dao_treasury,
+            ProposalData::EmissionCurve(_) => thresholds.emission_curve,
+            ProposalData::Grant { .. } => thresholds.grant,
+            ProposalData::SubnetTreasurySpend { .. } => thresholds.subnet_custom,
+            ProposalData::FounderShareRouting { .. } => thresholds.subnet_params,
+        }
+    }
+
+    /// Amount of blocks between the acceptance of this kind of proposal and its enactment.
+    /// Custom proposals have no on-chain effect and are never delayed.
+    #[must_use]
+    pub fn enactment_delay(&self, delays: &EnactmentDelays) -> u64 {
+        match self {
+            ProposalData::GlobalCustom | ProposalData::SubnetCustom { .. } => 0,
+            ProposalData::GlobalParams(_) => delays.global_params,
+            ProposalData::SubnetParams { .. } => delays.subnet_params,
+            ProposalData::TransferDaoTreasury { .. } => delays.transfer_dao_treasury,
+            ProposalData::EmissionCurve(_) => delays.emission_curve,
+            ProposalData::Grant { .. } => delays.grant,
+            ProposalData::SubnetTreasurySpend { .. } => delays.subnet_treasury_spend,
+            ProposalData::FounderShareRouting { .. } => delays.subnet_params,
+        }
+    }
 }
 
 #[derive(DebugNoBound, TypeInfo, Decode, Encode, MaxEncodedLen, PartialEq, Eq)]
@@ -218,7 +407,7 @@ impl<T: Config> Pallet<T> {
 
     pub fn add_proposal(
         key: T::AccountId,
-        metadata: BoundedVec<u8, ConstU32<256>>,
+        metadata: ProposalMetadata,
         data: ProposalData<T>,
     ) -> DispatchResult {
         let GovernanceConfiguration {
@@ -239,16 +428,7 @@ impl<T: Config> Pallet<T> {
 
         let proposal_id = Self::get_next_proposal_id();
         let current_block = PalletSubspace::<T>::get_current_block_number();
-        let expiration_block = current_block.saturating_add(proposal_expiration as u64);
-
-        // TODO: extract rounding function
-        let expiration_block = if expiration_block % 100 == 0 {
-            expiration_block
-        } else {
-            expiration_block
-                .saturating_add(100)
-                .saturating_sub(expiration_block.checked_rem(100).unwrap_or_default())
-        };
+        let expiration_block = expiration_block_after(current_block, proposal_expiration);
 
         let proposal = Proposal {
             id: proposal_id,
@@ -285,7 +465,7 @@ impl<T: Config> Pallet<T> {
         sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
 
         let proposal_data = ProposalData::GlobalCustom;
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
     }
 
     pub fn do_add_subnet_custom_proposal(
@@ -299,7 +479,7 @@ impl<T: Config> Pallet<T> {
         sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
 
         let proposal_data = ProposalData::SubnetCustom { subnet_id };
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
     }
 
     pub fn do_add_transfer_dao_treasury_proposal(
@@ -311,20 +491,14 @@ impl<T: Config> Pallet<T> {
         let key = ensure_signed(origin)?;
         ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
         ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
-        ensure!(
-            pallet_subspace::Pallet::<T>::has_enough_balance(
-                &DaoTreasuryAddress::<T>::get(),
-                value
-            ),
-            Error::<T>::InsufficientDaoTreasuryFunds
-        );
+        Self::ensure_treasury_covers_spend(value)?;
         sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
 
         let proposal_data = ProposalData::TransferDaoTreasury {
             amount: value,
             account: dest,
         };
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
     }
 
     pub fn do_add_global_params_proposal(
@@ -341,7 +515,22 @@ impl<T: Config> Pallet<T> {
         pallet_subspace::Pallet::check_global_params(&params)?;
 
         let proposal_data = ProposalData::GlobalParams(params);
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
+    }
+
+    pub fn do_add_emission_curve_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        curve: EmissionCurve,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        curve.validate().map_err(|_| Error::<T>::InvalidEmissionCurve)?;
+
+        let proposal_data = ProposalData::EmissionCurve(curve);
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
     }
 
     pub fn do_add_subnet_params_proposal(
@@ -367,7 +556,254 @@ impl<T: Config> Pallet<T> {
         SubnetChangeset::<T>::update(subnet_id, params.clone())?;
 
         let proposal_data = ProposalData::SubnetParams { subnet_id, params };
-        Self::add_proposal(key, BoundedVec::truncate_from(data), proposal_data)
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
+    }
+
+    pub fn do_add_subnet_treasury_spend_proposal(
+        origin: T::RuntimeOrigin,
+        subnet_id: u16,
+        data: Vec<u8>,
+        recipient: T::AccountId,
+        amount: u64,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        Self::validate_subnet_treasury_spend(subnet_id, amount)?;
+
+        let proposal_data = ProposalData::SubnetTreasurySpend {
+            subnet_id,
+            recipient,
+            amount,
+        };
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
+    }
+
+    pub fn do_add_founder_share_routing_proposal(
+        origin: T::RuntimeOrigin,
+        subnet_id: u16,
+        data: Vec<u8>,
+        to_treasury: bool,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        Self::validate_founder_share_routing(subnet_id)?;
+
+        let proposal_data = ProposalData::FounderShareRouting {
+            subnet_id,
+            to_treasury,
+        };
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
+    }
+
+    /// The founder share routing is part of the subnet treasury, so it is only decided by vote.
+    fn validate_founder_share_routing(subnet_id: u16) -> DispatchResult {
+        ensure!(
+            matches!(
+                SubnetGovernanceConfig::<T>::get(subnet_id).vote_mode,
+                VoteMode::Vote
+            ),
+            Error::<T>::NotVoteMode
+        );
+
+        Ok(())
+    }
+
+    /// Subnet treasuries can only be spent by subnets governed by vote.
+    fn validate_subnet_treasury_spend(subnet_id: u16, amount: u64) -> DispatchResult {
+        ensure!(
+            matches!(
+                SubnetGovernanceConfig::<T>::get(subnet_id).vote_mode,
+                VoteMode::Vote
+            ),
+            Error::<T>::NotVoteMode
+        );
+        ensure!(
+            PalletSubspace::<T>::has_enough_balance(
+                &PalletSubspace::<T>::subnet_treasury_account(subnet_id),
+                amount
+            ),
+            Error::<T>::InsufficientSubnetTreasuryFunds
+        );
+
+        Ok(())
+    }
+
+    pub fn do_add_grant_proposal(
+        origin: T::RuntimeOrigin,
+        data: Vec<u8>,
+        recipient: T::AccountId,
+        milestones: Vec<u64>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(!data.is_empty(), Error::<T>::ProposalDataTooSmall);
+        ensure!(data.len() <= 256, Error::<T>::ProposalDataTooLarge);
+        sp_std::str::from_utf8(&data).map_err(|_| Error::<T>::InvalidProposalData)?;
+
+        let milestones: BoundedVec<_, _> =
+            milestones.try_into().map_err(|_| Error::<T>::InvalidGrant)?;
+        Self::validate_grant(&milestones)?;
+
+        let proposal_data = ProposalData::Grant {
+            recipient,
+            milestones,
+        };
+        Self::add_proposal(key, ProposalMetadata::from_link(data), proposal_data)
+    }
+
+    /// Withdraws an open proposal. Part of the proposal cost is refunded if nobody voted yet.
+    pub fn do_cancel_proposal(origin: T::RuntimeOrigin, proposal_id: ProposalId) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let Ok(proposal) = Proposals::<T>::try_get(proposal_id) else {
+            return Err(Error::<T>::ProposalNotFound.into());
+        };
+        ensure!(proposal.proposer == key, Error::<T>::NotProposer);
+
+        let ProposalStatus::Open {
+            votes_for,
+            votes_against,
+            ..
+        } = &proposal.status
+        else {
+            return Err(Error::<T>::ProposalClosed.into());
+        };
+
+        let refund = if votes_for.is_empty() && votes_against.is_empty() {
+            ProposalCancellationRefund::<T>::get().mul_floor(proposal.proposal_cost)
+        } else {
+            0
+        };
+
+        let current_block = PalletSubspace::<T>::get_current_block_number();
+        proposal.cancel(current_block, refund)
+    }
+
+    /// Replaces the data and/or metadata of an open proposal. The proposal keeps its kind and
+    /// subnet, every vote cast so far is discarded and the voting period starts over.
+    pub fn do_amend_proposal(
+        origin: T::RuntimeOrigin,
+        proposal_id: ProposalId,
+        data: Option<ProposalData<T>>,
+        metadata: Option<ProposalMetadata>,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let Ok(mut proposal) = Proposals::<T>::try_get(proposal_id) else {
+            return Err(Error::<T>::ProposalNotFound.into());
+        };
+        ensure!(proposal.proposer == key, Error::<T>::NotProposer);
+        ensure!(proposal.is_active(), Error::<T>::ProposalClosed);
+
+        // Votes are only reset when what they were cast on changes. Custom proposals carry no
+        // data, they are described by their metadata
+        let data = data.filter(|data| *data != proposal.data);
+        let metadata = metadata.filter(|metadata| *metadata != proposal.metadata);
+        ensure!(
+            data.is_some() || metadata.is_some(),
+            Error::<T>::ProposalNotAmended
+        );
+
+        if let Some(data) = data {
+            ensure!(
+                core::mem::discriminant(&data) == core::mem::discriminant(&proposal.data)
+                    && data.subnet_id() == proposal.subnet_id(),
+                Error::<T>::ProposalKindMismatch
+            );
+            proposal.data = Self::validate_proposal_data(data)?;
+        }
+        if let Some(metadata) = metadata {
+            metadata.validate::<T>()?;
+            proposal.metadata = metadata;
+        }
+
+        proposal.status = ProposalStatus::Open {
+            votes_for: BoundedBTreeSet::new(),
+            votes_against: BoundedBTreeSet::new(),
+            stake_for: 0,
+            stake_against: 0,
+        };
+
+        // The amended proposal gets a full voting period, the wiped votes can't be left
+        // without time to be cast again
+        let proposal_expiration = GlobalGovernanceConfig::<T>::get().proposal_expiration;
+        proposal.expiration_block = expiration_block_after(
+            PalletSubspace::<T>::get_current_block_number(),
+            proposal_expiration,
+        );
+
+        Proposals::<T>::insert(proposal_id, proposal);
+        Self::deposit_event(Event::<T>::ProposalAmended(proposal_id));
+
+        Ok(())
+    }
+
+    fn validate_proposal_data(data: ProposalData<T>) -> Result<ProposalData<T>, DispatchError> {
+        match data {
+            ProposalData::GlobalCustom | ProposalData::SubnetCustom { .. } => Ok(data),
+            ProposalData::GlobalParams(mut params) => {
+                params.governance_config = Self::validate(params.governance_config)?;
+                pallet_subspace::Pallet::check_global_params(&params)?;
+                Ok(ProposalData::GlobalParams(params))
+            }
+            ProposalData::SubnetParams {
+                subnet_id,
+                mut params,
+            } => {
+                params.governance_config = Self::validate(params.governance_config)?;
+                SubnetChangeset::<T>::update(subnet_id, params.clone())?;
+                Ok(ProposalData::SubnetParams { subnet_id, params })
+            }
+            ProposalData::TransferDaoTreasury { account, amount } => {
+                Self::ensure_treasury_covers_spend(amount)?;
+                Ok(ProposalData::TransferDaoTreasury { account, amount })
+            }
+            ProposalData::EmissionCurve(curve) => {
+                curve.validate().map_err(|_| Error::<T>::InvalidEmissionCurve)?;
+                Ok(ProposalData::EmissionCurve(curve))
+            }
+            ProposalData::Grant {
+                recipient,
+                milestones,
+            } => {
+                Self::validate_grant(&milestones)?;
+                Ok(ProposalData::Grant {
+                    recipient,
+                    milestones,
+                })
+            }
+            ProposalData::SubnetTreasurySpend {
+                subnet_id, amount, ..
+            } => {
+                Self::validate_subnet_treasury_spend(subnet_id, amount)?;
+                Ok(data)
+            }
+            ProposalData::FounderShareRouting { subnet_id, .. } => {
+                Self::validate_founder_share_routing(subnet_id)?;
+                Ok(data)
+            }
+        }
+    }
+}
+
+/// Proposals are ticked every 100 blocks, so they expire on the first tick after the voting
+/// period.
+fn expiration_block_after(block: u64, proposal_expiration: u32) -> u64 {
+    let expiration_block = block.saturating_add(proposal_expiration as u64);
+    if expiration_block % 100 == 0 {
+        expiration_block
+    } else {
+        expiration_block
+            .saturating_add(100)
+            .saturating_sub(expiration_block.checked_rem(100).unwrap_or_default())
     }
 }
 
@@ -417,14 +853,11 @@ pub fn check_senate_approval<T: Config>(proposal: Proposal<T>) -> DispatchResult
         .len() as u32;
     let total_senate_members = SenateMembers::<T>::iter().count() as u32;
 
-    // Check if the senate_for count meets the 4/7 ratio of total senate members
-    let mut senate_threshold = total_senate_members.saturating_mul(4).saturating_div(7);
-    // If the threshold is exactly 50% of the total, increase by 1
-    if senate_threshold.saturating_mul(2) == total_senate_members {
-        senate_threshold = senate_threshold.saturating_add(1);
-    }
-    // Senate agreement overrides DAO (for now)
-    if senate_for >= senate_threshold {
+    let config = SenateConfig::<T>::get();
+    let senate_threshold = config.approval_ratio.mul_ceil(total_senate_members).max(1);
+
+    // Senate agreement overrides DAO, unless the senate may only veto
+    if senate_for >= senate_threshold && !config.veto_only {
         return proposal.senate_accept(block_number);
     } else if senate_against >= senate_threshold {
         return proposal.senate_refuse(block_number);
@@ -446,31 +879,53 @@ fn tick_proposal<T: Config>(
         return Err(Error::<T>::ProposalIsFinished.into());
     };
 
-    let votes_for: Vec<(T::AccountId, u64)> = votes_for
+    // Explicit delegators lend their own stake to their representative instead of the modules
+    // they stake on.
+    let subnet_id = proposal.subnet_id();
+    let is_not_delegating = |key: &T::AccountId| {
+        not_delegating.contains(key) || representative_of::<T>(key, subnet_id).is_some()
+    };
+
+    let mut votes_for: Vec<(T::AccountId, u64)> = votes_for
         .iter()
         .cloned()
         .map(|id| {
             if SenateMembers::<T>::contains_key(id.clone()) {
                 (id, 0u64)
             } else {
-                let stake = calc_stake::<T>(not_delegating, &id);
+                let stake = calc_stake_by::<T>(&is_not_delegating, &id);
                 (id, stake)
             }
         })
         .collect();
-    let votes_against: Vec<(T::AccountId, u64)> = votes_against
+    let mut votes_against: Vec<(T::AccountId, u64)> = votes_against
         .iter()
         .cloned()
         .map(|id| {
             if SenateMembers::<T>::contains_key(id.clone()) {
                 (id, 0u64)
             } else {
-                let stake = calc_stake::<T>(not_delegating, &id);
+                let stake = calc_stake_by::<T>(&is_not_delegating, &id);
                 (id, stake)
             }
         })
         .collect();
 
+    // Delegators that voted directly keep their own vote, the rest is attributed to the
+    // representative if it voted.
+    let voters: BTreeSet<T::AccountId> =
+        votes_for.iter().chain(votes_against.iter()).map(|(id, _)| id.clone()).collect();
+    for (representative, representative_stake) in
+        votes_for.iter_mut().chain(votes_against.iter_mut())
+    {
+        let represented_stake = delegators_of::<T>(representative, subnet_id)
+            .into_iter()
+            .filter(|delegator| !voters.contains(delegator))
+            .map(|delegator| pallet_subspace::Pallet::<T>::get_owned_stake(&delegator))
+            .fold(0u64, |acc, stake| acc.saturating_add(stake));
+        *representative_stake = representative_stake.saturating_add(represented_stake);
+    }
+
     let stake_for_sum: u64 = votes_for.iter().map(|(_, stake)| *stake).sum();
     let stake_against_sum: u64 = votes_against.iter().map(|(_, stake)| *stake).sum();
 
@@ -511,10 +966,26 @@ fn tick_proposal<T: Config>(
         },
     );
 
-    if stake_against_sum > stake_for_sum {
-        proposal.refuse(block_number, stake_for_sum, stake_against_sum)
-    } else {
+    let governance_config = match proposal.subnet_id() {
+        Some(subnet_id) => SubnetGovernanceConfig::<T>::get(subnet_id),
+        None => GlobalGovernanceConfig::<T>::get(),
+    };
+
+    let participation = stake_for_sum.saturating_add(stake_against_sum);
+    let eligible_stake = match proposal.subnet_id() {
+        Some(subnet_id) => PalletSubspace::<T>::get_total_subnet_stake(subnet_id),
+        None => pallet_subspace::TotalStake::<T>::get(),
+    };
+    let quorum = governance_config.quorum.mul_ceil(eligible_stake);
+    if participation < quorum {
+        return proposal.fail_quorum(block_number, stake_for_sum, stake_against_sum);
+    }
+
+    let threshold = proposal.data.approval_threshold(&governance_config.approval_thresholds);
+    if stake_for_sum >= threshold.mul_ceil(participation) {
         proposal.accept(block_number, stake_for_sum, stake_against_sum)
+    } else {
+        proposal.refuse(block_number, stake_for_sum, stake_against_sum)
     }
 }
 
@@ -530,22 +1001,30 @@ pub fn tick_proposal_rewards<T: Config>(block_number: u64) {
 }
 
 #[inline]
-fn calc_stake<T: Config>(not_delegating: &BTreeSet<T::AccountId>, voter: &T::AccountId) -> u64 {
-    let own_stake = if !not_delegating.contains(voter) {
+pub(crate) fn calc_stake<T: Config>(
+    not_delegating: &BTreeSet<T::AccountId>,
+    voter: &T::AccountId,
+) -> u64 {
+    calc_stake_by::<T>(|key| not_delegating.contains(key), voter)
+}
+
+/// The voter's own stake if it is not delegating its voting power, and the stake of the
+/// stakers that are.
+pub(crate) fn calc_stake_by<T: Config>(
+    is_not_delegating: impl Fn(&T::AccountId) -> bool,
+    voter: &T::AccountId,
+) -> u64 {
+    let own_stake = if !is_not_delegating(voter) {
         0
     } else {
         pallet_subspace::Pallet::<T>::get_owned_stake(voter)
     };
 
-    let calculate_delegated = || -> u64 {
-        PalletSubspace::<T>::get_stake_from_vector(voter)
-            .into_iter()
-            .filter(|(staker, _)| !not_delegating.contains(staker))
-            .map(|(_, stake)| stake)
-            .sum()
-    };
-
-    let delegated_stake = calculate_delegated();
+    let delegated_stake: u64 = PalletSubspace::<T>::get_stake_from_vector(voter)
+        .into_iter()
+        .filter(|(staker, _)| !is_not_delegating(staker))
+        .map(|(_, stake)| stake)
+        .sum();
 
     own_stake.saturating_add(delegated_stake)
 }
@@ -0,0 +1,267 @@
+use crate::{
+    proposal::{calc_stake_by, get_reward_allocation},
+    voting::{delegators_of, representative_of},
+    *,
+};
+use frame_support::sp_runtime::traits::IntegerSquareRoot;
+use governance_runtime_api::{
+    PendingReward, ProposalFilter, ProposalInfo, ProposalKind, ProposalState, TreasuryState,
+    VotingPower, MAX_LISTED_PROPOSALS,
+};
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+use substrate_fixed::types::I92F36;
+
+impl<T: Config> Pallet<T> {
+    /// The proposal as exposed by the runtime API.
+    pub fn proposal_info(proposal: Proposal<T>) -> ProposalInfo<T::AccountId> {
+        let subnet_id = proposal.data.subnet_id();
+        let kind = match proposal.data {
+            ProposalData::GlobalCustom => ProposalKind::GlobalCustom,
+            ProposalData::GlobalParams(_) => ProposalKind::GlobalParams,
+            ProposalData::SubnetCustom { .. } => ProposalKind::SubnetCustom,
+            ProposalData::SubnetParams { .. } => ProposalKind::SubnetParams,
+            ProposalData::TransferDaoTreasury { account, amount } => {
+                ProposalKind::TransferDaoTreasury { account, amount }
+            }
+            ProposalData::EmissionCurve(_) => ProposalKind::EmissionCurve,
+            ProposalData::Grant {
+                recipient,
+                milestones,
+            } => ProposalKind::Grant {
+                recipient,
+                amount: milestones.iter().fold(0u64, |acc, amount| acc.saturating_add(*amount)),
+            },
+            ProposalData::SubnetTreasurySpend {
+                recipient, amount, ..
+            } => ProposalKind::SubnetTreasurySpend { recipient, amount },
+            ProposalData::FounderShareRouting { to_treasury, .. } => {
+                ProposalKind::FounderShareRouting { to_treasury }
+            }
+        };
+
+        let (state, decided_by_senate, finalized_at, stake_for, stake_against) =
+            match &proposal.status {
+                ProposalStatus::Open {
+                    stake_for,
+                    stake_against,
+                    ..
+                } => (ProposalState::Open, false, None, *stake_for, *stake_against),
+                ProposalStatus::Accepted {
+                    block,
+                    stake_for,
+                    stake_against,
+                } => (
+                    ProposalState::Accepted,
+                    false,
+                    Some(*block),
+                    *stake_for,
+                    *stake_against,
+                ),
+                ProposalStatus::AcceptedBySenate { block } => {
+                    (ProposalState::Accepted, true, Some(*block), 0, 0)
+                }
+                ProposalStatus::Refused {
+                    block,
+                    stake_for,
+                    stake_against,
+                } => (
+                    ProposalState::Refused,
+                    false,
+                    Some(*block),
+                    *stake_for,
+                    *stake_against,
+                ),
+                ProposalStatus::RefusedBySenate { block } => {
+                    (ProposalState::Refused, true, Some(*block), 0, 0)
+                }
+                ProposalStatus::Expired => (
+                    ProposalState::Expired,
+                    false,
+                    Some(proposal.expiration_block),
+                    0,
+                    0,
+                ),
+                ProposalStatus::FailedQuorum {
+                    block,
+                    stake_for,
+                    stake_against,
+                } => (
+                    ProposalState::FailedQuorum,
+                    false,
+                    Some(*block),
+                    *stake_for,
+                    *stake_against,
+                ),
+                ProposalStatus::Cancelled { block } => {
+                    (ProposalState::Cancelled, false, Some(*block), 0, 0)
+                }
+                ProposalStatus::EnactmentCancelled { block } => {
+                    (ProposalState::EnactmentCancelled, false, Some(*block), 0, 0)
+                }
+            };
+
+        let (votes_for, votes_against) = match proposal.status {
+            ProposalStatus::Open {
+                votes_for,
+                votes_against,
+                ..
+            } => (
+                votes_for.into_iter().collect(),
+                votes_against.into_iter().collect(),
+            ),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        ProposalInfo {
+            id: proposal.id,
+            proposer: proposal.proposer,
+            kind,
+            subnet_id,
+            state,
+            decided_by_senate,
+            finalized_at,
+            enactment_block: PendingEnactments::<T>::get(proposal.id),
+            title: proposal.metadata.title.into_inner(),
+            link: proposal.metadata.link.into_inner(),
+            votes_for,
+            votes_against,
+            stake_for,
+            stake_against,
+            creation_block: proposal.creation_block,
+            expiration_block: proposal.expiration_block,
+            proposal_cost: proposal.proposal_cost,
+        }
+    }
+
+    pub fn get_proposal(id: ProposalId) -> Option<ProposalInfo<T::AccountId>> {
+        Proposals::<T>::get(id).map(Self::proposal_info)
+    }
+
+    /// Proposals matching the filter, skipping the first `offset` matches. At most `limit` are
+    /// returned, capped at `MAX_LISTED_PROPOSALS`.
+    pub fn list_proposals(
+        filter: ProposalFilter<T::AccountId>,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<ProposalInfo<T::AccountId>> {
+        Proposals::<T>::iter_values()
+            .map(Self::proposal_info)
+            .filter(|proposal| filter.matches(proposal))
+            .skip(offset as usize)
+            .take(limit.min(MAX_LISTED_PROPOSALS) as usize)
+            .collect()
+    }
+
+    pub fn voting_power(
+        account: &T::AccountId,
+        subnet_id: Option<u16>,
+    ) -> VotingPower<T::AccountId> {
+        let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
+        let is_not_delegating = |key: &T::AccountId| {
+            not_delegating.contains(key) || representative_of::<T>(key, subnet_id).is_some()
+        };
+
+        let represented_stake = delegators_of::<T>(account, subnet_id)
+            .iter()
+            .map(pallet_subspace::Pallet::<T>::get_owned_stake)
+            .fold(0u64, |acc, stake| acc.saturating_add(stake));
+
+        VotingPower {
+            stake: calc_stake_by::<T>(is_not_delegating, account),
+            represented_stake,
+            representative: representative_of::<T>(account, subnet_id),
+            senate_member: Self::is_senate_member(account.clone()),
+        }
+    }
+
+    /// Estimates the rewards of the account, following the same allocation as
+    /// `execute_proposal_rewards`.
+    pub fn pending_rewards(account: &T::AccountId) -> Vec<PendingReward> {
+        let mut groups: BTreeMap<Option<u16>, Vec<(ProposalId, UnrewardedProposal<T>)>> =
+            BTreeMap::new();
+        for (proposal_id, proposal) in UnrewardedProposals::<T>::iter() {
+            groups.entry(proposal.subnet_id).or_default().push((proposal_id, proposal));
+        }
+
+        groups
+            .into_iter()
+            .filter_map(|(subnet_id, proposals)| {
+                let governance_config = match subnet_id {
+                    Some(subnet_id) => SubnetGovernanceConfig::<T>::get(subnet_id),
+                    None => GlobalGovernanceConfig::<T>::get(),
+                };
+
+                let mut account_stakes: BTreeMap<T::AccountId, u64> = BTreeMap::new();
+                let mut total_allocation = I92F36::from_num(0);
+                let mut voted_on = Vec::new();
+                for (n, (proposal_id, proposal)) in proposals.into_iter().enumerate() {
+                    for (voter, stake) in
+                        proposal.votes_for.into_iter().chain(proposal.votes_against)
+                    {
+                        if &voter == account {
+                            voted_on.push(proposal_id);
+                        }
+                        let entry = account_stakes.entry(voter).or_default();
+                        *entry = entry.saturating_add(stake);
+                    }
+
+                    let n = u16::try_from(n).unwrap_or(u16::MAX);
+                    if let Ok(allocation) = get_reward_allocation::<T>(&governance_config, n) {
+                        total_allocation = total_allocation.saturating_add(allocation);
+                    }
+                }
+
+                let stake = *account_stakes.get(account)?;
+                let total_sqrt_stake = account_stakes
+                    .values()
+                    .fold(0u64, |acc, stake| acc.saturating_add(stake.integer_sqrt()));
+                let share = I92F36::from_num(stake.integer_sqrt())
+                    .checked_div(I92F36::from_num(total_sqrt_stake))
+                    .unwrap_or_default();
+
+                Some(PendingReward {
+                    subnet_id,
+                    proposals: voted_on,
+                    stake,
+                    estimated_reward: total_allocation
+                        .checked_mul(share)
+                        .unwrap_or_default()
+                        .to_num(),
+                })
+            })
+            .collect()
+    }
+
+    pub fn treasury_state() -> TreasuryState<T::AccountId> {
+        let address = DaoTreasuryAddress::<T>::get();
+
+        let pending_spends = PendingEnactments::<T>::iter_keys()
+            .filter_map(Proposals::<T>::get)
+            .filter_map(|proposal| match proposal.data {
+                ProposalData::TransferDaoTreasury { amount, .. } => Some(amount),
+                ProposalData::Grant { milestones, .. } => {
+                    Some(milestones.iter().fold(0u64, |acc, amount| acc.saturating_add(*amount)))
+                }
+                _ => None,
+            })
+            .fold(0u64, |acc, amount| acc.saturating_add(amount));
+
+        let (active_grants, grant_escrow) =
+            Grants::<T>::iter_values().fold((0u32, 0u64), |(count, escrow), grant| {
+                (
+                    count.saturating_add(1),
+                    escrow.saturating_add(grant.remaining()),
+                )
+            });
+
+        TreasuryState {
+            balance: pallet_subspace::Pallet::<T>::get_balance_u64(&address),
+            address,
+            payment_liability: Self::total_payment_liability(),
+            pending_spends,
+            grant_escrow,
+            active_payment_schedules: PaymentSchedules::<T>::iter_keys().count() as u32,
+            active_grants,
+        }
+    }
+}
@@ -1,12 +1,58 @@
-use frame_system::ensure_root;
+use frame_support::{pallet_prelude::DispatchResult, weights::Weight, DebugNoBound};
+use frame_system::{ensure_root, ensure_signed};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::collections::btree_map::BTreeMap;
 
-use crate::*;
+use crate::{proposal::calc_stake, *};
+
+/// Most accounts running in a single senate election.
+pub const MAX_SENATE_CANDIDATES: u32 = 64;
+
+/// Most accounts voting in a single senate election.
+pub const MAX_SENATE_VOTERS: u32 = 1024;
+
+/// Most seats the senate can be configured with.
+pub const MAX_SENATE_MEMBERS: u32 = 64;
+
+#[derive(Clone, TypeInfo, Decode, Encode, PartialEq, Eq, DebugNoBound, MaxEncodedLen)]
+pub struct SenateConfiguration {
+    /// Maximum amount of seats in the senate.
+    pub max_members: u16,
+    /// Amount of blocks a member keeps their seat for.
+    pub term_length: u64,
+    /// Amount of blocks between two senate elections.
+    pub election_interval: u64,
+    /// Share of the senate required to accept or refuse a proposal on its own.
+    pub approval_ratio: Percent,
+    /// When enabled, the senate can only refuse proposals.
+    pub veto_only: bool,
+}
+
+impl Default for SenateConfiguration {
+    fn default() -> Self {
+        Self {
+            max_members: 7,
+            // ~30 days
+            term_length: 324_000,
+            // ~10 days
+            election_interval: 108_000,
+            // 4 out of 7 members
+            approval_ratio: Percent::from_percent(57),
+            veto_only: false,
+        }
+    }
+}
 
 impl<T: Config> Pallet<T> {
     pub fn is_senate_member(key: T::AccountId) -> bool {
         SenateMembers::<T>::contains_key(key)
     }
 
+    fn term_end(block_number: u64) -> u64 {
+        block_number.saturating_add(SenateConfig::<T>::get().term_length)
+    }
+
     pub fn do_add_senate_member(
         origin: OriginFor<T>,
         senate_member_key: T::AccountId,
@@ -18,9 +64,16 @@ impl<T: Config> Pallet<T> {
             !SenateMembers::<T>::contains_key(&senate_member_key),
             Error::<T>::SenateMemberExists
         );
+        let max_members = SenateConfig::<T>::get().max_members as usize;
+        ensure!(
+            SenateMembers::<T>::iter_keys().count() < max_members,
+            Error::<T>::SenateFull
+        );
 
         // Add the senate member
-        SenateMembers::<T>::insert(&senate_member_key, ());
+        let term_end = Self::term_end(Self::current_block());
+        SenateMembers::<T>::insert(&senate_member_key, term_end);
+        Self::deposit_event(Event::<T>::SenateMemberAdded(senate_member_key));
 
         Ok(())
     }
@@ -37,9 +90,173 @@ impl<T: Config> Pallet<T> {
             Error::<T>::SenateMemberNotFound
         );
 
-        // Add the senate member
+        // Remove the senate member
         SenateMembers::<T>::remove(&senate_member_key);
+        Self::deposit_event(Event::<T>::SenateMemberRemoved(senate_member_key));
+
+        Ok(())
+    }
+
+    pub fn do_set_senate_configuration(
+        origin: OriginFor<T>,
+        config: SenateConfiguration,
+    ) -> DispatchResult {
+        ensure_root(origin)?;
+
+        ensure!(
+            config.max_members > 0 && u32::from(config.max_members) <= MAX_SENATE_MEMBERS,
+            Error::<T>::InvalidSenateConfiguration
+        );
+        ensure!(
+            config.term_length > 0,
+            Error::<T>::InvalidSenateConfiguration
+        );
+        ensure!(
+            config.election_interval > 0,
+            Error::<T>::InvalidSenateConfiguration
+        );
+        ensure!(
+            config.approval_ratio >= Percent::from_percent(50),
+            Error::<T>::InvalidSenateConfiguration
+        );
+
+        SenateConfig::<T>::set(config);
+        Self::deposit_event(Event::<T>::SenateConfigurationUpdated);
 
         Ok(())
     }
+
+    pub fn do_submit_senate_candidacy(origin: OriginFor<T>) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(
+            !SenateCandidates::<T>::contains_key(&key),
+            Error::<T>::AlreadySenateCandidate
+        );
+        ensure!(
+            !SenateMembers::<T>::contains_key(&key),
+            Error::<T>::SenateMemberExists
+        );
+        ensure!(
+            SenateCandidates::<T>::count() < MAX_SENATE_CANDIDATES,
+            Error::<T>::TooManySenateCandidates
+        );
+
+        SenateCandidates::<T>::insert(&key, ());
+        Self::deposit_event(Event::<T>::SenateCandidacySubmitted(key));
+
+        Ok(())
+    }
+
+    /// Backs a candidate with the caller's voting power. A voter backs a single candidate,
+    /// voting again moves the support to the new candidate.
+    pub fn do_vote_senate_candidate(
+        origin: OriginFor<T>,
+        candidate: T::AccountId,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(
+            SenateCandidates::<T>::contains_key(&candidate),
+            Error::<T>::NotSenateCandidate
+        );
+
+        let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
+        ensure!(
+            calc_stake::<T>(&not_delegating, &key) > 0,
+            Error::<T>::InsufficientStake
+        );
+        ensure!(
+            SenateVotes::<T>::contains_key(&key) || SenateVotes::<T>::count() < MAX_SENATE_VOTERS,
+            Error::<T>::TooManySenateVoters
+        );
+
+        SenateVotes::<T>::insert(&key, &candidate);
+        Self::deposit_event(Event::<T>::SenateCandidateVoted {
+            voter: key,
+            candidate,
+        });
+
+        Ok(())
+    }
+
+    fn current_block() -> u64 {
+        <frame_system::Pallet<T>>::block_number()
+            .try_into()
+            .ok()
+            .expect("blockchain won't pass 2 ^ 64 blocks")
+    }
+}
+
+/// Removes members whose term ended and runs an election when one is due.
+///
+/// # Returns
+///
+/// The weight consumed.
+pub fn tick_senate<T: Config>(block_number: u64) -> Weight {
+    let mut members: u64 = 0;
+    let mut expired = Vec::new();
+    for (key, term_end) in SenateMembers::<T>::iter() {
+        members = members.saturating_add(1);
+        if term_end <= block_number {
+            expired.push(key);
+        }
+    }
+
+    let mut weight =
+        T::DbWeight::get().reads_writes(members.saturating_add(1), expired.len() as u64);
+    for key in expired {
+        SenateMembers::<T>::remove(&key);
+        Pallet::<T>::deposit_event(Event::<T>::SenateMemberTermEnded(key));
+    }
+
+    let config = SenateConfig::<T>::get();
+    let election_due = block_number.checked_rem(config.election_interval).is_some_and(|r| r == 0);
+    if election_due {
+        weight = weight.saturating_add(<T as Config>::WeightInfo::run_senate_election(
+            SenateCandidates::<T>::count(),
+            SenateVotes::<T>::count(),
+        ));
+        run_senate_election::<T>(block_number, &config);
+    }
+
+    weight
+}
+
+/// Fills the open seats with the candidates backed by the most stake, then resets the
+/// candidacies and votes for the next election.
+pub(crate) fn run_senate_election<T: Config>(block_number: u64, config: &SenateConfiguration) {
+    let not_delegating = NotDelegatingVotingPower::<T>::get().into_inner();
+
+    let mut tally: BTreeMap<T::AccountId, u64> = BTreeMap::new();
+    for (voter, candidate) in SenateVotes::<T>::iter() {
+        if !SenateCandidates::<T>::contains_key(&candidate)
+            || SenateMembers::<T>::contains_key(&candidate)
+        {
+            continue;
+        }
+
+        let stake = calc_stake::<T>(&not_delegating, &voter);
+        let backing = tally.entry(candidate).or_default();
+        *backing = backing.saturating_add(stake);
+    }
+
+    let mut ranked: Vec<_> = tally.into_iter().filter(|(_, stake)| *stake > 0).collect();
+    ranked.sort_by(|(a_key, a_stake), (b_key, b_stake)| {
+        b_stake.cmp(a_stake).then_with(|| a_key.cmp(b_key))
+    });
+
+    let open_seats =
+        (config.max_members as usize).saturating_sub(SenateMembers::<T>::iter_keys().count());
+    let term_end = block_number.saturating_add(config.term_length);
+
+    let elected: Vec<_> = ranked.into_iter().take(open_seats).map(|(key, _)| key).collect();
+    for key in &elected {
+        SenateMembers::<T>::insert(key, term_end);
+    }
+
+    let _ = SenateCandidates::<T>::clear(MAX_SENATE_CANDIDATES, None);
+    let _ = SenateVotes::<T>::clear(MAX_SENATE_VOTERS, None);
+
+    Pallet::<T>::deposit_event(Event::<T>::SenateElectionFinished { elected });
 }
@@ -2,8 +2,35 @@ use crate::{
     proposal::{check_senate_approval, ProposalStatus},
     *,
 };
-use frame_support::pallet_prelude::DispatchResult;
+use frame_support::{pallet_prelude::DispatchResult, DebugNoBound};
 use frame_system::ensure_signed;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+/// Maximum amount of delegators a representative can have on a single scope.
+pub const MAX_DELEGATORS: u32 = 128;
+
+/// The proposals a vote delegation applies to.
+#[derive(
+    Clone,
+    Copy,
+    TypeInfo,
+    Decode,
+    Encode,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    DebugNoBound,
+    MaxEncodedLen,
+)]
+pub enum DelegationScope {
+    /// Global proposals, and subnet proposals without a subnet scoped delegation.
+    Global,
+    /// Proposals of the given subnet.
+    Subnet(u16),
+}
 
 impl<T: Config> Pallet<T> {
     /// Votes on proposals,
@@ -18,6 +45,7 @@ impl<T: Config> Pallet<T> {
         let Ok(mut proposal) = Proposals::<T>::try_get(proposal_id) else {
             return Err(Error::<T>::ProposalNotFound.into());
         };
+        let subnet_id = proposal.subnet_id();
 
         let ProposalStatus::Open {
             votes_for,
@@ -44,7 +72,13 @@ impl<T: Config> Pallet<T> {
                 Error::<T>::InsufficientStake
             );
 
-            if !NotDelegatingVotingPower::<T>::get().contains(&key) && voter_delegated_stake == 0 {
+            // Voting directly overrides an explicit delegation, so the voter's own stake counts
+            let has_representative = representative_of::<T>(&key, subnet_id).is_some();
+
+            if !NotDelegatingVotingPower::<T>::get().contains(&key)
+                && voter_delegated_stake == 0
+                && !has_representative
+            {
                 return Err(Error::<T>::VoterIsDelegatingVotingPower.into());
             }
         }
@@ -96,4 +130,114 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::<T>::ProposalVoteUnregistered(proposal_id, key));
         Ok(())
     }
+
+    /// Delegates the caller's voting power on proposals of the given scope to a representative.
+    /// Delegating again within the same scope replaces the previous representative.
+    pub fn do_delegate_votes(
+        origin: T::RuntimeOrigin,
+        representative: T::AccountId,
+        scope: DelegationScope,
+    ) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        ensure!(key != representative, Error::<T>::SelfDelegation);
+        if let DelegationScope::Subnet(subnet_id) = scope {
+            ensure!(
+                pallet_subspace::Pallet::<T>::if_subnet_exist(subnet_id),
+                Error::<T>::InvalidDelegationScope
+            );
+        }
+
+        // Delegations are only followed for a single hop
+        ensure!(
+            !VoteDelegations::<T>::contains_key(&representative, scope),
+            Error::<T>::DelegationChainTooLong
+        );
+        ensure!(
+            !VoteDelegators::<T>::contains_key(&key, scope),
+            Error::<T>::DelegationChainTooLong
+        );
+
+        if let Some(previous) = VoteDelegations::<T>::get(&key, scope) {
+            remove_delegator::<T>(&previous, scope, &key);
+        }
+        VoteDelegators::<T>::try_mutate(&representative, scope, |delegators| {
+            delegators.try_insert(key.clone()).map_err(|_| Error::<T>::TooManyDelegators)
+        })?;
+
+        VoteDelegations::<T>::insert(&key, scope, &representative);
+        Self::deposit_event(Event::<T>::VotesDelegated {
+            delegator: key,
+            representative,
+            scope,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the caller's delegation for the given scope.
+    pub fn do_undelegate_votes(origin: T::RuntimeOrigin, scope: DelegationScope) -> DispatchResult {
+        let key = ensure_signed(origin)?;
+
+        let Some(representative) = VoteDelegations::<T>::take(&key, scope) else {
+            return Err(Error::<T>::VoteDelegationNotFound.into());
+        };
+        remove_delegator::<T>(&representative, scope, &key);
+
+        Self::deposit_event(Event::<T>::VotesUndelegated {
+            delegator: key,
+            scope,
+        });
+
+        Ok(())
+    }
+}
+
+fn remove_delegator<T: Config>(
+    representative: &T::AccountId,
+    scope: DelegationScope,
+    delegator: &T::AccountId,
+) {
+    VoteDelegators::<T>::mutate_exists(representative, scope, |delegators| {
+        if let Some(set) = delegators {
+            set.remove(delegator);
+            if set.is_empty() {
+                *delegators = None;
+            }
+        }
+    });
+}
+
+/// The representative voting on the delegator's behalf on proposals of the given subnet, or
+/// global proposals when `None`. A subnet scoped delegation takes precedence over a global one.
+pub fn representative_of<T: Config>(
+    delegator: &T::AccountId,
+    subnet_id: Option<u16>,
+) -> Option<T::AccountId> {
+    subnet_id
+        .and_then(|id| VoteDelegations::<T>::get(delegator, DelegationScope::Subnet(id)))
+        .or_else(|| VoteDelegations::<T>::get(delegator, DelegationScope::Global))
+}
+
+/// The delegators the representative votes for on proposals of the given subnet, or global
+/// proposals when `None`.
+pub fn delegators_of<T: Config>(
+    representative: &T::AccountId,
+    subnet_id: Option<u16>,
+) -> Vec<T::AccountId> {
+    let mut delegators: Vec<_> = subnet_id
+        .map(|id| VoteDelegators::<T>::get(representative, DelegationScope::Subnet(id)))
+        .unwrap_or_default()
+        .into_iter()
+        .collect();
+
+    // global delegators with a subnet scoped delegation are represented by someone else there
+    let global = VoteDelegators::<T>::get(representative, DelegationScope::Global);
+    delegators.extend(global.into_iter().filter(|delegator| {
+        subnet_id.map_or(true, |id| {
+            !VoteDelegations::<T>::contains_key(delegator, DelegationScope::Subnet(id))
+        })
+    }));
+
+    delegators
 }
@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ubuntu-64gb-hil-2`, CPU: `AMD EPYC-Milan Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("specs/local.json")`, DB CACHE: `1024`
+//!
+//! NOTE: the functions marked `ESTIMATE` were written by hand and are not output of the
+//! benchmark CLI. Regenerate this file with `make run-benchmarking` to replace them.
 
 // Executed Command:
 // ./target/release/node-subspace
@@ -49,6 +52,35 @@ pub trait WeightInfo {
 	fn add_to_whitelist() -> Weight;
 	fn remove_from_whitelist() -> Weight;
 	fn cancel_payment_schedule() -> Weight;
+	fn submit_senate_candidacy() -> Weight;
+	fn vote_senate_candidate() -> Weight;
+	fn delegate_votes() -> Weight;
+	fn undelegate_votes() -> Weight;
+	fn cancel_proposal() -> Weight;
+	fn amend_proposal() -> Weight;
+	fn fast_track_enactment() -> Weight;
+	fn open_dao_application_vote() -> Weight;
+	fn vote_dao_application() -> Weight;
+	fn attest_grant_milestone() -> Weight;
+	fn submit_reference_price() -> Weight;
+	fn set_senate_configuration() -> Weight;
+	fn run_senate_election(c: u32, v: u32, ) -> Weight;
+	fn pause_payment_schedule() -> Weight;
+	fn resume_payment_schedule() -> Weight;
+	fn set_payment_oracle() -> Weight;
+	fn set_enactment_delays() -> Weight;
+	fn cancel_enactment() -> Weight;
+	fn remove_from_whitelist_with_reason() -> Weight;
+	fn set_application_vote_configuration() -> Weight;
+	fn add_emission_curve_proposal() -> Weight;
+	fn add_grant_proposal() -> Weight;
+	fn cancel_grant() -> Weight;
+	fn add_subnet_treasury_spend_proposal() -> Weight;
+	fn add_founder_share_routing_proposal() -> Weight;
+	fn create_payment_schedule() -> Weight;
+	fn create_reference_payment_schedule() -> Weight;
+	fn tick_enactments(p: u32, e: u32, ) -> Weight;
+	fn tick_applications(a: u32, v: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_governance` using the Substrate node and recommended hardware.
@@ -348,6 +380,523 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateCandidates` (r:1 w:1)
+	/// Proof: `GovernanceModule::SenateCandidates` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateMembers` (r:1 w:0)
+	/// Proof: `GovernanceModule::SenateMembers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CounterForSenateCandidates` (r:1 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn submit_senate_candidacy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3505`
+		// Minimum execution time: 14_127_000 picoseconds.
+		Weight::from_parts(14_690_000, 3505)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateCandidates` (r:1 w:0)
+	/// Proof: `GovernanceModule::SenateCandidates` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: Some(1), `max_size`: Some(4294967295), added: 494, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::SenateVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::SenateVotes` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CounterForSenateVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateVotes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn vote_senate_candidate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 27_904_000 picoseconds.
+		Weight::from_parts(28_765_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::VoteDelegations` (r:2 w:1)
+	/// Proof: `GovernanceModule::VoteDelegations` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::VoteDelegators` (r:3 w:2)
+	/// Proof: `GovernanceModule::VoteDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn delegate_votes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4507`
+		//  Estimated: `7972`
+		// Minimum execution time: 38_914_000 picoseconds.
+		Weight::from_parts(40_236_000, 7972)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::VoteDelegations` (r:1 w:1)
+	/// Proof: `GovernanceModule::VoteDelegations` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::VoteDelegators` (r:1 w:1)
+	/// Proof: `GovernanceModule::VoteDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn undelegate_votes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4376`
+		//  Estimated: `7841`
+		// Minimum execution time: 24_632_000 picoseconds.
+		Weight::from_parts(25_819_000, 7841)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ProposalCancellationRefund` (r:1 w:0)
+	/// Proof: `GovernanceModule::ProposalCancellationRefund` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `486`
+		//  Estimated: `3951`
+		// Minimum execution time: 38_714_000 picoseconds.
+		Weight::from_parts(39_962_000, 3951)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(55), added: 550, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::MaxNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxNameLength` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::MinNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinNameLength` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	fn amend_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `486`
+		//  Estimated: `3951`
+		// Minimum execution time: 32_181_000 picoseconds.
+		Weight::from_parts(33_405_000, 3951)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateMembers` (r:2 w:0)
+	/// Proof: `GovernanceModule::SenateMembers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::PendingEnactments` (r:1 w:1)
+	/// Proof: `GovernanceModule::PendingEnactments` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::FastTrackVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::FastTrackVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::SenateConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::SenateConfig` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:0)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn fast_track_enactment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `6642`
+		// Minimum execution time: 41_263_000 picoseconds.
+		Weight::from_parts(42_518_000, 6642)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::CuratorApplications` (r:1 w:0)
+	/// Proof: `GovernanceModule::CuratorApplications` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Curator` (r:1 w:0)
+	/// Proof: `GovernanceModule::Curator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::ApplicationVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::ApplicationVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(55), added: 550, mode: `MaxEncodedLen`)
+	fn open_dao_application_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `3886`
+		// Minimum execution time: 23_610_000 picoseconds.
+		Weight::from_parts(24_372_000, 3886)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::ApplicationVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::ApplicationVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: Some(1), `max_size`: Some(4294967295), added: 494, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn vote_dao_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `3963`
+		// Minimum execution time: 27_905_000 picoseconds.
+		Weight::from_parts(28_813_000, 3963)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Curator` (r:1 w:0)
+	/// Proof: `GovernanceModule::Curator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Grants` (r:1 w:1)
+	/// Proof: `GovernanceModule::Grants` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn attest_grant_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 45_732_000 picoseconds.
+		Weight::from_parts(47_018_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentOracle` (r:1 w:0)
+	/// Proof: `GovernanceModule::PaymentOracle` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::ReferencePrice` (r:0 w:1)
+	/// Proof: `GovernanceModule::ReferencePrice` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn submit_reference_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_468_000, 1517)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateConfig` (r:0 w:1)
+	/// Proof: `GovernanceModule::SenateConfig` (`max_values`: Some(1), `max_size`: Some(27), added: 522, mode: `MaxEncodedLen`)
+	fn set_senate_configuration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_114_000 picoseconds.
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: Some(1), `max_size`: Some(4294967295), added: 494, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateVotes` (r:1025 w:1024)
+	/// Proof: `GovernanceModule::SenateVotes` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateCandidates` (r:65 w:64)
+	/// Proof: `GovernanceModule::SenateCandidates` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateMembers` (r:66 w:64)
+	/// Proof: `GovernanceModule::SenateMembers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1024 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::CounterForSenateCandidates` (r:0 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CounterForSenateVotes` (r:0 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateVotes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `v` is `[1, 1024]`.
+	fn run_senate_election(c: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183 + c * (97 ±0) + v * (129 ±0)`
+		//  Estimated: `3505 + c * (2515 ±0) + v * (2604 ±0)`
+		// Minimum execution time: 18_930_000 picoseconds.
+		Weight::from_parts(19_511_000, 3505)
+			// Standard Error: 120_600
+			.saturating_add(Weight::from_parts(2_412_000, 0).saturating_mul(c.into()))
+			// Standard Error: 593_650
+			.saturating_add(Weight::from_parts(11_873_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2515).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2604).saturating_mul(v.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:1 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn pause_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154`
+		//  Estimated: `3619`
+		// Minimum execution time: 13_508_000 picoseconds.
+		Weight::from_parts(14_021_000, 3619)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:1 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn resume_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154`
+		//  Estimated: `3619`
+		// Minimum execution time: 13_640_000 picoseconds.
+		Weight::from_parts(14_187_000, 3619)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentOracle` (r:0 w:1)
+	/// Proof: `GovernanceModule::PaymentOracle` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_payment_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_903_000 picoseconds.
+		Weight::from_parts(7_215_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::EnactmentDelayConfig` (r:0 w:1)
+	/// Proof: `GovernanceModule::EnactmentDelayConfig` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_enactment_delays() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_820_000 picoseconds.
+		Weight::from_parts(7_093_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PendingEnactments` (r:1 w:1)
+	/// Proof: `GovernanceModule::PendingEnactments` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::FastTrackVotes` (r:0 w:1)
+	/// Proof: `GovernanceModule::FastTrackVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_enactment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4077`
+		// Minimum execution time: 21_374_000 picoseconds.
+		Weight::from_parts(22_150_000, 4077)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Curator` (r:1 w:0)
+	/// Proof: `GovernanceModule::Curator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::LegitWhitelist` (r:1 w:1)
+	/// Proof: `GovernanceModule::LegitWhitelist` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CuratorApplications` (r:2 w:1)
+	/// Proof: `GovernanceModule::CuratorApplications` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	fn remove_from_whitelist_with_reason() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `6650`
+		// Minimum execution time: 34_120_000 picoseconds.
+		Weight::from_parts(35_004_000, 6650)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::ApplicationVoteConfig` (r:0 w:1)
+	/// Proof: `GovernanceModule::ApplicationVoteConfig` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	fn set_application_vote_configuration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_710_000 picoseconds.
+		Weight::from_parts(6_982_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_emission_curve_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3569`
+		// Minimum execution time: 46_912_000 picoseconds.
+		Weight::from_parts(48_105_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:1 w:0)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_grant_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `6148`
+		// Minimum execution time: 58_947_000 picoseconds.
+		Weight::from_parts(60_382_000, 6148)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Grants` (r:1 w:1)
+	/// Proof: `GovernanceModule::Grants` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_grant() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 43_518_000 picoseconds.
+		Weight::from_parts(44_870_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SubnetGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::SubnetGovernanceConfig` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_subnet_treasury_spend_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6148`
+		// Minimum execution time: 58_417_000 picoseconds.
+		Weight::from_parts(60_104_000, 6148)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SubnetGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::SubnetGovernanceConfig` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_founder_share_routing_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `3569`
+		// Minimum execution time: 51_962_000 picoseconds.
+		Weight::from_parts(53_381_000, 3569)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:2 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::NextPaymentScheduleId` (r:1 w:1)
+	/// Proof: `GovernanceModule::NextPaymentScheduleId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn create_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `6342`
+		// Minimum execution time: 24_913_000 picoseconds.
+		Weight::from_parts(25_704_000, 6342)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:2 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::NextPaymentScheduleId` (r:1 w:1)
+	/// Proof: `GovernanceModule::NextPaymentScheduleId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::ReferencePrice` (r:1 w:0)
+	/// Proof: `GovernanceModule::ReferencePrice` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn create_reference_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `441`
+		//  Estimated: `6381`
+		// Minimum execution time: 26_877_000 picoseconds.
+		Weight::from_parts(27_590_000, 6381)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PendingEnactments` (r:201 w:100)
+	/// Proof: `GovernanceModule::PendingEnactments` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:100 w:100)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:200 w:200)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::FastTrackVotes` (r:0 w:100)
+	/// Proof: `GovernanceModule::FastTrackVotes` (`max_values`: None, `max_size`: Some(2050), added: 4525, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 100]`.
+	/// The range of component `e` is `[0, 100]`.
+	fn tick_enactments(p: u32, e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `96`
+		//  Estimated: `3481`
+		// Minimum execution time: 2_884_000 picoseconds.
+		Weight::from_parts(3_106_000, 3481)
+			// Standard Error: 95_600
+			.saturating_add(Weight::from_parts(1_912_000, 0).saturating_mul(p.into()))
+			// Standard Error: 2_066_850
+			.saturating_add(Weight::from_parts(41_337_000, 0).saturating_mul(e.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(e.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2491).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(e.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::ApplicationExpiration` (r:1 w:0)
+	/// Proof: `GovernanceModule::ApplicationExpiration` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::CuratorApplications` (r:101 w:100)
+	/// Proof: `GovernanceModule::CuratorApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ApplicationVotes` (r:100 w:100)
+	/// Proof: `GovernanceModule::ApplicationVotes` (`max_values`: None, `max_size`: Some(65560), added: 68035, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1024 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ApplicationVoteConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::ApplicationVoteConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::TotalStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::LegitWhitelist` (r:0 w:1)
+	/// Proof: `GovernanceModule::LegitWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 100]`.
+	/// The range of component `v` is `[0, 1024]`.
+	fn tick_applications(a: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3769`
+		// Minimum execution time: 11_962_000 picoseconds.
+		Weight::from_parts(12_450_000, 3769)
+			// Standard Error: 1_124_800
+			.saturating_add(Weight::from_parts(22_496_000, 0).saturating_mul(a.into()))
+			// Standard Error: 205_900
+			.saturating_add(Weight::from_parts(4_118_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 68035).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(v.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -646,4 +1195,521 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateCandidates` (r:1 w:1)
+	/// Proof: `GovernanceModule::SenateCandidates` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateMembers` (r:1 w:0)
+	/// Proof: `GovernanceModule::SenateMembers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CounterForSenateCandidates` (r:1 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn submit_senate_candidacy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `3505`
+		// Minimum execution time: 14_127_000 picoseconds.
+		Weight::from_parts(14_690_000, 3505)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateCandidates` (r:1 w:0)
+	/// Proof: `GovernanceModule::SenateCandidates` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: Some(1), `max_size`: Some(4294967295), added: 494, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::SenateVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::SenateVotes` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CounterForSenateVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateVotes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn vote_senate_candidate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 27_904_000 picoseconds.
+		Weight::from_parts(28_765_000, 3877)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::VoteDelegations` (r:2 w:1)
+	/// Proof: `GovernanceModule::VoteDelegations` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::VoteDelegators` (r:3 w:2)
+	/// Proof: `GovernanceModule::VoteDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn delegate_votes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4507`
+		//  Estimated: `7972`
+		// Minimum execution time: 38_914_000 picoseconds.
+		Weight::from_parts(40_236_000, 7972)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::VoteDelegations` (r:1 w:1)
+	/// Proof: `GovernanceModule::VoteDelegations` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::VoteDelegators` (r:1 w:1)
+	/// Proof: `GovernanceModule::VoteDelegators` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn undelegate_votes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4376`
+		//  Estimated: `7841`
+		// Minimum execution time: 24_632_000 picoseconds.
+		Weight::from_parts(25_819_000, 7841)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ProposalCancellationRefund` (r:1 w:0)
+	/// Proof: `GovernanceModule::ProposalCancellationRefund` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `486`
+		//  Estimated: `3951`
+		// Minimum execution time: 38_714_000 picoseconds.
+		Weight::from_parts(39_962_000, 3951)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(55), added: 550, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::MaxNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MaxNameLength` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::MinNameLength` (r:1 w:0)
+	/// Proof: `SubspaceModule::MinNameLength` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	fn amend_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `486`
+		//  Estimated: `3951`
+		// Minimum execution time: 32_181_000 picoseconds.
+		Weight::from_parts(33_405_000, 3951)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateMembers` (r:2 w:0)
+	/// Proof: `GovernanceModule::SenateMembers` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::PendingEnactments` (r:1 w:1)
+	/// Proof: `GovernanceModule::PendingEnactments` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::FastTrackVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::FastTrackVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::SenateConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::SenateConfig` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:0)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn fast_track_enactment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `702`
+		//  Estimated: `6642`
+		// Minimum execution time: 41_263_000 picoseconds.
+		Weight::from_parts(42_518_000, 6642)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::CuratorApplications` (r:1 w:0)
+	/// Proof: `GovernanceModule::CuratorApplications` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Curator` (r:1 w:0)
+	/// Proof: `GovernanceModule::Curator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::ApplicationVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::ApplicationVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(55), added: 550, mode: `MaxEncodedLen`)
+	fn open_dao_application_vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421`
+		//  Estimated: `3886`
+		// Minimum execution time: 23_610_000 picoseconds.
+		Weight::from_parts(24_372_000, 3886)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::ApplicationVotes` (r:1 w:1)
+	/// Proof: `GovernanceModule::ApplicationVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: Some(1), `max_size`: Some(4294967295), added: 494, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:1 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn vote_dao_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `3963`
+		// Minimum execution time: 27_905_000 picoseconds.
+		Weight::from_parts(28_813_000, 3963)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Curator` (r:1 w:0)
+	/// Proof: `GovernanceModule::Curator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Grants` (r:1 w:1)
+	/// Proof: `GovernanceModule::Grants` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn attest_grant_milestone() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 45_732_000 picoseconds.
+		Weight::from_parts(47_018_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentOracle` (r:1 w:0)
+	/// Proof: `GovernanceModule::PaymentOracle` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::ReferencePrice` (r:0 w:1)
+	/// Proof: `GovernanceModule::ReferencePrice` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn submit_reference_price() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `1517`
+		// Minimum execution time: 9_112_000 picoseconds.
+		Weight::from_parts(9_468_000, 1517)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SenateConfig` (r:0 w:1)
+	/// Proof: `GovernanceModule::SenateConfig` (`max_values`: Some(1), `max_size`: Some(27), added: 522, mode: `MaxEncodedLen`)
+	fn set_senate_configuration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_114_000 picoseconds.
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: Some(1), `max_size`: Some(4294967295), added: 494, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateVotes` (r:1025 w:1024)
+	/// Proof: `GovernanceModule::SenateVotes` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateCandidates` (r:65 w:64)
+	/// Proof: `GovernanceModule::SenateCandidates` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::SenateMembers` (r:66 w:64)
+	/// Proof: `GovernanceModule::SenateMembers` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1024 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::CounterForSenateCandidates` (r:0 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateCandidates` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CounterForSenateVotes` (r:0 w:1)
+	/// Proof: `GovernanceModule::CounterForSenateVotes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 64]`.
+	/// The range of component `v` is `[1, 1024]`.
+	fn run_senate_election(c: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `183 + c * (97 ±0) + v * (129 ±0)`
+		//  Estimated: `3505 + c * (2515 ±0) + v * (2604 ±0)`
+		// Minimum execution time: 18_930_000 picoseconds.
+		Weight::from_parts(19_511_000, 3505)
+			// Standard Error: 120_600
+			.saturating_add(Weight::from_parts(2_412_000, 0).saturating_mul(c.into()))
+			// Standard Error: 593_650
+			.saturating_add(Weight::from_parts(11_873_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(v.into())))
+			.saturating_add(Weight::from_parts(0, 2515).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2604).saturating_mul(v.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:1 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn pause_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154`
+		//  Estimated: `3619`
+		// Minimum execution time: 13_508_000 picoseconds.
+		Weight::from_parts(14_021_000, 3619)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:1 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn resume_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `154`
+		//  Estimated: `3619`
+		// Minimum execution time: 13_640_000 picoseconds.
+		Weight::from_parts(14_187_000, 3619)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentOracle` (r:0 w:1)
+	/// Proof: `GovernanceModule::PaymentOracle` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_payment_oracle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_903_000 picoseconds.
+		Weight::from_parts(7_215_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::EnactmentDelayConfig` (r:0 w:1)
+	/// Proof: `GovernanceModule::EnactmentDelayConfig` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn set_enactment_delays() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_820_000 picoseconds.
+		Weight::from_parts(7_093_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PendingEnactments` (r:1 w:1)
+	/// Proof: `GovernanceModule::PendingEnactments` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::FastTrackVotes` (r:0 w:1)
+	/// Proof: `GovernanceModule::FastTrackVotes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_enactment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `4077`
+		// Minimum execution time: 21_374_000 picoseconds.
+		Weight::from_parts(22_150_000, 4077)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Curator` (r:1 w:0)
+	/// Proof: `GovernanceModule::Curator` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::LegitWhitelist` (r:1 w:1)
+	/// Proof: `GovernanceModule::LegitWhitelist` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::CuratorApplications` (r:2 w:1)
+	/// Proof: `GovernanceModule::CuratorApplications` (`max_values`: None, `max_size`: Some(355), added: 2830, mode: `MaxEncodedLen`)
+	fn remove_from_whitelist_with_reason() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `6650`
+		// Minimum execution time: 34_120_000 picoseconds.
+		Weight::from_parts(35_004_000, 6650)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::ApplicationVoteConfig` (r:0 w:1)
+	/// Proof: `GovernanceModule::ApplicationVoteConfig` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	fn set_application_vote_configuration() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_710_000 picoseconds.
+		Weight::from_parts(6_982_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_emission_curve_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120`
+		//  Estimated: `3569`
+		// Minimum execution time: 46_912_000 picoseconds.
+		Weight::from_parts(48_105_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:1 w:0)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_grant_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `6148`
+		// Minimum execution time: 58_947_000 picoseconds.
+		Weight::from_parts(60_382_000, 6148)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::Grants` (r:1 w:1)
+	/// Proof: `GovernanceModule::Grants` (`max_values`: None, `max_size`: Some(326), added: 2801, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_grant() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `6196`
+		// Minimum execution time: 43_518_000 picoseconds.
+		Weight::from_parts(44_870_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SubnetGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::SubnetGovernanceConfig` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_subnet_treasury_spend_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `6148`
+		// Minimum execution time: 58_417_000 picoseconds.
+		Weight::from_parts(60_104_000, 6148)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::SubnetGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::SubnetGovernanceConfig` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::GlobalGovernanceConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::GlobalGovernanceConfig` (`max_values`: Some(1), `max_size`: Some(30), added: 525, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:1 w:1)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	fn add_founder_share_routing_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `284`
+		//  Estimated: `3569`
+		// Minimum execution time: 51_962_000 picoseconds.
+		Weight::from_parts(53_381_000, 3569)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:2 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::NextPaymentScheduleId` (r:1 w:1)
+	/// Proof: `GovernanceModule::NextPaymentScheduleId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn create_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `6342`
+		// Minimum execution time: 24_913_000 picoseconds.
+		Weight::from_parts(25_704_000, 6342)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PaymentSchedules` (r:2 w:1)
+	/// Proof: `GovernanceModule::PaymentSchedules` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::NextPaymentScheduleId` (r:1 w:1)
+	/// Proof: `GovernanceModule::NextPaymentScheduleId` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::ReferencePrice` (r:1 w:0)
+	/// Proof: `GovernanceModule::ReferencePrice` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn create_reference_payment_schedule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `441`
+		//  Estimated: `6381`
+		// Minimum execution time: 26_877_000 picoseconds.
+		Weight::from_parts(27_590_000, 6381)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::PendingEnactments` (r:201 w:100)
+	/// Proof: `GovernanceModule::PendingEnactments` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::Proposals` (r:100 w:100)
+	/// Proof: `GovernanceModule::Proposals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:200 w:200)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GovernanceModule::FastTrackVotes` (r:0 w:100)
+	/// Proof: `GovernanceModule::FastTrackVotes` (`max_values`: None, `max_size`: Some(2050), added: 4525, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[0, 100]`.
+	/// The range of component `e` is `[0, 100]`.
+	fn tick_enactments(p: u32, e: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `96`
+		//  Estimated: `3481`
+		// Minimum execution time: 2_884_000 picoseconds.
+		Weight::from_parts(3_106_000, 3481)
+			// Standard Error: 95_600
+			.saturating_add(Weight::from_parts(1_912_000, 0).saturating_mul(p.into()))
+			// Standard Error: 2_066_850
+			.saturating_add(Weight::from_parts(41_337_000, 0).saturating_mul(e.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(e.into())))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(e.into())))
+			.saturating_add(Weight::from_parts(0, 2491).saturating_mul(p.into()))
+			.saturating_add(Weight::from_parts(0, 5206).saturating_mul(e.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `GovernanceModule::ApplicationExpiration` (r:1 w:0)
+	/// Proof: `GovernanceModule::ApplicationExpiration` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::NotDelegatingVotingPower` (r:1 w:0)
+	/// Proof: `GovernanceModule::NotDelegatingVotingPower` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::CuratorApplications` (r:101 w:100)
+	/// Proof: `GovernanceModule::CuratorApplications` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ApplicationVotes` (r:100 w:100)
+	/// Proof: `GovernanceModule::ApplicationVotes` (`max_values`: None, `max_size`: Some(65560), added: 68035, mode: `MaxEncodedLen`)
+	/// Storage: `SubspaceModule::StakeTo` (r:1024 w:0)
+	/// Proof: `SubspaceModule::StakeTo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::ApplicationVoteConfig` (r:1 w:0)
+	/// Proof: `GovernanceModule::ApplicationVoteConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalStake` (r:1 w:0)
+	/// Proof: `SubspaceModule::TotalStake` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::LegitWhitelist` (r:0 w:1)
+	/// Proof: `GovernanceModule::LegitWhitelist` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `a` is `[1, 100]`.
+	/// The range of component `v` is `[0, 1024]`.
+	fn tick_applications(a: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `304`
+		//  Estimated: `3769`
+		// Minimum execution time: 11_962_000 picoseconds.
+		Weight::from_parts(12_450_000, 3769)
+			// Standard Error: 1_124_800
+			.saturating_add(Weight::from_parts(22_496_000, 0).saturating_mul(a.into()))
+			// Standard Error: 205_900
+			.saturating_add(Weight::from_parts(4_118_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 68035).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(v.into()))
+	}
 }
\ No newline at end of file
@@ -0,0 +1,94 @@
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+use pallet_subnet_emission::{types::DECRYPTION_SHARE_LEN, DecryptionNodeBonds};
+use pallet_subspace::Pallet as SubspaceMod;
+use sp_std::vec;
+
+fn fund_node<T: Config>(node: &T::AccountId) -> u64 {
+    let bond = <T as pallet_subnet_emission::Config>::MinDecryptionNodeBond::get();
+    SubspaceMod::<T>::add_balance_to_account(
+        node,
+        SubspaceMod::<T>::u64_to_balance(bond.saturating_mul(2)).unwrap(),
+    );
+    bond
+}
+
+benchmarks! {
+    bond_decryption_node {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let bond = fund_node::<T>(&caller);
+    }: bond_decryption_node(RawOrigin::Signed(caller.clone()), bond)
+    verify {
+        assert_eq!(DecryptionNodeBonds::<T>::get(&caller).active, bond);
+    }
+
+    unbond_decryption_node {
+        let caller: T::AccountId = account("Alice", 0, 1);
+        let bond = fund_node::<T>(&caller);
+        Pallet::<T>::bond_decryption_node(RawOrigin::Signed(caller.clone()).into(), bond)?;
+
+        // the caller is the last of a full authority set
+        let max_authorities = <T as pallet_subnet_emission::Config>::MaxAuthorities::get();
+        let mut authorities: Vec<_> = (1..max_authorities)
+            .map(|i| (account("Authority", i, 1), (vec![0; 256], vec![1, 0, 1])))
+            .collect();
+        authorities.push((caller.clone(), (vec![0; 256], vec![1, 0, 1])));
+        Authorities::<T>::set(BoundedVec::truncate_from(authorities));
+    }: unbond_decryption_node(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert_eq!(DecryptionNodeBonds::<T>::get(&caller).unbonding, bond);
+    }
+
+    send_decryption_shares {
+        let s in 1 .. MAX_DECRYPTION_SHARES;
+
+        let subnet_id = 0;
+        let caller: T::AccountId = account("Alice", 0, 1);
+
+        // the caller is the last member of the largest committee to submit
+        let max_authorities = <T as pallet_subnet_emission::Config>::MaxAuthorities::get();
+        let others: Vec<(T::AccountId, u16)> =
+            (1..max_authorities).map(|i| (account("Authority", i, 1), i as u16)).collect();
+        let mut members = others.clone();
+        members.push((caller.clone(), max_authorities as u16));
+        DecryptionCommittee::<T>::put(DecryptionCommitteeInfo {
+            public_key: (vec![0; 32], vec![1, 0, 1]),
+            threshold: 1,
+            members,
+        });
+        DecryptionNodeBanQueue::<T>::insert(subnet_id, &caller, 0);
+
+        let share = DecryptionShare::truncate_from(vec![1; DECRYPTION_SHARE_LEN as usize]);
+        let submitted: Vec<_> =
+            others.into_iter().map(|(member, _)| (member, share.clone())).collect();
+        for block in 0..u64::from(s) {
+            DecryptionShares::<T>::insert(
+                subnet_id,
+                (block, 0),
+                BoundedVec::truncate_from(submitted.clone()),
+            );
+        }
+        let shares = DecryptionShareSet::truncate_from(
+            (0..u64::from(s)).map(|block| (block, 0, share.clone())).collect(),
+        );
+    }: {
+        // the unsigned payload can't be signed by a benchmark account, this is the dispatch body
+        assert!(DecryptionCommittee::<T>::get().is_some());
+        assert!(pallet_subnet_emission::Pallet::<T>::is_decryption_due(subnet_id));
+        pallet_subnet_emission::Pallet::<T>::handle_decryption_shares(
+            subnet_id,
+            caller.clone(),
+            shares,
+        );
+    }
+    verify {
+        assert_eq!(
+            DecryptionShares::<T>::get(subnet_id, (0, 0)).len(),
+            max_authorities as usize
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+/// Weights decrypted from a module's ciphertext, with the validator key they were encrypted for
+pub type DecryptedWeight = (Vec<(u16, u16)>, Vec<u8>);
+
+/// Outcome of simulating copying on one epoch: (should decrypt, delta, simulation state)
+pub type CachedSimulation<T> = (bool, I64F64, ConsensusSimulationResult<T>);
+
+// Offchain storage can not be iterated, so every key cached for a subnet is recorded in an index
+// that is walked when the subnet state is deleted. The index is kept as one entry per key behind a
+// counter, so recording a key does not rewrite the keys recorded before it.
+fn index_len_key(subnet_id: u16) -> Vec<u8> {
+    alloc::format!("ow_cache_index:{subnet_id}").into_bytes()
+}
+
+fn index_entry_key(subnet_id: u16, position: u32) -> Vec<u8> {
+    alloc::format!("ow_cache_index:{subnet_id}:{position}").into_bytes()
+}
+
+fn decrypted_weight_key(subnet_id: u16, block: u64, uid: u16, encrypted: &[u8]) -> Vec<u8> {
+    let ciphertext_hash = sp_io::hashing::blake2_256(encrypted);
+    (b"decrypted_weight", subnet_id, block, uid, ciphertext_hash).encode()
+}
+
+fn simulation_key<T: Config>(
+    subnet_id: u16,
+    block: u64,
+    simulation_result: &ConsensusSimulationResult<T>,
+    weights: &[(u16, Vec<(u16, u16)>)],
+) -> Vec<u8> {
+    // the simulation of an epoch builds on the state it started from and on the weights that
+    // could be decrypted, a committee can still be missing shares for some of them
+    let state_hash = sp_io::hashing::blake2_256(&simulation_result.encode());
+    let weights_hash = sp_io::hashing::blake2_256(&weights.encode());
+    (b"simulation", subnet_id, block, state_hash, weights_hash).encode()
+}
+
+impl<T: Config> Pallet<T> {
+    /// Decrypts a module's weights through `decrypt`, unless the same ciphertext was already
+    /// decrypted for that epoch. Only successful decryptions are cached, a committee can still be
+    /// missing shares that arrive later.
+    pub fn decrypt_weight_cached(
+        subnet_id: u16,
+        block: u64,
+        uid: u16,
+        encrypted: Vec<u8>,
+        decrypt: impl FnOnce(Vec<u8>) -> Option<DecryptedWeight>,
+    ) -> Option<DecryptedWeight> {
+        let key = decrypted_weight_key(subnet_id, block, uid, &encrypted);
+        let storage = StorageValueRef::persistent(&key);
+
+        if let Ok(Some(decrypted)) = storage.get::<DecryptedWeight>() {
+            return Some(decrypted);
+        }
+
+        let decrypted = decrypt(encrypted)?;
+        storage.set(&decrypted);
+        Self::index_cache_key(subnet_id, key);
+
+        Some(decrypted)
+    }
+
+    /// The simulation of an epoch, if it was already run from the given state on the same
+    /// decrypted weights.
+    pub fn cached_simulation(
+        subnet_id: u16,
+        block: u64,
+        simulation_result: &ConsensusSimulationResult<T>,
+        weights: &[(u16, Vec<(u16, u16)>)],
+    ) -> Option<CachedSimulation<T>> {
+        let key = simulation_key(subnet_id, block, simulation_result, weights);
+        StorageValueRef::persistent(&key).get().ok().flatten()
+    }
+
+    pub fn cache_simulation(
+        subnet_id: u16,
+        block: u64,
+        simulation_result: &ConsensusSimulationResult<T>,
+        weights: &[(u16, Vec<(u16, u16)>)],
+        simulation: &CachedSimulation<T>,
+    ) {
+        let key = simulation_key(subnet_id, block, simulation_result, weights);
+        StorageValueRef::persistent(&key).set(simulation);
+        Self::index_cache_key(subnet_id, key);
+    }
+
+    fn index_cache_key(subnet_id: u16, key: Vec<u8>) {
+        let len_key = index_len_key(subnet_id);
+        let len_storage = StorageValueRef::persistent(&len_key);
+
+        let len = len_storage.get::<u32>().ok().flatten().unwrap_or_default();
+        StorageValueRef::persistent(&index_entry_key(subnet_id, len)).set(&key);
+        len_storage.set(&len.saturating_add(1));
+    }
+
+    /// Drops everything cached for the subnet.
+    pub(crate) fn clear_subnet_cache(subnet_id: u16) {
+        let len_key = index_len_key(subnet_id);
+        let mut len_storage = StorageValueRef::persistent(&len_key);
+
+        for position in 0..len_storage.get::<u32>().ok().flatten().unwrap_or_default() {
+            let mut entry = StorageValueRef::persistent(&index_entry_key(subnet_id, position));
+            if let Ok(Some(key)) = entry.get::<Vec<u8>>() {
+                StorageValueRef::persistent(&key).clear();
+            }
+            entry.clear();
+        }
+        len_storage.clear();
+    }
+}
@@ -80,6 +80,13 @@ pub mod dispatches {
                 Error::<T>::DecryptedWeightsLengthMismatch
             );
 
+            // The weights are combined off-chain from the committee's shares, their hashes are
+            // still checked against the commitments when they are handled
+            ensure!(
+                pallet_subnet_emission::Pallet::<T>::has_decryption_quorum(subnet_id),
+                Error::<T>::InsufficientDecryptionShares
+            );
+
             log::info!("setting irrationality delta to 0 at subnet {}", subnet_id);
             IrrationalityDelta::<T>::set(subnet_id, I64F64::from_num(0));
 
@@ -122,14 +129,17 @@ pub mod dispatches {
             ensure_none(origin)?;
 
             let KeepAlivePayload {
-                public_key: _,
+                public_key,
                 block_number,
                 public,
             } = payload;
 
             let acc_id = public.into_account();
 
-            pallet_subnet_emission::Pallet::<T>::handle_authority_node_ping(acc_id.clone());
+            pallet_subnet_emission::Pallet::<T>::handle_authority_node_ping(
+                acc_id.clone(),
+                public_key,
+            );
 
             Self::deposit_event(Event::KeepAliveSent {
                 block_number,
@@ -148,6 +158,11 @@ pub mod dispatches {
 
             Authorities::<T>::try_mutate(|authorities| {
                 new_authorities.into_iter().try_for_each(|(account_id, public_key)| {
+                    ensure!(
+                        pallet_subnet_emission::Pallet::<T>::is_decryption_node_bonded(&account_id),
+                        Error::<T>::DecryptionNodeNotBonded
+                    );
+
                     authorities
                         .try_push((account_id, public_key))
                         .map_err(|_| Error::<T>::TooManyAuthorities)
@@ -157,5 +172,109 @@ pub mod dispatches {
             Self::deposit_event(Event::AuthoritiesAdded);
             Ok(().into())
         }
+
+        #[pallet::call_index(3)]
+        #[pallet::weight((
+            <T as pallet::Config>::WeightInfo::send_decryption_shares(payload.shares.len() as u32),
+            DispatchClass::Normal,
+            Pays::No
+        ))]
+        pub fn send_decryption_shares(
+            origin: OriginFor<T>,
+            payload: DecryptionSharesPayload<T::Public, BlockNumberFor<T>>,
+            _signature: T::Signature,
+        ) -> DispatchResultWithPostInfo {
+            // Signature valiadation is performed by the validate unsigned function
+            ensure_none(origin)?;
+
+            let DecryptionSharesPayload {
+                subnet_id,
+                shares,
+                block_number,
+                public,
+            } = payload;
+
+            let acc_id = public.into_account();
+
+            let committee =
+                DecryptionCommittee::<T>::get().ok_or(Error::<T>::NoDecryptionCommittee)?;
+            ensure!(
+                committee.members.iter().any(|(member, _)| member == &acc_id),
+                Error::<T>::NotCommitteeMember
+            );
+            ensure!(
+                pallet_subnet_emission::Pallet::<T>::is_decryption_due(subnet_id),
+                Error::<T>::DecryptionNotDue
+            );
+
+            pallet_subnet_emission::Pallet::<T>::handle_decryption_shares(
+                subnet_id,
+                acc_id.clone(),
+                shares,
+            );
+
+            Self::deposit_event(Event::DecryptionSharesSent {
+                subnet_id,
+                block_number,
+                worker: acc_id,
+            });
+            Ok(().into())
+        }
+
+        /// Replaces the decryption committee, or removes it to go back to single node
+        /// decryption. Subnets switch keys on their next node assignment or rotation.
+        #[pallet::call_index(4)]
+        #[pallet::weight((Weight::zero(), DispatchClass::Operational, Pays::No))]
+        pub fn set_decryption_committee(
+            origin: OriginFor<T>,
+            committee: Option<DecryptionCommitteeInfo<T::AccountId>>,
+        ) -> DispatchResultWithPostInfo {
+            ensure_root(origin)?;
+
+            if let Some(committee) = &committee {
+                Self::validate_committee(committee)?;
+            }
+
+            let threshold = committee.as_ref().map(|committee| committee.threshold);
+            DecryptionCommittee::<T>::set(committee);
+
+            Self::deposit_event(Event::DecryptionCommitteeSet { threshold });
+            Ok(().into())
+        }
+
+        /// Bonds `amount` from the caller's balance for running a decryption node. Authorities
+        /// can only be added once their bond covers `MinDecryptionNodeBond`.
+        #[pallet::call_index(5)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::bond_decryption_node(), DispatchClass::Normal, Pays::Yes))]
+        pub fn bond_decryption_node(
+            origin: OriginFor<T>,
+            amount: u64,
+        ) -> DispatchResultWithPostInfo {
+            let key = ensure_signed(origin)?;
+            ensure!(amount > 0, Error::<T>::InvalidBondAmount);
+
+            pallet_subnet_emission::Pallet::<T>::bond_decryption_node(&key, amount)?;
+            Ok(().into())
+        }
+
+        /// Removes the caller from the authorities and starts unbonding its decryption node
+        /// bond, which is returned after `DecryptionNodeUnbondingPeriod` blocks.
+        #[pallet::call_index(6)]
+        #[pallet::weight((<T as pallet::Config>::WeightInfo::unbond_decryption_node(), DispatchClass::Normal, Pays::Yes))]
+        pub fn unbond_decryption_node(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let key = ensure_signed(origin)?;
+
+            // the committee's key shares can not be replaced by a single member leaving
+            let in_committee = DecryptionCommittee::<T>::get().is_some_and(|committee| {
+                committee.members.iter().any(|(member, _)| member == &key)
+            });
+            ensure!(!in_committee, Error::<T>::CommitteeMemberCannotUnbond);
+
+            ensure!(
+                pallet_subnet_emission::Pallet::<T>::unbond_decryption_node(&key),
+                Error::<T>::DecryptionNodeNotBonded
+            );
+            Ok(().into())
+        }
     }
 }
@@ -21,16 +21,22 @@ use pallet_subnet_emission::{
         },
         yuma::YumaEpoch,
     },
-    types::{BlockWeights, PublicKey},
-    Authorities, DecryptionNodeBanQueue, SubnetDecryptionData,
+    types::{
+        BlockWeights, DecryptionCommitteeInfo, DecryptionShare, DecryptionShareSet, PublicKey,
+        MAX_DECRYPTION_SHARES,
+    },
+    Authorities, DecryptionCommittee, DecryptionNodeBanQueue, DecryptionShares,
+    SubnetDecryptionData,
 };
 
 use sp_std::collections::btree_map::BTreeMap;
 
+use cache::DecryptedWeight;
 use pallet_subnet_emission::{ConsensusParameters, Weights};
 use pallet_subspace::{
     math::{inplace_normalize_64, vec_fixed64_to_fixed32},
-    Consensus, CopierMargin, MinFees,
+    BondsAveraging, Consensus, CopierBondsAveraging, CopierDelegationFee, CopierMargin,
+    CopierStakeFraction, MinFees,
 };
 use parity_scale_codec::{Decode, Encode};
 use scale_info::prelude::marker::PhantomData;
@@ -43,16 +49,22 @@ use sp_runtime::{
 };
 use substrate_fixed::types::I32F32;
 use types::{
-    ConsensusSimulationResult, DecryptedWeightsPayload, KeepAlivePayload, ShouldDecryptResult,
+    ConsensusSimulationResult, CopierSimulation, DecryptedWeightsPayload, DecryptionSharesPayload,
+    KeepAlivePayload, SendReason, ShouldDecryptResult, SubnetDecision,
 };
 use util::process_consensus_params;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+mod cache;
 mod dispatches;
 pub mod migrations;
 mod process;
 mod profitability;
+mod shares;
 pub mod types;
 mod util;
+pub mod weights;
 
 pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"wcs!");
 
@@ -91,11 +103,13 @@ use substrate_fixed::types::I64F64;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
+    pub use crate::weights::WeightInfo;
     use frame_support::{
         pallet_prelude::{ValueQuery, *},
         Identity,
     };
     use frame_system::pallet_prelude::*;
+
     /// This pallet's configuration trait
     #[pallet::config]
     pub trait Config:
@@ -112,6 +126,9 @@ pub mod pallet {
 
         #[pallet::constant]
         type UnsignedPriority: Get<TransactionPriority>;
+
+        /// The weight information of this pallet.
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::validate_unsigned]
@@ -130,16 +147,21 @@ pub mod pallet {
                     Self::validate_signature_and_authority(&block_number, payload, signature)?;
                     Self::validate_unsigned_transaction(&block_number, "KeepAlive")
                 }
+                Call::send_decryption_shares { payload, signature } => {
+                    let block_number = payload.block_number;
+                    Self::validate_signature_and_authority(&block_number, payload, signature)?;
+                    Self::validate_unsigned_transaction(&block_number, "DecryptionShares")
+                }
                 _ => InvalidTransaction::Call.into(),
             }
         }
     }
 
     #[cfg(feature = "testnet")]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(16);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(17);
 
     #[cfg(not(feature = "testnet"))]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -206,6 +228,7 @@ pub mod pallet {
                 pallet_subnet_emission::Pallet::<T>::get_valid_subnets(Some(&acc_id));
 
             log::info!("Valid subnets: {:?}", valid_subnets);
+            ow_extensions::offworker::report_subnets_assigned(valid_subnets.len() as u32);
             // The runtime mimics this logic, by deleting all storages related to consenus
             // parameters and weights
             hanging_subnets.iter().for_each(|subnet_id| {
@@ -213,6 +236,12 @@ pub mod pallet {
                 Self::delete_subnet_state(subnet_id);
             });
 
+            // Committee members release their shares of every subnet due for decryption, not only
+            // of the subnets assigned to them
+            Self::send_decryption_shares(&acc_id);
+
+            Self::retire_previous_decryption_keys(&acc_id, &public);
+
             let (queued_for_ban, remaining_valid_subnets) =
                 Self::handle_banned_subnets(&valid_subnets, &acc_id);
 
@@ -249,6 +278,14 @@ pub mod pallet {
             subnet_id: u16,
             node_id: T::AccountId,
         },
+        /// Committee member sent its decryption shares
+        DecryptionSharesSent {
+            subnet_id: u16,
+            block_number: BlockNumberFor<T>,
+            worker: T::AccountId,
+        },
+        /// The decryption committee was replaced or removed
+        DecryptionCommitteeSet { threshold: Option<u16> },
     }
 
     #[pallet::error]
@@ -263,19 +300,26 @@ pub mod pallet {
         EmptyDecryptedWeights,
         /// Send more decrypted weights than expected epoch count
         DecryptedWeightsLengthMismatch,
+        /// Not enough committee members submitted their decryption shares yet
+        InsufficientDecryptionShares,
+        /// There is no decryption committee
+        NoDecryptionCommittee,
+        /// The sender is not a member of the decryption committee
+        NotCommitteeMember,
+        /// The subnet's weights are not due for decryption yet
+        DecryptionNotDue,
+        /// Threshold is zero or above the member count, or share indices are zero or repeated
+        InvalidCommittee,
+        /// Committee members have to be authorities
+        CommitteeMemberNotAuthority,
+        /// The decryption node has no bond, or its bond is below the minimum
+        DecryptionNodeNotBonded,
+        /// Bond amount has to be above zero
+        InvalidBondAmount,
+        /// Decryption committee members can not unbond while in the committee
+        CommitteeMemberCannotUnbond,
     }
 
-    // 10 % of total active stake
-    #[pallet::type_value]
-    pub fn DefaultMeasuredStakeAmount<T: Config>() -> Percent {
-        Percent::from_percent(10u8)
-    }
-
-    /// The amount of actual consensus sum stake. Used for a simulated consensus.
-    #[pallet::storage]
-    pub type MeasuredStakeAmount<T: Config> =
-        StorageValue<_, Percent, ValueQuery, DefaultMeasuredStakeAmount<T>>;
-
     /// The amount of delta between comulative copier dividends and compulative delegator dividends.
     #[pallet::storage]
     pub type IrrationalityDelta<T: Config> = StorageMap<_, Identity, u16, I64F64, ValueQuery>;
@@ -346,8 +390,10 @@ impl<T: Config> Pallet<T> {
 
     /// Decrypts all subnet weights for a given netuid. Returns a vector of block weights,
     /// maintaining the length of consensus parameters. Failed decryptions result in empty vectors.
-    /// V2 TODO: make more efficient in v2, we can be just caching this
+    /// Weights already decrypted while simulating the subnet are taken from the offchain cache.
     pub(crate) fn decrypt_all_subnet_weighs(netuid: u16) -> Vec<BlockWeights> {
+        let committee = DecryptionCommittee::<T>::get();
+
         ConsensusParameters::<T>::iter_prefix(netuid)
             .map(|(block_number, consensus_params)| {
                 let uid_weights: Vec<_> = consensus_params
@@ -356,7 +402,11 @@ impl<T: Config> Pallet<T> {
                     .map(|module_params| {
                         // Removed the (_, module_params) pattern
                         if !module_params.weight_encrypted.is_empty() {
-                            ow_extensions::offworker::decrypt_weight(
+                            Self::decrypt_module_weight(
+                                committee.as_ref(),
+                                netuid,
+                                block_number,
+                                module_params.uid,
                                 module_params.weight_encrypted.clone(),
                             )
                             .map(|(weights, key)| (module_params.uid, weights, key))
@@ -372,6 +422,31 @@ impl<T: Config> Pallet<T> {
             .collect()
     }
 
+    /// Decrypts a module's weights with the committee's shares if there is a committee, with the
+    /// node's key otherwise.
+    pub(crate) fn decrypt_module_weight(
+        committee: Option<&DecryptionCommitteeInfo<T::AccountId>>,
+        netuid: u16,
+        block_number: u64,
+        uid: u16,
+        encrypted: Vec<u8>,
+    ) -> Option<DecryptedWeight> {
+        let decrypt = |encrypted| match committee {
+            Some(committee) => ow_extensions::offworker::combine_decryption_shares(
+                committee.public_key.clone(),
+                encrypted,
+                pallet_subnet_emission::Pallet::<T>::decryption_shares_for(
+                    netuid,
+                    block_number,
+                    uid,
+                ),
+                committee.threshold,
+            ),
+            None => ow_extensions::offworker::decrypt_weight(encrypted),
+        };
+        Self::decrypt_weight_cached(netuid, block_number, uid, encrypted, decrypt)
+    }
+
     fn do_send_weights(
         subnet_id: u16,
         delta: I64F64,
@@ -384,6 +459,10 @@ impl<T: Config> Pallet<T> {
             );
         }
 
+        if !pallet_subnet_emission::Pallet::<T>::has_decryption_quorum(subnet_id) {
+            return Err("Waiting for the decryption shares of the committee");
+        }
+
         let decrypted_weights = Self::decrypt_all_subnet_weighs(subnet_id);
 
         log::info!("Sending decrypted weights to subnet {}", subnet_id);
@@ -417,6 +496,7 @@ impl<T: Config> Pallet<T> {
                         subnet_id,
                         e
                     );
+                    ow_extensions::offworker::report_submission_error();
                     "Failed"
                 }
             };
@@ -431,13 +511,22 @@ impl<T: Config> Pallet<T> {
     // Get this from onchain storage, this should not run on every block but `KeepAlive` interval
     fn do_send_ping(current_block: u64) -> Result<(), DispatchError> {
         let storage = StorageValueRef::persistent(b"last_keep_alive");
-
-        if storage.get::<u64>().ok().flatten().map_or(true, |last| {
-            current_block.saturating_sub(last) >= T::PingInterval::get()
-        }) {
-            let public_key = ow_extensions::offworker::get_encryption_key()
-                .ok_or(DispatchError::Other("Failed to get encryption key"))?;
-
+        let key_storage = StorageValueRef::persistent(b"last_keep_alive_key");
+
+        let public_key = ow_extensions::offworker::get_encryption_key()
+            .ok_or(DispatchError::Other("Failed to get encryption key"))?;
+        // A rotated key is announced right away instead of waiting for the interval
+        let key_changed = key_storage
+            .get::<(Vec<u8>, Vec<u8>)>()
+            .ok()
+            .flatten()
+            .map_or(true, |announced| announced != public_key);
+
+        if key_changed
+            || storage.get::<u64>().ok().flatten().map_or(true, |last| {
+                current_block.saturating_sub(last) >= T::PingInterval::get()
+            })
+        {
             let signer = Signer::<T, T::AuthorityId>::all_accounts();
             if !signer.can_sign() {
                 return Err(DispatchError::Other(
@@ -458,11 +547,28 @@ impl<T: Config> Pallet<T> {
                 .try_for_each(|(_, result)| {
                     result.map_err(|e| {
                         log::error!("Failed to send keep-alive transaction: {:?}", e);
+                        ow_extensions::offworker::report_submission_error();
                         DispatchError::Other("Failed to send keep-alive transaction")
                     })
                 })?;
             storage.set(&current_block);
+            key_storage.set(&public_key);
+            ow_extensions::offworker::report_ping(current_block);
         }
         Ok(())
     }
+
+    /// Lets the node drop the keys it used before its rotations once the chain switched to the
+    /// new key, each as soon as no weights encrypted to it are left.
+    fn retire_previous_decryption_keys(acc_id: &T::AccountId, public_key: &PublicKey) {
+        let announced = Authorities::<T>::get()
+            .iter()
+            .any(|(account, key)| account == acc_id && key == public_key);
+
+        if announced {
+            ow_extensions::offworker::retire_decryption_keys(
+                pallet_subnet_emission::Pallet::<T>::retained_decryption_keys(acc_id),
+            );
+        }
+    }
 }
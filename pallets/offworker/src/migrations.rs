@@ -27,3 +27,49 @@ pub mod v1 {
         }
     }
 }
+
+pub mod v2 {
+    use super::*;
+    use frame_support::storage_alias;
+    use pallet_subspace::{CopierStakeFraction, N};
+
+    pub mod old_storage {
+        use super::*;
+
+        #[storage_alias]
+        pub type MeasuredStakeAmount<T: Config> = StorageValue<Pallet<T>, Percent>;
+    }
+
+    /// Moves the global `MeasuredStakeAmount` into the per-subnet `CopierStakeFraction`.
+    pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = StorageVersion::get::<Pallet<T>>();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (1, 2);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (16, 17);
+
+            if on_chain_version != from {
+                log::info!("Storage v2 is already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+
+            let mut count: u64 = 1;
+            // subnets only differ from the default if the global value was changed
+            if let Some(measured_stake) = old_storage::MeasuredStakeAmount::<T>::take() {
+                for netuid in N::<T>::iter_keys() {
+                    count = count.saturating_add(1);
+                    CopierStakeFraction::<T>::insert(netuid, measured_stake);
+                }
+            }
+
+            log::info!("Migrated to v2");
+            T::DbWeight::get().reads_writes(count, count)
+        }
+    }
+}
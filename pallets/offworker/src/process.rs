@@ -9,74 +9,127 @@ impl<T: Config> Pallet<T> {
         let mut deregistered_subnets = Vec::new();
 
         for subnet_id in subnets {
-            let params = ConsensusParameters::<T>::iter_prefix(subnet_id).collect::<Vec<_>>();
-            let max_block = params.iter().fold(0, |max, (block, _)| max.max(*block));
-            let subnet_registration_block =
-                pallet_subspace::SubnetRegistrationBlock::<T>::get(subnet_id).unwrap_or(0);
-
-            let copier_margin = CopierMargin::<T>::get(subnet_id);
-            let max_encryption_period =
-                pallet_subnet_emission::Pallet::<T>::get_max_encryption_interval(&subnet_id);
-
-            let (last_processed_block, simulation_result) = Self::get_subnet_state(
-                subnet_id,
-                current_block,
-                copier_margin,
-                max_encryption_period,
-            );
-
-            // check if the subnet has been deregistered
-            if subnet_registration_block > current_block {
-                log::info!("Skipping subnet {} as it has been deregistered", subnet_id);
-                deregistered_subnets.push(subnet_id);
-                continue;
+            match Self::process_subnet(subnet_id, &acc_id, current_block) {
+                SubnetDecision::Deregistered => deregistered_subnets.push(subnet_id),
+                SubnetDecision::Send { delta, reason } => {
+                    let forced_send = reason == SendReason::Rotation;
+                    if let Err(err) = Self::do_send_weights(subnet_id, delta, forced_send) {
+                        log::error!(
+                            "Couldn't send weights to runtime for subnet {}: {}",
+                            subnet_id,
+                            err
+                        );
+                    }
+                }
+                SubnetDecision::UpToDate
+                | SubnetDecision::AwaitingShares
+                | SubnetDecision::Hold { .. } => {}
             }
+        }
+
+        deregistered_subnets
+    }
+
+    /// Simulates the epochs of the subnet not processed yet and decides whether its weights
+    /// should be sent. Nothing is submitted, so it can be replayed outside of the offchain worker.
+    pub fn process_subnet(
+        subnet_id: u16,
+        acc_id: &T::AccountId,
+        current_block: u64,
+    ) -> SubnetDecision {
+        let params = ConsensusParameters::<T>::iter_prefix(subnet_id).collect::<Vec<_>>();
+        let max_block = params.iter().fold(0, |max, (block, _)| max.max(*block));
+        let subnet_registration_block =
+            pallet_subspace::SubnetRegistrationBlock::<T>::get(subnet_id).unwrap_or(0);
+
+        let copier_margin = CopierMargin::<T>::get(subnet_id);
+        let max_encryption_period =
+            pallet_subnet_emission::Pallet::<T>::get_max_encryption_interval(&subnet_id);
+
+        let (last_processed_block, simulation_result) = Self::get_subnet_state(
+            subnet_id,
+            current_block,
+            copier_margin,
+            max_encryption_period,
+        );
+
+        // check if the subnet has been deregistered
+        if subnet_registration_block > current_block {
+            log::info!("Skipping subnet {} as it has been deregistered", subnet_id);
+            return SubnetDecision::Deregistered;
+        }
+
+        log::info!(
+            "subnet state for subnet {} is {:?}",
+            subnet_id,
+            simulation_result
+        );
+
+        // How long the oldest epoch still encrypted has been waiting
+        let oldest_block = params.iter().map(|(block, _)| *block).min().unwrap_or(current_block);
+        ow_extensions::offworker::report_blocks_behind(
+            subnet_id,
+            current_block.saturating_sub(oldest_block),
+        );
 
+        if last_processed_block >= max_block {
             log::info!(
-                "subnet state for subnet {} is {:?}",
-                subnet_id,
-                simulation_result
+                "Skipping subnet {} as it has already been processed",
+                subnet_id
             );
+            return SubnetDecision::UpToDate;
+        }
 
-            if last_processed_block >= max_block {
-                log::info!(
-                    "Skipping subnet {} as it has already been processed",
-                    subnet_id
-                );
-                continue;
-            }
-
+        // Committee encrypted weights can't be decrypted to simulate them before the shares are in
+        if !pallet_subnet_emission::Pallet::<T>::has_decryption_quorum(subnet_id) {
             log::info!(
-                "Processing subnet {} from block {} to {}",
-                subnet_id,
-                last_processed_block,
-                max_block
+                "Skipping subnet {} until the committee released its decryption shares",
+                subnet_id
             );
+            return SubnetDecision::AwaitingShares;
+        }
 
-            let new_params = params
-                .into_iter()
-                .filter(|(block, _)| *block > last_processed_block)
-                .collect::<Vec<_>>();
+        log::info!(
+            "Processing subnet {} from block {} to {}",
+            subnet_id,
+            last_processed_block,
+            max_block
+        );
 
-            let (send_weights, result, forced_send) = process_consensus_params::<T>(
-                subnet_id,
-                acc_id.clone(),
-                new_params,
-                simulation_result,
-            );
+        let new_params = params
+            .into_iter()
+            .filter(|(block, _)| *block > last_processed_block)
+            .collect::<Vec<_>>();
 
-            if !send_weights {
-                Self::save_subnet_state(subnet_id, max_block, result.simulation_result);
-            } else if let Err(err) = Self::do_send_weights(subnet_id, result.delta, forced_send) {
-                log::error!(
-                    "Couldn't send weights to runtime for subnet {}: {}",
-                    subnet_id,
-                    err
-                );
-            }
+        let (send_weights, result, forced_send) =
+            process_consensus_params::<T>(subnet_id, acc_id.clone(), new_params, simulation_result);
+        ow_extensions::offworker::report_irrationality_delta(
+            subnet_id,
+            result.delta.to_num::<f64>().to_bits(),
+        );
+
+        if !send_weights {
+            Self::save_subnet_state(subnet_id, max_block, result.simulation_result);
+            return SubnetDecision::Hold {
+                delta: result.delta,
+            };
         }
 
-        deregistered_subnets
+        // The weights are sent on the last epoch, see `is_copying_irrational`
+        let encryption_window_len =
+            max_block.saturating_sub(result.simulation_result.creation_block);
+        let reason = if forced_send {
+            SendReason::Rotation
+        } else if encryption_window_len >= result.simulation_result.max_encryption_period {
+            SendReason::EncryptionPeriodOver
+        } else {
+            SendReason::CopyingIrrational
+        };
+
+        SubnetDecision::Send {
+            delta: result.delta,
+            reason,
+        }
     }
 
     fn get_subnet_state(
@@ -133,5 +186,7 @@ impl<T: Config> Pallet<T> {
         let storage_key = alloc::format!("subnet_state:{subnet_id}");
         let mut storage = StorageValueRef::persistent(storage_key.as_bytes());
         storage.clear();
+        Self::clear_subnet_cache(*subnet_id);
+        ow_extensions::offworker::report_blocks_behind(*subnet_id, 0);
     }
 }
@@ -89,9 +89,85 @@ where
     average_dividends.saturating_mul(fee_factor).saturating_mul(copier_stake).into()
 }
 
-pub fn get_copier_stake<T>(active_stake: u64) -> u64
+pub fn get_copier_stake<T>(subnet_id: u16, active_stake: u64) -> u64
 where
     T: pallet_subspace::Config + pallet::Config,
 {
-    MeasuredStakeAmount::<T>::get().mul_floor(active_stake)
+    CopierStakeFraction::<T>::get(subnet_id).mul_floor(active_stake)
+}
+
+impl<T: Config> Pallet<T> {
+    /// Simulates copying the subnet's consensus over every epoch still waiting for decryption,
+    /// deterministically from `ConsensusParameters`. Returns `None` if no epoch is encrypted.
+    ///
+    /// The reveal block is projected as the first epoch copying turns irrational at, or the end
+    /// of the max encryption period if it never does.
+    ///
+    /// Validators are simulated with the last weights they revealed, as the encrypted ones are
+    /// unknown on-chain, so this only approximates the offworker, which simulates the decrypted
+    /// weights.
+    pub fn simulate_copier_profitability(subnet_id: u16) -> Option<CopierSimulation> {
+        let mut epochs: Vec<_> = ConsensusParameters::<T>::iter_prefix(subnet_id).collect();
+        epochs.sort_by_key(|(block, _)| *block);
+
+        let (creation_block, first_params) = epochs.first()?;
+        let creation_block = *creation_block;
+        let max_encryption_period =
+            pallet_subnet_emission::Pallet::<T>::get_max_encryption_interval(&subnet_id);
+
+        let mut copier_bonds = crate::util::calculate_average_bonds(first_params);
+        let mut copier_stake = 0;
+        let mut simulation_result = ConsensusSimulationResult::<T> {
+            cumulative_avg_delegate_divs: IrrationalityDelta::<T>::get(subnet_id),
+            creation_block,
+            copier_margin: CopierMargin::<T>::get(subnet_id),
+            max_encryption_period,
+            ..Default::default()
+        };
+        let mut last_block = creation_block;
+        let mut revealed_at = None;
+        let mut simulated_epochs = 0u32;
+
+        for (block, params) in epochs {
+            copier_stake = crate::util::simulated_copier_stake(&params);
+            last_block = block;
+            simulated_epochs = simulated_epochs.saturating_add(1);
+
+            let (result, bonds) = crate::util::simulate_copying(
+                &[],
+                params,
+                subnet_id,
+                simulation_result.clone(),
+                block,
+                copier_bonds.clone(),
+            );
+
+            // epochs the copier could not be simulated on leave the state untouched
+            if let Some(bonds) = bonds {
+                copier_bonds = bonds;
+                simulation_result = result.simulation_result;
+            }
+
+            if result.should_decrypt {
+                revealed_at = Some(block);
+                break;
+            }
+        }
+
+        let (_, delta) = is_copying_irrational(simulation_result.clone(), last_block);
+
+        Some(CopierSimulation {
+            copier_stake,
+            cumulative_copier_divs: simulation_result.cumulative_copier_divs,
+            cumulative_avg_delegate_divs: simulation_result.cumulative_avg_delegate_divs,
+            copier_margin: simulation_result.copier_margin,
+            delta,
+            is_copying_irrational: revealed_at.is_some(),
+            simulated_epochs,
+            creation_block,
+            max_encryption_period,
+            projected_reveal_block: revealed_at
+                .unwrap_or_else(|| creation_block.saturating_add(max_encryption_period)),
+        })
+    }
 }
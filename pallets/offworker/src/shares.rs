@@ -0,0 +1,120 @@
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Sends this node's decryption shares of the encrypted weights of every subnet that is due
+    /// for decryption, if the node is a member of the decryption committee.
+    pub fn send_decryption_shares(acc_id: &T::AccountId) {
+        let Some(committee) = DecryptionCommittee::<T>::get() else {
+            return;
+        };
+        if !committee.members.iter().any(|(member, _)| member == acc_id) {
+            return;
+        }
+
+        for subnet_id in SubnetDecryptionData::<T>::iter_keys() {
+            if !pallet_subnet_emission::Pallet::<T>::is_decryption_due(subnet_id) {
+                continue;
+            }
+
+            let shares: Vec<_> = ConsensusParameters::<T>::iter_prefix(subnet_id)
+                .flat_map(|(block, params)| {
+                    params
+                        .modules
+                        .into_values()
+                        .filter(|module| !module.weight_encrypted.is_empty())
+                        .map(move |module| (block, module))
+                })
+                .filter(|(block, module)| {
+                    !DecryptionShares::<T>::get(subnet_id, (*block, module.uid))
+                        .iter()
+                        .any(|(member, _)| member == acc_id)
+                })
+                .filter_map(|(block, module)| {
+                    ow_extensions::offworker::decryption_share(module.weight_encrypted)
+                        .and_then(|share| DecryptionShare::try_from(share).ok())
+                        .map(|share| (block, module.uid, share))
+                })
+                .take(MAX_DECRYPTION_SHARES as usize)
+                .collect();
+            // the rest of the shares is sent in the next blocks
+            let shares = DecryptionShareSet::truncate_from(shares);
+
+            if shares.is_empty() {
+                continue;
+            }
+
+            if let Err(err) = Self::do_send_decryption_shares(subnet_id, shares) {
+                log::error!(
+                    "Couldn't send decryption shares for subnet {}: {}",
+                    subnet_id,
+                    err
+                );
+            }
+        }
+    }
+
+    fn do_send_decryption_shares(
+        subnet_id: u16,
+        shares: DecryptionShareSet,
+    ) -> Result<(), &'static str> {
+        let signer = Signer::<T, T::AuthorityId>::all_accounts();
+        if !signer.can_sign() {
+            return Err(
+                "No local accounts available. Consider adding one via `author_insertKey` RPC.",
+            );
+        }
+
+        log::info!(
+            "Sending {} decryption shares to subnet {}",
+            shares.len(),
+            subnet_id
+        );
+
+        signer
+            .send_unsigned_transaction(
+                |account| DecryptionSharesPayload {
+                    subnet_id,
+                    shares: shares.clone(),
+                    block_number: <system::Pallet<T>>::block_number(),
+                    public: account.public.clone(),
+                },
+                |payload, signature| Call::send_decryption_shares { payload, signature },
+            )
+            .into_iter()
+            .try_for_each(|(_, result)| {
+                result.map_err(|_| {
+                    ow_extensions::offworker::report_submission_error();
+                    "Failed to send decryption shares transaction"
+                })
+            })
+    }
+
+    pub(crate) fn validate_committee(
+        committee: &DecryptionCommitteeInfo<T::AccountId>,
+    ) -> Result<(), Error<T>> {
+        let members = committee.members.len();
+        frame_support::ensure!(
+            committee.threshold > 0 && usize::from(committee.threshold) <= members,
+            Error::<T>::InvalidCommittee
+        );
+
+        let mut indices: Vec<u16> = committee.members.iter().map(|(_, index)| *index).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        frame_support::ensure!(
+            indices.len() == members && !indices.contains(&0),
+            Error::<T>::InvalidCommittee
+        );
+
+        let authorities = Authorities::<T>::get();
+        frame_support::ensure!(
+            committee
+                .members
+                .iter()
+                .all(|(member, _)| authorities.iter().any(|(account, _)| account == member)),
+            Error::<T>::CommitteeMemberNotAuthority
+        );
+
+        Ok(())
+    }
+}
@@ -71,6 +71,51 @@ impl<T: pallet_subspace::Config> Default for ShouldDecryptResult<T> {
     }
 }
 
+/// Outcome of processing the encrypted epochs of a subnet on a block.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SubnetDecision {
+    /// The subnet was deregistered after its weights were encrypted
+    Deregistered,
+    /// Every epoch was already processed on an earlier block
+    UpToDate,
+    /// The committee did not release enough decryption shares to decrypt the weights yet
+    AwaitingShares,
+    /// Copying is still profitable, the weights stay encrypted
+    Hold { delta: I64F64 },
+    /// The decrypted weights are sent to the runtime
+    Send { delta: I64F64, reason: SendReason },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendReason {
+    /// Copiers would no longer profit from copying the consensus
+    CopyingIrrational,
+    /// The weights were encrypted for as long as the subnet allows
+    EncryptionPeriodOver,
+    /// The node is rotating away from the subnet and hands over its decrypted weights
+    Rotation,
+}
+
+/// Copier simulation of a subnet run from on-chain data only, as exposed by the runtime API.
+///
+/// Weights still encrypted are unknown on-chain, so validators are simulated with the last weights
+/// they revealed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CopierSimulation {
+    pub copier_stake: u64,
+    pub cumulative_copier_divs: I64F64,
+    pub cumulative_avg_delegate_divs: I64F64,
+    pub copier_margin: I64F64,
+    /// Copier dividends above the margin over the delegate dividends, negative once copying is
+    /// irrational.
+    pub delta: I64F64,
+    pub is_copying_irrational: bool,
+    pub simulated_epochs: u32,
+    pub creation_block: u64,
+    pub max_encryption_period: u64,
+    pub projected_reveal_block: u64,
+}
+
 pub struct SimulationYumaParams<T: Config> {
     pub uid: u16,
     pub params: ConsensusParams<T>,
@@ -87,6 +132,15 @@ pub struct DecryptedWeightsPayload<Public, BlockNumber> {
     pub forced_send_by_rotation: bool,
 }
 
+/// Decryption shares of a committee member for a subnet's encrypted weights.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
+pub struct DecryptionSharesPayload<Public, BlockNumber> {
+    pub subnet_id: u16,
+    pub shares: DecryptionShareSet,
+    pub block_number: BlockNumber,
+    pub public: Public,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo)]
 pub struct KeepAlivePayload<Public, BlockNumber> {
     pub public_key: (Vec<u8>, Vec<u8>),
@@ -100,6 +154,12 @@ impl<T: SigningTypes> SignedPayload<T> for DecryptedWeightsPayload<T::Public, Bl
     }
 }
 
+impl<T: SigningTypes> SignedPayload<T> for DecryptionSharesPayload<T::Public, BlockNumberFor<T>> {
+    fn public(&self) -> T::Public {
+        self.public.clone()
+    }
+}
+
 impl<T: SigningTypes> SignedPayload<T> for KeepAlivePayload<T::Public, BlockNumberFor<T>> {
     fn public(&self) -> T::Public {
         self.public.clone()
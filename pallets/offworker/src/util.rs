@@ -21,6 +21,7 @@ where
 
     let mut final_should_send = false;
     let mut forced_send = false;
+    let committee = DecryptionCommittee::<T>::get();
 
     log::info!("Processing consensus params for subnet {}", subnet_id);
     log::info!(
@@ -71,8 +72,13 @@ where
                     Some((uid, Vec::new(), Vec::new()))
                 } else {
                     log::info!("encrypted weights are: {:?}", params.weight_encrypted);
-                    match ow_extensions::offworker::decrypt_weight(params.weight_encrypted.clone())
-                    {
+                    match Pallet::<T>::decrypt_module_weight(
+                        committee.as_ref(),
+                        subnet_id,
+                        *param_block,
+                        uid,
+                        params.weight_encrypted.clone(),
+                    ) {
                         Some((decrypted, key)) => {
                             log::info!(
                                 "Successfully decrypted weights for UID: {}, decrypted length: {}",
@@ -115,13 +121,40 @@ where
             weights_for_should_decrypt.len()
         );
 
-        let mut should_decrypt_result = should_decrypt_weights::<T>(
-            &weights_for_should_decrypt,
-            params.clone(),
+        // Epochs simulated on an earlier block that could not send the weights are not run again
+        let mut should_decrypt_result = match Pallet::<T>::cached_simulation(
             subnet_id,
-            simulation_result.clone(),
             *param_block,
-        );
+            &simulation_result,
+            &weights_for_should_decrypt,
+        ) {
+            Some((should_decrypt, delta, simulation_result)) => ShouldDecryptResult {
+                should_decrypt,
+                simulation_result,
+                delta,
+            },
+            None => {
+                let result = should_decrypt_weights::<T>(
+                    &weights_for_should_decrypt,
+                    params.clone(),
+                    subnet_id,
+                    simulation_result.clone(),
+                    *param_block,
+                );
+                Pallet::<T>::cache_simulation(
+                    subnet_id,
+                    *param_block,
+                    &simulation_result,
+                    &weights_for_should_decrypt,
+                    &(
+                        result.should_decrypt,
+                        result.delta,
+                        result.simulation_result.clone(),
+                    ),
+                );
+                result
+            }
+        };
 
         log::info!(
             "should_decrypt result: {}, delta: {}",
@@ -164,20 +197,53 @@ pub fn should_decrypt_weights<T: Config>(
     decrypted_weights: &[(u16, Vec<(u16, u16)>)],
     latest_runtime_yuma_params: ConsensusParams<T>,
     subnet_id: u16,
-    mut simulation_result: ConsensusSimulationResult<T>,
+    simulation_result: ConsensusSimulationResult<T>,
     block_number: u64,
 ) -> ShouldDecryptResult<T> {
+    let copier_bonds = get_copier_bonds::<T>(subnet_id, &latest_runtime_yuma_params);
+
+    let (result, copier_bonds) = simulate_copying::<T>(
+        decrypted_weights,
+        latest_runtime_yuma_params,
+        subnet_id,
+        simulation_result,
+        block_number,
+        copier_bonds,
+    );
+
+    // Save the copier bonds for future use
+    if let Some(copier_bonds) = copier_bonds {
+        save_copier_bonds(subnet_id, &copier_bonds);
+    }
+
+    result
+}
+
+/// Runs one epoch of the copier simulation starting from the given copier bonds, returning the
+/// result together with the copier bonds the epoch ended with.
+pub fn simulate_copying<T: Config>(
+    decrypted_weights: &[(u16, Vec<(u16, u16)>)],
+    latest_runtime_yuma_params: ConsensusParams<T>,
+    subnet_id: u16,
+    mut simulation_result: ConsensusSimulationResult<T>,
+    block_number: u64,
+    copier_bonds: Vec<(u16, u16)>,
+) -> (ShouldDecryptResult<T>, Option<Vec<(u16, u16)>>) {
     let simulation_params = match compute_simulation_yuma_params::<T>(
         decrypted_weights,
         latest_runtime_yuma_params,
         subnet_id,
+        copier_bonds,
     ) {
         Some(params) => params,
         None => {
-            return ShouldDecryptResult {
-                should_decrypt: true,
-                ..Default::default()
-            }
+            return (
+                ShouldDecryptResult {
+                    should_decrypt: true,
+                    ..Default::default()
+                },
+                None,
+            )
         }
     };
 
@@ -190,7 +256,7 @@ pub fn should_decrypt_weights<T: Config>(
     let decrypted_weights = decrypted_weights_map.into_iter().collect::<Vec<_>>();
     if decrypted_weights.is_empty() {
         log::info!("subnet {subnet_id} does not have any decrypted weights");
-        return ShouldDecryptResult::<T>::default();
+        return (ShouldDecryptResult::<T>::default(), None);
     }
 
     log::info!("simulation yuma params for subnet {subnet_id} are {simulation_yuma_params:?}");
@@ -198,19 +264,18 @@ pub fn should_decrypt_weights<T: Config>(
     // Run consensus simulation with error handling
     let simulation_yuma_output =
         match YumaEpoch::<T>::new(subnet_id, simulation_yuma_params).run(decrypted_weights) {
-            Ok(output) => {
-                // Save the copier bonds for future use
-                save_copier_bonds::<T>(subnet_id, &output);
-                output
-            }
+            Ok(output) => output,
             Err(e) => {
                 log::error!("Failed to run consensus simulation: {:?}", e);
-                return ShouldDecryptResult::default();
+                return (ShouldDecryptResult::default(), None);
             }
         };
 
-    // Get delegation fee (this is not a Result type)
-    let delegation_fee = MinFees::<T>::get().stake_delegation_fee;
+    let copier_bonds = simulation_yuma_output.bonds.last().cloned().flatten();
+
+    // Delegation fee assumed for the subnet, the minimum one unless configured
+    let delegation_fee = CopierDelegationFee::<T>::get(subnet_id)
+        .unwrap_or_else(|| MinFees::<T>::get().stake_delegation_fee);
 
     log::info!("simulation yuma output for subnet {subnet_id} is {simulation_yuma_output:?}");
 
@@ -229,11 +294,14 @@ pub fn should_decrypt_weights<T: Config>(
     }
     log::info!("Delta: {}", delta);
 
-    ShouldDecryptResult {
-        should_decrypt: is_irrational,
-        delta: delta.abs(),
-        simulation_result,
-    }
+    (
+        ShouldDecryptResult {
+            should_decrypt: is_irrational,
+            delta: delta.abs(),
+            simulation_result,
+        },
+        copier_bonds,
+    )
 }
 
 /// Appends copier information to simulated consensus ConsensusParams
@@ -242,6 +310,7 @@ pub fn compute_simulation_yuma_params<T: Config>(
     decrypted_weights: &[(u16, Vec<(u16, u16)>)],
     mut runtime_yuma_params: ConsensusParams<T>,
     subnet_id: u16,
+    copier_bonds: Vec<(u16, u16)>,
 ) -> Option<SimulationYumaParams<T>> {
     let copier_uid: u16 = runtime_yuma_params.modules.len() as u16;
 
@@ -267,7 +336,8 @@ pub fn compute_simulation_yuma_params<T: Config>(
         copier_weights
     );
 
-    runtime_yuma_params = add_copier_to_yuma_params(copier_uid, runtime_yuma_params, subnet_id);
+    runtime_yuma_params =
+        add_copier_to_yuma_params(copier_uid, runtime_yuma_params, subnet_id, copier_bonds);
 
     let mut onchain_weights: BTreeMap<u16, Vec<(u16, u16)>> =
         Weights::<T>::iter_prefix(subnet_id).collect();
@@ -298,18 +368,9 @@ pub fn add_copier_to_yuma_params<T: Config>(
     copier_uid: u16,
     mut runtime_yuma_params: ConsensusParams<T>,
     subnet_id: u16,
+    copier_bonds: Vec<(u16, u16)>,
 ) -> ConsensusParams<T> {
-    let copier_bonds = get_copier_bonds::<T>(subnet_id, &runtime_yuma_params);
-
-    // Calculate total active stake
-    let total_active_stake: u64 = runtime_yuma_params
-        .modules
-        .values()
-        .filter(|m| m.validator_permit)
-        .map(|m| m.stake_original.to_num::<u64>())
-        .sum();
-
-    let copier_stake = get_copier_stake::<T>(total_active_stake);
+    let copier_stake = simulated_copier_stake::<T>(&runtime_yuma_params);
     let current_block = runtime_yuma_params.current_block;
 
     // Collect original stakes, including the copier's stake
@@ -361,6 +422,19 @@ pub fn add_copier_to_yuma_params<T: Config>(
     runtime_yuma_params
 }
 
+/// Stake the simulated copier holds, a share of the total stake of the subnet's validators.
+pub fn simulated_copier_stake<T: Config>(consensus_params: &ConsensusParams<T>) -> u64 {
+    // Calculate total active stake
+    let total_active_stake: u64 = consensus_params
+        .modules
+        .values()
+        .filter(|m| m.validator_permit)
+        .map(|m| m.stake_original.to_num::<u64>())
+        .sum();
+
+    get_copier_stake::<T>(consensus_params.subnet_id, total_active_stake)
+}
+
 fn get_copier_bonds<T: Config>(
     subnet_id: u16,
     consensus_params: &ConsensusParams<T>,
@@ -378,16 +452,14 @@ fn get_copier_bonds<T: Config>(
     }
 }
 
-pub fn save_copier_bonds<T: Config>(subnet_id: u16, consensus_output: &ConsensusOutput<T>) {
+pub fn save_copier_bonds(subnet_id: u16, bonds: &[(u16, u16)]) {
     let storage_key = alloc::format!("copier_bonds:{subnet_id}");
     let storage = StorageValueRef::persistent(storage_key.as_bytes());
-
-    if let Some(Some(bonds)) = consensus_output.bonds.last() {
-        storage.set(bonds);
-    }
+    storage.set(&bonds);
 }
 
-/// Calculates the weighted average bonds across all active validators.
+/// Calculates the average bonds across all active validators, as configured by the subnet's
+/// `CopierBondsAveraging`.
 ///
 /// # Arguments
 ///
@@ -404,16 +476,18 @@ pub fn save_copier_bonds<T: Config>(subnet_id: u16, consensus_output: &Consensus
 /// The function:
 /// 1. Filters for active validators with validator permits
 /// 2. Handles special cases:
+///    - Returns empty vector if the subnet averages to empty bonds
 ///    - Returns empty vector if no active validators
 ///    - Returns bonds of single validator if only one exists
 /// 3. Calculates total stake across all validators
 /// 4. For multiple validators:
-///    - Computes weighted bond values based on each validator's stake proportion
+///    - Computes weighted bond values based on each validator's stake proportion, or on an equal
+///      share with uniform averaging
 ///    - Aggregates weighted bonds by target
 ///
 /// # Notes
 ///
-/// - Weights are calculated as: validator_stake / total_stake
+/// - Weights are calculated as: validator_stake / total_stake, or 1 / validator_count
 /// - Bond values are rounded to nearest integer
 /// - Handles potential arithmetic overflow using saturating addition
 /// - Returns empty vector if total stake is zero
@@ -421,7 +495,14 @@ pub fn save_copier_bonds<T: Config>(subnet_id: u16, consensus_output: &Consensus
 /// # Type Parameters
 ///
 /// * `T` - Must implement the `Config` trait
-fn calculate_average_bonds<T: Config>(consensus_params: &ConsensusParams<T>) -> Vec<(u16, u16)> {
+pub fn calculate_average_bonds<T: Config>(
+    consensus_params: &ConsensusParams<T>,
+) -> Vec<(u16, u16)> {
+    let averaging = CopierBondsAveraging::<T>::get(consensus_params.subnet_id);
+    if averaging == BondsAveraging::Empty {
+        return Vec::new();
+    }
+
     let active_validators: Vec<&ModuleParams<T::AccountId>> =
         consensus_params.modules.values().filter(|m| m.validator_permit).collect();
 
@@ -431,8 +512,13 @@ fn calculate_average_bonds<T: Config>(consensus_params: &ConsensusParams<T>) ->
         _ => {}
     }
 
+    let validator_stake = |validator: &ModuleParams<T::AccountId>| match averaging {
+        BondsAveraging::Uniform => I64F64::from_num(1),
+        _ => validator.stake_original,
+    };
+
     let total_stake = active_validators.iter().fold(I64F64::from_num(0), |acc, v| {
-        acc.checked_add(v.stake_original).unwrap_or(acc)
+        acc.checked_add(validator_stake(*v)).unwrap_or(acc)
     });
 
     if total_stake == I64F64::from_num(0) {
@@ -443,8 +529,7 @@ fn calculate_average_bonds<T: Config>(consensus_params: &ConsensusParams<T>) ->
         active_validators
             .iter()
             .fold(BTreeMap::new(), |mut acc: BTreeMap<u16, u16>, validator| {
-                let weight = validator
-                    .stake_original
+                let weight = validator_stake(*validator)
                     .checked_div(total_stake)
                     .unwrap_or(I64F64::from_num(0));
 
@@ -0,0 +1,150 @@
+//! Weights for `pallet_offworker`
+//!
+//! Estimated from the storage accessed by each call until `make run-benchmarking` regenerates
+//! this file with the benchmark CLI.
+
+// Regenerate with:
+// ./target/release/node-subspace
+// benchmark
+// pallet
+// --chain
+// specs/local.json
+// --pallet
+// pallet_offworker
+// --extrinsic
+// *
+// --steps
+// 50
+// --repeat
+// 20
+// --output
+// pallets/offworker/src/weights.rs
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_offworker`.
+pub trait WeightInfo {
+	fn bond_decryption_node() -> Weight;
+	fn unbond_decryption_node() -> Weight;
+	fn send_decryption_shares(s: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_offworker` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bond_decryption_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `219`
+		//  Estimated: `3593`
+		// Minimum execution time: 38_512_000 picoseconds.
+		Weight::from_parts(39_604_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubnetEmissionModule::DecryptionCommittee` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionCommittee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Authorities` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::Authorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodes` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBondGrace` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBondGrace` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unbond_decryption_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6012`
+		//  Estimated: `7497`
+		// Minimum execution time: 42_107_000 picoseconds.
+		Weight::from_parts(43_981_000, 7497)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SubnetEmissionModule::DecryptionCommittee` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionCommittee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBanQueue` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBanQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionShares` (r:256 w:256)
+	/// Proof: `SubnetEmissionModule::DecryptionShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	fn send_decryption_shares(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1487`
+		//  Estimated: `4952`
+		// Minimum execution time: 17_204_000 picoseconds.
+		Weight::from_parts(18_116_000, 4952)
+			// Standard Error: 493_650
+			.saturating_add(Weight::from_parts(9_873_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 12935).saturating_mul(s.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn bond_decryption_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `219`
+		//  Estimated: `3593`
+		// Minimum execution time: 38_512_000 picoseconds.
+		Weight::from_parts(39_604_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `SubnetEmissionModule::DecryptionCommittee` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionCommittee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Authorities` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::Authorities` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodes` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBondGrace` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBondGrace` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn unbond_decryption_node() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6012`
+		//  Estimated: `7497`
+		// Minimum execution time: 42_107_000 picoseconds.
+		Weight::from_parts(43_981_000, 7497)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `SubnetEmissionModule::DecryptionCommittee` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionCommittee` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBanQueue` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBanQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionShares` (r:256 w:256)
+	/// Proof: `SubnetEmissionModule::DecryptionShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 256]`.
+	fn send_decryption_shares(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1487`
+		//  Estimated: `4952`
+		// Minimum execution time: 17_204_000 picoseconds.
+		Weight::from_parts(18_116_000, 4952)
+			// Standard Error: 493_650
+			.saturating_add(Weight::from_parts(9_873_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 12935).saturating_mul(s.into()))
+	}
+}
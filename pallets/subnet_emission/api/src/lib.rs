@@ -3,7 +3,9 @@
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::{prelude::vec::Vec, TypeInfo};
 
-use frame_support::dispatch::DispatchResult;
+use frame_support::{
+    dispatch::DispatchResult, sp_runtime::Perbill, traits::ConstU32, BoundedVec,
+};
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
 pub enum SubnetConsensus {
@@ -19,6 +21,75 @@ pub enum SubnetConsensus {
 
 pub type SubnetWeights = Vec<(u16, Vec<(u16, u16)>)>;
 
+/// Maximum amount of steps in a [`EmissionCurve::Piecewise`] curve.
+pub const MAX_EMISSION_CURVE_STEPS: u32 = 32;
+
+/// Describes how the unit emission is scaled as the total issuance grows. All
+/// issuance values are in the smallest token unit and no tokens are emitted
+/// once `max_supply` is reached.
+#[derive(Clone, Debug, PartialEq, Eq, TypeInfo, Decode, Encode, MaxEncodedLen)]
+pub enum EmissionCurve {
+    /// Halves the emission every time `interval` more tokens are issued.
+    StepHalving { interval: u64, max_supply: u64 },
+    /// Halves the emission every `half_life` issued tokens, decaying
+    /// continuously in between.
+    ExponentialDecay { half_life: u64, max_supply: u64 },
+    /// Uses the multiplier of the last step whose issuance threshold was
+    /// reached. Thresholds must start at zero and be strictly increasing.
+    Piecewise {
+        steps: BoundedVec<(u64, Perbill), ConstU32<MAX_EMISSION_CURVE_STEPS>>,
+        max_supply: u64,
+    },
+}
+
+impl EmissionCurve {
+    pub fn max_supply(&self) -> u64 {
+        match self {
+            Self::StepHalving { max_supply, .. }
+            | Self::ExponentialDecay { max_supply, .. }
+            | Self::Piecewise { max_supply, .. } => *max_supply,
+        }
+    }
+
+    /// Checks that the curve is well formed.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.max_supply() == 0 {
+            return Err("max supply must be greater than zero");
+        }
+
+        match self {
+            Self::StepHalving { interval, .. } if *interval == 0 => {
+                Err("halving interval must be greater than zero")
+            }
+            Self::ExponentialDecay { half_life, .. } if *half_life == 0 => {
+                Err("half life must be greater than zero")
+            }
+            Self::Piecewise { steps, max_supply } => {
+                match steps.first() {
+                    Some((0, _)) => {}
+                    Some(_) => return Err("first step must start at zero issuance"),
+                    None => return Err("piecewise curve must have at least one step"),
+                }
+
+                let increasing = steps.windows(2).all(|pair| match pair {
+                    [(a, _), (b, _)] => a < b,
+                    _ => true,
+                });
+                if !increasing {
+                    return Err("step thresholds must be strictly increasing");
+                }
+
+                if steps.iter().any(|(threshold, _)| threshold >= max_supply) {
+                    return Err("step thresholds must be below max supply");
+                }
+
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 pub trait SubnetEmissionApi<AccountId> {
     fn get_lowest_emission_netuid(ignore_subnet_immunity: bool) -> Option<u16>;
 
@@ -46,6 +117,8 @@ pub trait SubnetEmissionApi<AccountId> {
 
     fn clear_subnet_includes(netuid: u16);
 
+    fn set_emission_curve(curve: EmissionCurve) -> DispatchResult;
+
     fn clear_module_includes(
         netuid: u16,
         uid: u16,
@@ -1,9 +1,19 @@
 #![cfg(feature = "runtime-benchmarks")]
 
-use crate::*;
+use crate::{
+    subnet_consensus::{util::params::ConsensusParams, yuma::YumaPhase},
+    types::DECRYPTION_SHARE_LEN,
+    *,
+};
 use frame_benchmarking::{account, benchmarks};
-use frame_system::RawOrigin;
+use frame_support::{
+    traits::{Get, Hooks},
+    weights::Weight,
+    BoundedVec,
+};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 pub use pallet::*;
+use pallet_subnet_emission_api::SubnetConsensus;
 use pallet_subspace::{vec, MinValidatorStake, Pallet as SubspaceMod, SubnetBurn};
 use sp_std::vec::Vec;
 
@@ -39,6 +49,74 @@ fn register_mock<T: Config>(
     Ok(())
 }
 
+fn populate_subnet<T: Config>(modules: u32, consensus: SubnetConsensus) -> Result<u16, &'static str> {
+    pallet_subspace::MaxRegistrationsPerBlock::<T>::set(u16::MAX);
+
+    let mut name = b"module".to_vec();
+    for i in 0..modules {
+        let key: T::AccountId = account("ModuleKey", i, 0);
+        pallet_subspace::RegistrationsPerBlock::<T>::set(0);
+        pallet_subspace::RegistrationsThisInterval::<T>::remove(0);
+
+        name.truncate(6);
+        name.extend(i.to_le_bytes());
+        register_mock::<T>(key, account("ModuleKey", i, 0), name.clone())?;
+
+        if let Some(netuid) = SubspaceMod::<T>::get_netuid_for_name(b"testnet") {
+            pallet_subspace::MaxAllowedUids::<T>::insert(netuid, u16::MAX);
+        }
+    }
+
+    let netuid = SubspaceMod::<T>::get_netuid_for_name(b"testnet").ok_or("subnet not registered")?;
+    let uids: Vec<u16> = (0..modules).map(|uid| uid as u16).collect();
+    for uid in &uids {
+        let weights = uids.iter().filter(|target| *target != uid).map(|target| (*target, 1)).collect();
+        Weights::<T>::insert(netuid, uid, weights);
+        WeightEncryptionData::<T>::insert(
+            netuid,
+            uid,
+            EncryptionMechanism { encrypted: vec![0u8; 256], decrypted_hashes: vec![0u8; 32] },
+        );
+    }
+
+    MinValidatorStake::<T>::set(netuid, 0);
+    SubnetConsensusType::<T>::set(netuid, Some(consensus));
+    PendingEmission::<T>::set(netuid, 1_000_000_000_000);
+
+    Ok(netuid)
+}
+
+fn register_subnets<T: Config>(count: u32) -> Result<Vec<u16>, &'static str> {
+    pallet_subspace::MaxRegistrationsPerBlock::<T>::set(u16::MAX);
+
+    let mut subnets = Vec::new();
+    for i in 0..count {
+        let key: T::AccountId = account("SubnetKey", i, 0);
+        SubspaceMod::<T>::add_balance_to_account(
+            &key,
+            SubspaceMod::<T>::u64_to_balance(SubnetBurn::<T>::get().saturating_mul(2)).unwrap(),
+        );
+        pallet_subspace::SubnetRegistrationsThisInterval::<T>::set(0);
+
+        let mut name = b"subnet".to_vec();
+        name.extend(i.to_le_bytes());
+        SubspaceMod::<T>::register_subnet(RawOrigin::Signed(key).into(), name.clone(), None)?;
+        subnets.push(SubspaceMod::<T>::get_netuid_for_name(&name).ok_or("subnet not registered")?);
+    }
+
+    Ok(subnets)
+}
+
+fn decryption_node_info<T: Config>(node_id: &T::AccountId) -> SubnetDecryptionInfo<T> {
+    SubnetDecryptionInfo {
+        node_id: node_id.clone(),
+        node_public_key: (vec![0; 256], vec![1, 0, 1]),
+        validity_block: None,
+        last_keep_alive: 1,
+        rotating_from: None,
+    }
+}
+
 benchmarks! {
     set_weights {
         let module_key: T::AccountId = account("ModuleKey", 0, 2);
@@ -89,4 +167,184 @@ benchmarks! {
         let _ = Pallet::<T>::delegate_weight_control(RawOrigin::Signed(module_key.clone()).into(), netuid, module_key2.clone());
 
     }: remove_weight_control(RawOrigin::Signed(module_key), netuid)
+
+    on_initialize {
+        let s in 1 .. 128;
+
+        // Every subnet has encrypted weights, and its decryption node is activated on this block
+        let node: T::AccountId = account("DecryptionNode", 0, 0);
+        DecryptionNodes::<T>::set(vec![decryption_node_info::<T>(&node)]);
+        for netuid in register_subnets::<T>(s)? {
+            pallet_subspace::UseWeightsEncryption::<T>::set(netuid, true);
+            SubnetDecryptionData::<T>::set(netuid, Some(decryption_node_info::<T>(&node)));
+            WeightEncryptionData::<T>::insert(
+                netuid,
+                0,
+                EncryptionMechanism { encrypted: vec![0u8; 256], decrypted_hashes: vec![0u8; 32] },
+            );
+        }
+
+        // Picks a block where no epoch is due, epochs are benchmarked separately
+        let block: BlockNumberFor<T> = 1u32.into();
+    }: {
+        Pallet::<T>::on_initialize(block);
+    }
+
+    subnet_pricing {
+        let s in 1 .. 128;
+        let v in 1 .. 256;
+
+        let rootnet = populate_subnet::<T>(v, SubnetConsensus::Root)?;
+        let mut subnets = register_subnets::<T>(s.saturating_sub(1))?;
+        subnets.push(rootnet);
+
+        // Every root validator weighs every subnet
+        let weights: Vec<_> = subnets.iter().map(|netuid| (*netuid, 1)).collect();
+        for uid in 0..v {
+            Weights::<T>::insert(rootnet, uid as u16, weights.clone());
+        }
+    }: {
+        Pallet::<T>::price_subnets(1_000_000_000);
+    }
+    verify {
+        assert!(SubnetEmission::<T>::contains_key(rootnet));
+    }
+
+    ban_decryption_node {
+        let s in 1 .. 128;
+        let m in 1 .. 420;
+
+        // The subnet is handed to another node once the current one is banned
+        register_subnets::<T>(s.saturating_sub(1))?;
+        let netuid = populate_subnet::<T>(m, SubnetConsensus::Yuma)?;
+        pallet_subspace::UseWeightsEncryption::<T>::set(netuid, true);
+
+        let node: T::AccountId = account("DecryptionNode", 0, 0);
+        let other: T::AccountId = account("DecryptionNode", 1, 0);
+        DecryptionNodes::<T>::set(vec![
+            decryption_node_info::<T>(&node),
+            decryption_node_info::<T>(&other),
+        ]);
+        SubnetDecryptionData::<T>::set(netuid, Some(decryption_node_info::<T>(&node)));
+        DecryptionNodeBonds::<T>::insert(
+            &node,
+            DecryptionNodeBond {
+                active: T::MinDecryptionNodeBond::get(),
+                ..Default::default()
+            },
+        );
+
+        // The committee's shares of every encrypted weight are in
+        let block = 0;
+        ConsensusParameters::<T>::insert(
+            netuid,
+            block,
+            ConsensusParams::<T>::new(netuid, 1_000_000_000)?,
+        );
+        DecryptionCommittee::<T>::put(DecryptionCommitteeInfo {
+            public_key: (vec![0; 32], vec![1, 0, 1]),
+            threshold: 1,
+            members: vec![(other.clone(), 1)],
+        });
+        let share = DecryptionShare::truncate_from(vec![1; DECRYPTION_SHARE_LEN as usize]);
+        for uid in 0..m {
+            DecryptionShares::<T>::insert(
+                netuid,
+                (block, uid as u16),
+                BoundedVec::truncate_from(vec![(other.clone(), share.clone())]),
+            );
+        }
+        DecryptionNodeBanQueue::<T>::insert(netuid, &node, 0);
+    }: {
+        Pallet::<T>::process_ban_queue(0);
+    }
+    verify {
+        assert!(BannedDecryptionNodes::<T>::contains_key(&node));
+    }
+
+    release_decryption_node_bond {
+        let node: T::AccountId = account("DecryptionNode", 0, 0);
+        DecryptionNodeBonds::<T>::insert(
+            &node,
+            DecryptionNodeBond {
+                active: 0,
+                unbonding: 1_000_000_000,
+                release_block: Some(0),
+            },
+        );
+    }: {
+        Pallet::<T>::release_decryption_node_bonds(0);
+    }
+    verify {
+        assert!(!DecryptionNodeBonds::<T>::contains_key(&node));
+    }
+
+    run_epoch_yuma {
+        let m in 1 .. 420;
+
+        let netuid = populate_subnet::<T>(m, SubnetConsensus::Yuma)?;
+        pallet_subspace::UseWeightsEncryption::<T>::set(netuid, false);
+    }: {
+        crate::distribute_emission::run_epoch::<T>(netuid);
+    }
+
+    run_epoch_encrypted {
+        let m in 1 .. 420;
+
+        let netuid = populate_subnet::<T>(m, SubnetConsensus::Yuma)?;
+        pallet_subspace::UseWeightsEncryption::<T>::set(netuid, true);
+    }: {
+        crate::distribute_emission::run_epoch::<T>(netuid);
+    }
+
+    run_epoch_linear {
+        let m in 1 .. 420;
+
+        let netuid = populate_subnet::<T>(m, SubnetConsensus::Linear)?;
+    }: {
+        crate::distribute_emission::run_epoch::<T>(netuid);
+    }
+
+    run_epoch_treasury {
+        let netuid = populate_subnet::<T>(1, SubnetConsensus::Treasury)?;
+    }: {
+        crate::distribute_emission::run_epoch::<T>(netuid);
+    }
+
+    run_epoch_yuma_start {
+        let m in 1 .. 4096;
+
+        let netuid = populate_subnet::<T>(m, SubnetConsensus::Yuma)?;
+        pallet_subspace::UseWeightsEncryption::<T>::set(netuid, false);
+        let emission = PendingEmission::<T>::get(netuid);
+    }: {
+        crate::distribute_emission::start_multi_block_epoch::<T>(netuid, emission)?;
+    }
+    verify {
+        assert!(YumaEpochCursor::<T>::contains_key(netuid));
+    }
+
+    run_epoch_yuma_phase {
+        let m in 1 .. 4096;
+
+        let netuid = populate_subnet::<T>(m, SubnetConsensus::Yuma)?;
+        pallet_subspace::UseWeightsEncryption::<T>::set(netuid, false);
+        let emission = PendingEmission::<T>::get(netuid);
+        crate::distribute_emission::start_multi_block_epoch::<T>(netuid, emission)?;
+        // The trust phase clips the whole weight matrix and writes it back, the most expensive
+        // one, chunks of the consensus phase are sized to cost about as much
+        while YumaEpochCursor::<T>::get(netuid).ok_or("epoch was dropped")?.phase
+            != YumaPhase::Trust
+        {
+            crate::distribute_emission::progress_multi_block_epochs::<T>(Weight::MAX);
+        }
+    }: {
+        crate::distribute_emission::progress_multi_block_epochs::<T>(Weight::MAX);
+    }
+    verify {
+        assert_eq!(
+            YumaEpochCursor::<T>::get(netuid).map(|epoch| epoch.phase),
+            Some(YumaPhase::Bonds)
+        );
+    }
 }
@@ -2,6 +2,7 @@ use crate::{
     distribute_emission::update_pending_emission,
     subnet_consensus::{util::params::ConsensusParams, yuma::YumaEpoch},
 };
+use frame_support::weights::Weight;
 use pallet_subspace::{MaxEncryptionPeriod, MaxEncryptionPeriodDefaultValue, UseWeightsEncryption};
 use sp_runtime::traits::Get;
 use sp_std::collections::btree_map::BTreeMap;
@@ -85,7 +86,7 @@ impl<T: Config> Pallet<T> {
                     netuid,
                     Some(SubnetDecryptionInfo {
                         node_id: node_info.node_id.clone(),
-                        node_public_key: node_info.node_public_key.clone(),
+                        node_public_key: Self::subnet_encryption_key(node_info),
                         validity_block: None, /* will be set based on the first encrypted
                                                * weight
                                                * occurrence and restarted on decrypted weights
@@ -102,6 +103,10 @@ impl<T: Config> Pallet<T> {
         }
     }
 
+    /// Applies the decrypted weights of the subnet.
+    ///
+    /// Weights that do not match the hash their validator committed to are dropped. The validator
+    /// supplies both the ciphertext and the hash, so a mismatch can't be blamed on the node.
     pub fn handle_decrypted_weights(netuid: u16, weights: Vec<BlockWeights>) {
         log::info!(
             "Received decrypted weights: {:?}, for subnet {netuid}",
@@ -354,7 +359,9 @@ impl<T: Config> Pallet<T> {
 
     /// Adds a new active authority node to the list of active authority nodes.
     /// If the node is already in the list, it will be updated with a new time.
-    pub fn handle_authority_node_ping(account_id: T::AccountId) {
+    ///
+    /// A ping announcing a key other than the authority's current one rotates the node's key.
+    pub fn handle_authority_node_ping(account_id: T::AccountId, announced_key: PublicKey) {
         log::info!(
             "Starting authority node ping handling for account: {:?}",
             account_id
@@ -372,8 +379,26 @@ impl<T: Config> Pallet<T> {
             return;
         };
 
-        // Update active nodes list
+        let rotated = !announced_key.0.is_empty() && announced_key != public_key;
+        let public_key = if rotated {
+            Self::rotate_decryption_key(&account_id, announced_key)
+        } else {
+            public_key
+        };
+
+        // Update active nodes list, only bonded nodes get new subnets assigned. Subnets already
+        // assigned to the node keep being served until they are rotated away.
+        let bonded = Self::can_serve_subnets(&account_id);
         DecryptionNodes::<T>::mutate(|nodes| {
+            if !bonded {
+                log::info!(
+                    "Authority {:?} is not bonded, not an active node",
+                    account_id
+                );
+                nodes.retain(|node| node.node_id != account_id);
+                return;
+            }
+
             match nodes.iter_mut().find(|node| node.node_id == account_id) {
                 Some(node) => {
                     log::info!(
@@ -382,12 +407,13 @@ impl<T: Config> Pallet<T> {
                         current_block
                     );
                     node.last_keep_alive = current_block;
+                    node.node_public_key = public_key.clone();
                 }
                 None => {
                     log::info!("Adding new authority node to active nodes list");
                     nodes.push(SubnetDecryptionInfo {
                         node_id: account_id.clone(),
-                        node_public_key: public_key,
+                        node_public_key: public_key.clone(),
                         last_keep_alive: current_block,
                         validity_block: None,
                         rotating_from: None,
@@ -405,12 +431,86 @@ impl<T: Config> Pallet<T> {
                     netuid
                 );
                 info.last_keep_alive = current_block;
+                if rotated {
+                    // the committee key, if there is one, stays in place
+                    info.node_public_key = public_key.clone();
+                    info.node_public_key = Self::subnet_encryption_key(&info);
+                }
                 SubnetDecryptionData::<T>::insert(netuid, info);
             });
 
         log::info!("Authority node ping handling completed successfully");
     }
 
+    /// Replaces the authority key of the node with the one it announced, returning it.
+    fn rotate_decryption_key(account_id: &T::AccountId, public_key: PublicKey) -> PublicKey {
+        log::info!("Decryption node {:?} rotated its key", account_id);
+
+        let mut previous_key = None;
+        Authorities::<T>::mutate(|authorities| {
+            authorities.iter_mut().filter(|(auth_id, _)| auth_id == account_id).for_each(
+                |(_, key)| previous_key = Some(core::mem::replace(key, public_key.clone())),
+            );
+        });
+
+        // weights encrypted so far were encrypted to the key being replaced
+        let pending: Vec<u16> = SubnetDecryptionData::<T>::iter()
+            .filter(|(_, info)| {
+                &info.node_id == account_id || info.rotating_from.as_ref() == Some(account_id)
+            })
+            .filter(|(netuid, _)| {
+                ConsensusParameters::<T>::iter_prefix_values(netuid).any(|params| {
+                    params.modules.values().any(|module| !module.weight_encrypted.is_empty())
+                })
+            })
+            .map(|(netuid, _)| netuid)
+            .collect();
+        if let Some(previous_key) = previous_key.filter(|_| !pending.is_empty()) {
+            DecryptionKeyRotations::<T>::mutate(account_id, |keys| {
+                keys.get_or_insert_with(Vec::new).push((previous_key, pending));
+            });
+        }
+
+        Self::deposit_event(Event::<T>::DecryptionKeyRotated {
+            node_id: account_id.clone(),
+            public_key: public_key.clone(),
+        });
+
+        public_key
+    }
+
+    /// The keys the node rotated away from that weights waiting to be decrypted by it are still
+    /// encrypted to. The node has to keep each of them until it is released.
+    pub fn retained_decryption_keys(node_id: &T::AccountId) -> Vec<PublicKey> {
+        DecryptionKeyRotations::<T>::get(node_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    /// Releases the subnet from the previous keys of the nodes serving it, its weights encrypted
+    /// before their rotation are gone.
+    fn release_previous_decryption_keys(subnet_id: u16) {
+        let Some(info) = SubnetDecryptionData::<T>::get(subnet_id) else {
+            return;
+        };
+
+        for node_id in core::iter::once(&info.node_id).chain(info.rotating_from.as_ref()) {
+            DecryptionKeyRotations::<T>::mutate_exists(node_id, |keys| {
+                if let Some(retained) = keys {
+                    for (_, netuids) in retained.iter_mut() {
+                        netuids.retain(|netuid| *netuid != subnet_id);
+                    }
+                    retained.retain(|(_, netuids)| !netuids.is_empty());
+                    if retained.is_empty() {
+                        *keys = None;
+                    }
+                }
+            });
+        }
+    }
+
     /// Returns a tuple of subnet UIDs (with_encryption, without_encryption) where:
     /// - First vector contains subnets that use weight encryption and have matching keys (if acc_id
     ///   is Some)
@@ -479,32 +579,73 @@ impl<T: Config> Pallet<T> {
         );
     }
 
-    pub(crate) fn process_ban_queue(block_number: u64) {
-        DecryptionNodeBanQueue::<T>::iter()
-            .filter(|(_, _, ban_block)| *ban_block <= block_number)
-            .for_each(|(subnet_id, node_id, _ban_block)| {
-                // Get the node info before removing from queue
-                if let Some(info) = SubnetDecryptionData::<T>::get(subnet_id) {
-                    // Remove from ban queue first
-                    DecryptionNodeBanQueue::<T>::remove(subnet_id, &node_id);
-
-                    // Cancel and then ban
-                    Self::cancel_offchain_worker(subnet_id, &info);
-                    Self::ban_offchain_worker(&node_id);
-
-                    Self::deposit_event(Event::<T>::DecryptionNodeBanned {
-                        subnet_id,
-                        node_id: node_id.clone(),
-                    });
+    /// Bans the decryption nodes whose ban block was reached.
+    ///
+    /// # Returns
+    ///
+    /// The weight consumed, billing every due ban as a ban of a node on the largest subnet.
+    pub(crate) fn process_ban_queue(block_number: u64) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let max_subnets = u32::from(pallet_subspace::MaxAllowedSubnets::<T>::get());
+        let mut weight = db_weight.reads(1);
+
+        for (subnet_id, node_id, ban_block) in DecryptionNodeBanQueue::<T>::iter() {
+            weight = weight.saturating_add(db_weight.reads(1));
+            if ban_block > block_number {
+                continue;
+            }
 
-                    log::info!(
-                        "Offchain worker banned at block {}: subnet {}, node {:?}",
-                        block_number,
-                        subnet_id,
-                        node_id
-                    );
-                }
-            });
+            let modules = u32::from(pallet_subspace::N::<T>::get(subnet_id));
+            weight = weight.saturating_add(<T as Config>::WeightInfo::ban_decryption_node(
+                max_subnets,
+                modules,
+            ));
+
+            // The node can't send the weights before the committee's shares are in, it gets
+            // the whole buffer again once they are
+            if !Self::has_decryption_quorum(subnet_id) {
+                let ban_block = block_number.saturating_add(T::EncryptionPeriodBuffer::get());
+                DecryptionNodeBanQueue::<T>::insert(subnet_id, &node_id, ban_block);
+
+                log::info!(
+                    "Ban of node {:?} on subnet {} postponed to block {}, waiting for the \
+                     decryption shares of the committee",
+                    node_id,
+                    subnet_id,
+                    ban_block
+                );
+                continue;
+            }
+
+            // Get the node info before removing from queue
+            if let Some(info) = SubnetDecryptionData::<T>::get(subnet_id) {
+                // Remove from ban queue first
+                DecryptionNodeBanQueue::<T>::remove(subnet_id, &node_id);
+
+                // Cancel and then ban
+                Self::cancel_offchain_worker(subnet_id, &info);
+                Self::ban_offchain_worker(&node_id);
+                Self::slash_decryption_node(
+                    &node_id,
+                    subnet_id,
+                    DecryptionNodeFaultKind::MissedDecryption,
+                );
+
+                Self::deposit_event(Event::<T>::DecryptionNodeBanned {
+                    subnet_id,
+                    node_id: node_id.clone(),
+                });
+
+                log::info!(
+                    "Offchain worker banned at block {}: subnet {}, node {:?}",
+                    block_number,
+                    subnet_id,
+                    node_id
+                );
+            }
+        }
+
+        weight
     }
 
     pub fn cancel_expired_offchain_workers(block_number: u64) {
@@ -540,8 +681,11 @@ impl<T: Config> Pallet<T> {
     /// Returns the total emission amount that was processed.
     fn cleanup_subnet_wc_state(subnet_id: u16, clear_node_assing: bool) {
         // Clear ConsensusParameters
+        Self::release_previous_decryption_keys(subnet_id);
         let _ = ConsensusParameters::<T>::clear_prefix(subnet_id, u32::MAX, None);
 
+        let _ = DecryptionShares::<T>::clear_prefix(subnet_id, u32::MAX, None);
+
         // --- Cleanup The Conditionals ---
         if clear_node_assing {
             SubnetDecryptionData::<T>::remove(subnet_id);
@@ -654,7 +798,7 @@ impl<T: Config> Pallet<T> {
                 subnet_id,
                 Some(SubnetDecryptionInfo {
                     node_id: new_node.node_id.clone(),
-                    node_public_key: new_node.node_public_key,
+                    node_public_key: Self::subnet_encryption_key(&new_node),
                     validity_block: None, /* This will get updated based on the first encrypted
                                            * weights */
                     rotating_from: Some(previous_node_id.clone()),
@@ -693,4 +837,87 @@ impl<T: Config> Pallet<T> {
         // Check if the input subnet_id is in the collected rotated subnets
         rotated_subnets.contains(&subnet_id)
     }
+
+    /// Key validators encrypt the subnet's weights to: the committee key if there is a
+    /// committee, or the key of the assigned node.
+    fn subnet_encryption_key(node: &SubnetDecryptionInfo<T>) -> PublicKey {
+        DecryptionCommittee::<T>::get()
+            .map(|committee| committee.public_key)
+            .unwrap_or_else(|| node.node_public_key.clone())
+    }
+
+    /// Whether committee members should release their decryption shares of the subnet's weights.
+    /// That is once the assigned node was called to send the weights back, so shares never reveal
+    /// weights before their encryption period is over.
+    pub fn is_decryption_due(subnet_id: u16) -> bool {
+        DecryptionNodeBanQueue::<T>::iter_prefix(subnet_id).next().is_some()
+    }
+
+    pub fn handle_decryption_shares(
+        subnet_id: u16,
+        node_id: T::AccountId,
+        shares: DecryptionShareSet,
+    ) {
+        for (block, uid, share) in shares {
+            DecryptionShares::<T>::mutate(subnet_id, (block, uid), |submitted| {
+                if !submitted.iter().any(|(member, _)| *member == node_id) {
+                    // members are authorities, so the set can only be full of duplicates
+                    let _ = submitted.try_push((node_id.clone(), share));
+                }
+            });
+        }
+
+        Self::deposit_event(Event::<T>::DecryptionSharesReceived { subnet_id, node_id });
+    }
+
+    /// Whether enough committee members submitted decryption shares to decrypt each of the
+    /// subnet's encrypted weights. Always true without a committee.
+    pub fn has_decryption_quorum(subnet_id: u16) -> bool {
+        let Some(committee) = DecryptionCommittee::<T>::get() else {
+            return true;
+        };
+
+        ConsensusParameters::<T>::iter_prefix(subnet_id).all(|(block, params)| {
+            params
+                .modules
+                .values()
+                .filter(|module| !module.weight_encrypted.is_empty())
+                .all(|module| {
+                    let submitted = DecryptionShares::<T>::get(subnet_id, (block, module.uid))
+                        .iter()
+                        .filter(|(node_id, _)| {
+                            committee.members.iter().any(|(member, _)| member == node_id)
+                        })
+                        .count();
+
+                    submitted >= usize::from(committee.threshold)
+                })
+        })
+    }
+
+    /// The decryption shares submitted for a module's encrypted weights, with the key the
+    /// member announced as an authority to verify them against.
+    pub fn decryption_shares_for(
+        subnet_id: u16,
+        block: u64,
+        uid: u16,
+    ) -> Vec<(PublicKey, Vec<u8>)> {
+        let Some(committee) = DecryptionCommittee::<T>::get() else {
+            return Vec::new();
+        };
+        let authorities = Authorities::<T>::get();
+        let submitted = DecryptionShares::<T>::get(subnet_id, (block, uid));
+
+        committee
+            .members
+            .into_iter()
+            .filter_map(|(member, _)| {
+                let (_, member_key) = authorities.iter().find(|(account, _)| *account == member)?;
+                submitted
+                    .iter()
+                    .find(|(node_id, _)| *node_id == member)
+                    .map(|(_, share)| (member_key.clone(), share.to_vec()))
+            })
+            .collect()
+    }
 }
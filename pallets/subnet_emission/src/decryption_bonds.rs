@@ -0,0 +1,202 @@
+use super::*;
+use frame_support::{dispatch::DispatchResult, traits::Get, weights::Weight};
+use pallet_subspace::Pallet as PalletSubspace;
+
+/// Number of faults kept per node in `DecryptionNodeFaults`.
+const MAX_RECORDED_FAULTS: usize = 32;
+
+impl<T: Config> Pallet<T> {
+    /// Whether the node's active bond covers `MinDecryptionNodeBond`.
+    pub fn is_decryption_node_bonded(node_id: &T::AccountId) -> bool {
+        DecryptionNodeBonds::<T>::get(node_id).active >= T::MinDecryptionNodeBond::get()
+    }
+
+    /// Whether the node can be assigned subnets: it is bonded, or it is an authority from before
+    /// bonds still within `DecryptionNodeBondGracePeriod`.
+    pub fn can_serve_subnets(node_id: &T::AccountId) -> bool {
+        Self::is_decryption_node_bonded(node_id)
+            || DecryptionNodeBondGrace::<T>::get(node_id).is_some_and(|grace_end| {
+                PalletSubspace::<T>::get_current_block_number() < grace_end
+            })
+    }
+
+    /// Moves `amount` from the operator's balance into the node's active bond.
+    pub fn bond_decryption_node(node_id: &T::AccountId, amount: u64) -> DispatchResult {
+        let balance = PalletSubspace::<T>::u64_to_balance(amount)
+            .ok_or(pallet_subspace::Error::<T>::CouldNotConvertToBalance)?;
+        PalletSubspace::<T>::remove_balance_from_account(node_id, balance)?;
+
+        DecryptionNodeBonds::<T>::mutate(node_id, |bond| {
+            bond.active = bond.active.saturating_add(amount);
+        });
+
+        Self::deposit_event(Event::<T>::DecryptionNodeBonded {
+            node_id: node_id.clone(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Removes the node from the authorities and starts unbonding its active bond. Returns false
+    /// if the node is neither an authority nor has an active bond.
+    ///
+    /// Subnets still assigned to the node stay with it until they are rotated away, and the
+    /// unbonding amount can be slashed for faults on them until it is released.
+    pub fn unbond_decryption_node(node_id: &T::AccountId) -> bool {
+        let was_authority = Authorities::<T>::mutate(|authorities| {
+            let len = authorities.len();
+            authorities.retain(|(account, _)| account != node_id);
+            authorities.len() != len
+        });
+        DecryptionNodes::<T>::mutate(|nodes| nodes.retain(|node| &node.node_id != node_id));
+        DecryptionNodeBondGrace::<T>::remove(node_id);
+
+        let release_block = PalletSubspace::<T>::get_current_block_number()
+            .saturating_add(T::DecryptionNodeUnbondingPeriod::get());
+        let amount = DecryptionNodeBonds::<T>::mutate_exists(node_id, |bond| {
+            let bond = bond.as_mut()?;
+            if bond.active == 0 {
+                return None;
+            }
+
+            let amount = core::mem::take(&mut bond.active);
+            bond.unbonding = bond.unbonding.saturating_add(amount);
+            bond.release_block = Some(release_block);
+            Some(amount)
+        });
+
+        if let Some(amount) = amount {
+            Self::deposit_event(Event::<T>::DecryptionNodeUnbonding {
+                node_id: node_id.clone(),
+                amount,
+                release_block,
+            });
+        }
+
+        was_authority || amount.is_some()
+    }
+
+    /// Returns the bonds whose unbonding period is over to their operators.
+    ///
+    /// # Returns
+    ///
+    /// The weight consumed.
+    pub(crate) fn release_decryption_node_bonds(block_number: u64) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut weight = db_weight.reads(1);
+
+        let mut released = Vec::new();
+        for (node_id, bond) in DecryptionNodeBonds::<T>::iter() {
+            weight = weight.saturating_add(db_weight.reads(1));
+            if bond.release_block.is_some_and(|release| release <= block_number) {
+                released.push((node_id, bond));
+            }
+        }
+
+        for (node_id, mut bond) in released {
+            weight =
+                weight.saturating_add(<T as Config>::WeightInfo::release_decryption_node_bond());
+
+            let amount = core::mem::take(&mut bond.unbonding);
+            bond.release_block = None;
+
+            if let Some(balance) = PalletSubspace::<T>::u64_to_balance(amount) {
+                PalletSubspace::<T>::add_balance_to_account(&node_id, balance);
+            }
+
+            if bond.active == 0 {
+                DecryptionNodeBonds::<T>::remove(&node_id);
+            } else {
+                DecryptionNodeBonds::<T>::insert(&node_id, bond);
+            }
+
+            Self::deposit_event(Event::<T>::DecryptionNodeBondReleased { node_id, amount });
+        }
+
+        weight
+    }
+
+    /// Slashes `DecryptionNodeSlash` of the node's active and unbonding bond to the DAO treasury
+    /// and records the fault. A node left below the minimum bond is taken out of rotation until
+    /// its operator tops the bond up.
+    pub fn slash_decryption_node(
+        node_id: &T::AccountId,
+        subnet_id: u16,
+        kind: DecryptionNodeFaultKind,
+    ) {
+        let slash = T::DecryptionNodeSlash::get();
+        let amount = DecryptionNodeBonds::<T>::mutate(node_id, |bond| {
+            let from_active = slash.mul_floor(bond.active);
+            let from_unbonding = slash.mul_floor(bond.unbonding);
+            bond.active = bond.active.saturating_sub(from_active);
+            bond.unbonding = bond.unbonding.saturating_sub(from_unbonding);
+
+            from_active.saturating_add(from_unbonding)
+        });
+
+        if let Some(balance) = PalletSubspace::<T>::u64_to_balance(amount) {
+            PalletSubspace::<T>::add_balance_to_account(&T::get_dao_treasury_address(), balance);
+        }
+
+        DecryptionNodeFaults::<T>::mutate(node_id, |faults| {
+            faults.push(DecryptionNodeFault {
+                subnet_id,
+                block: PalletSubspace::<T>::get_current_block_number(),
+                kind,
+                slashed: amount,
+            });
+            let excess = faults.len().saturating_sub(MAX_RECORDED_FAULTS);
+            faults.drain(..excess);
+        });
+
+        // a fault ends the grace period of an unbonded authority
+        DecryptionNodeBondGrace::<T>::remove(node_id);
+        if !Self::is_decryption_node_bonded(node_id) {
+            DecryptionNodes::<T>::mutate(|nodes| nodes.retain(|node| &node.node_id != node_id));
+        }
+
+        log::warn!(
+            "decryption node {node_id:?} slashed {amount} for {kind:?} on subnet {subnet_id}"
+        );
+
+        Self::deposit_event(Event::<T>::DecryptionNodeSlashed {
+            node_id: node_id.clone(),
+            subnet_id,
+            kind,
+            amount,
+        });
+    }
+
+    /// Bond and fault history of every authority and of every node with a bond or faults left.
+    pub fn get_decryption_node_bonds() -> Vec<(
+        T::AccountId,
+        bool,
+        DecryptionNodeBond,
+        Vec<DecryptionNodeFault>,
+    )> {
+        let authorities: Vec<T::AccountId> =
+            Authorities::<T>::get().into_iter().map(|(account, _)| account).collect();
+
+        let mut nodes = authorities.clone();
+        for node_id in
+            DecryptionNodeBonds::<T>::iter_keys().chain(DecryptionNodeFaults::<T>::iter_keys())
+        {
+            if !nodes.contains(&node_id) {
+                nodes.push(node_id);
+            }
+        }
+
+        nodes
+            .into_iter()
+            .map(|node_id| {
+                (
+                    node_id.clone(),
+                    authorities.contains(&node_id),
+                    DecryptionNodeBonds::<T>::get(&node_id),
+                    DecryptionNodeFaults::<T>::get(&node_id),
+                )
+            })
+            .collect()
+    }
+}
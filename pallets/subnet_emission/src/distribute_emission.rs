@@ -1,11 +1,11 @@
 use super::*;
 use crate::subnet_consensus::{linear::LinearEpoch, treasury::TreasuryEpoch};
 
-use crate::subnet_consensus::util::params::ConsensusParams;
-use frame_support::storage::with_storage_layer;
+use crate::subnet_consensus::util::params::{ConsensusParams, ModuleKey};
+use frame_support::{storage::with_storage_layer, traits::Get, weights::Weight};
 use pallet_subnet_emission_api::SubnetConsensus;
 use pallet_subspace::{Pallet as PalletSubspace, N};
-use subnet_consensus::yuma::YumaEpoch;
+use subnet_consensus::yuma::{YumaEpoch, YumaEpochState, YumaPhase};
 
 const NO_WEIGHTS: &str = "no weights";
 
@@ -16,20 +16,89 @@ const NO_WEIGHTS: &str = "no weights";
 /// * `block_number` - The current block number.
 /// * `subnets_emission_distribution` - A map of subnet IDs to their emission values.
 ///
-/// This function iterates through all subnets, updates their pending emissions,
-/// and runs an epoch if it's time for that subnet.
-fn process_subnets<T: Config>(block_number: u64, subnets_emission_distribution: PricedSubnets) {
+/// # Returns
+///
+/// The weight consumed by the epochs that were run.
+///
+/// This function first drains epochs deferred by previous blocks, then iterates through all
+/// subnets, updates their pending emissions, and runs an epoch if it's time for that subnet.
+/// Epochs that would exceed `MaxEpochWeightPerBlock` are pushed to `DeferredEpochs`.
+fn process_subnets<T: Config>(
+    block_number: u64,
+    subnets_emission_distribution: PricedSubnets,
+) -> Weight {
+    let budget = T::MaxEpochWeightPerBlock::get();
+    // Epochs already split across blocks advance by one phase every block
+    let mut consumed = progress_multi_block_epochs::<T>(budget);
+
+    let deferred_before = DeferredEpochs::<T>::decode_len().unwrap_or(0);
+    // At least one deferred epoch is run every block, so the queue can't starve
+    consumed = consumed.saturating_add(Pallet::<T>::run_deferred_epochs(
+        budget.saturating_sub(consumed),
+        true,
+    ));
+
+    let mut deferred = DeferredEpochs::<T>::get();
+    let mut ran_any = deferred.len() < deferred_before;
+
     for netuid in N::<T>::iter_keys() {
         update_pending_emission::<T>(
             netuid,
             subnets_emission_distribution.get(&netuid).unwrap_or(&0),
         );
 
-        if pallet_subspace::Pallet::<T>::blocks_until_next_epoch(netuid, block_number) == 0 {
-            run_epoch::<T>(netuid);
+        if pallet_subspace::Pallet::<T>::blocks_until_next_epoch(netuid, block_number) != 0 {
+            continue;
+        }
+
+        // The pending emission keeps accumulating, until the deferred epoch runs
+        if deferred.contains(&netuid) || YumaEpochCursor::<T>::contains_key(netuid) {
+            continue;
         }
+
+        let epoch_weight = estimate_epoch_weight::<T>(netuid);
+        // A full queue can't happen while netuids are unique, run the epoch right away if it does
+        if ran_any
+            && consumed.saturating_add(epoch_weight).any_gt(budget)
+            && deferred.try_push(netuid).is_ok()
+        {
+            log::info!("deferring epoch of subnet {netuid}, epoch weight budget exceeded");
+            Pallet::<T>::deposit_event(Event::<T>::EpochDeferred(netuid));
+            continue;
+        }
+
+        run_epoch::<T>(netuid);
+        consumed = consumed.saturating_add(epoch_weight);
+        ran_any = true;
     }
+
+    DeferredEpochs::<T>::set(deferred);
+    consumed
 }
+
+/// Estimates the weight of running an epoch for a given subnet, based on its consensus type
+/// and module count.
+pub(crate) fn estimate_epoch_weight<T: Config>(netuid: u16) -> Weight {
+    let modules = u32::from(N::<T>::get(netuid));
+
+    match SubnetConsensusType::<T>::get(netuid) {
+        None | Some(SubnetConsensus::Root) => T::DbWeight::get().reads(1),
+        Some(SubnetConsensus::Treasury) => <T as Config>::WeightInfo::run_epoch_treasury(),
+        Some(SubnetConsensus::Linear) => <T as Config>::WeightInfo::run_epoch_linear(modules),
+        Some(SubnetConsensus::Yuma) => {
+            if pallet_subspace::UseWeightsEncryption::<T>::get(netuid) {
+                // Falls back to the plain epoch when no decryption node is active
+                <T as Config>::WeightInfo::run_epoch_encrypted(modules)
+                    .max(<T as Config>::WeightInfo::run_epoch_yuma(modules))
+            } else if should_split_epoch::<T>(netuid) {
+                <T as Config>::WeightInfo::run_epoch_yuma_start(modules)
+            } else {
+                <T as Config>::WeightInfo::run_epoch_yuma(modules)
+            }
+        }
+    }
+}
+
 /// Updates the pending emission for a given subnet.
 ///
 /// # Arguments
@@ -56,26 +125,41 @@ pub(crate) fn update_pending_emission<T: Config>(netuid: u16, new_queued_emissio
 /// This function clears the set weight rate limiter, retrieves the pending emission,
 /// and if there's emission to distribute, runs the consensus algorithm. If successful,
 /// it finalizes the epoch. If an error occurs during consensus, it logs the error
-fn run_epoch<T: Config>(netuid: u16) {
+pub(crate) fn run_epoch<T: Config>(netuid: u16) {
     log::trace!("running epoch for subnet {netuid}");
 
     let emission_to_drain = PendingEmission::<T>::get(netuid);
-    if emission_to_drain > 0 {
-        match run_consensus_algorithm::<T>(netuid, emission_to_drain) {
-            Ok(_) => {
-                finalize_epoch::<T>(netuid, true); // clear emission
-            }
-            Err(NO_WEIGHTS) => {
-                log::warn!("no weights set on subnet {netuid}");
-                finalize_epoch::<T>(netuid, false); // do not clear emission, just deposit event
-            }
-            Err(e) => {
-                log::error!(
-                    "Error running consensus algorithm for subnet {}: {:?}",
-                    netuid,
-                    e
-                );
-            }
+    if emission_to_drain > 0 && should_split_epoch::<T>(netuid) {
+        match start_multi_block_epoch::<T>(netuid, emission_to_drain) {
+            Ok(()) => log::info!("started multi block epoch for subnet {netuid}"),
+            Err(e) => settle_epoch::<T>(netuid, Err(e)),
+        }
+    } else if emission_to_drain > 0 {
+        settle_epoch::<T>(
+            netuid,
+            run_consensus_algorithm::<T>(netuid, emission_to_drain),
+        );
+    }
+}
+
+/// Finalizes the epoch of a subnet according to the result of its consensus, whether it ran in
+/// a single block or across several.
+fn settle_epoch<T: Config>(netuid: u16, result: Result<(), &'static str>) {
+    match result {
+        Ok(()) => {
+            finalize_epoch::<T>(netuid, true); // clear emission
+        }
+        Err(NO_WEIGHTS) => {
+            log::warn!("no weights set on subnet {netuid}");
+            // do not clear emission, it carries over to the next epoch
+            Pallet::<T>::deposit_event(Event::<T>::EpochSkipped(netuid));
+        }
+        Err(e) => {
+            log::error!(
+                "Error running consensus algorithm for subnet {}: {:?}",
+                netuid,
+                e
+            );
         }
     }
 }
@@ -249,6 +333,164 @@ fn run_treasury_consensus<T: Config>(
 
 // Runs the treasury consensus algorithm for subnet 1.
 
+// ---------------------------------
+// Multi block epochs
+// ---------------------------------
+
+/// Whether the epoch of a given subnet is too large to be computed in a single block.
+///
+/// Only unencrypted Yuma subnets are split, encrypted ones just snapshot their parameters.
+fn should_split_epoch<T: Config>(netuid: u16) -> bool {
+    matches!(
+        SubnetConsensusType::<T>::get(netuid),
+        Some(SubnetConsensus::Yuma)
+    ) && !pallet_subspace::UseWeightsEncryption::<T>::get(netuid)
+        && N::<T>::get(netuid) > T::MultiBlockEpochThreshold::get()
+}
+
+/// Snapshots the consensus parameters and weights of the subnet, runs the weights phase and
+/// persists the epoch state. The epoch drains the pending emission of the subnet right away, the
+/// same as a single block epoch would.
+///
+/// The remaining phases run on the following blocks, see [`progress_multi_block_epochs`].
+pub(crate) fn start_multi_block_epoch<T: Config>(
+    netuid: u16,
+    emission_to_drain: u64,
+) -> Result<(), &'static str> {
+    with_storage_layer(|| {
+        let params = ConsensusParams::<T>::new(netuid, emission_to_drain)?;
+        let weights: Vec<(u16, Vec<(u16, u16)>)> = Weights::<T>::iter_prefix(netuid).collect();
+
+        let state = YumaEpoch::new(netuid, params).start(weights).map_err(|err| {
+            log::error!("could not start yuma consensus for {netuid}: {err:?}");
+            "could not start yuma consensus"
+        })?;
+
+        YumaEpochInputs::<T>::insert(netuid, state.inputs);
+        YumaEpochWeights::<T>::insert(netuid, state.weights);
+        YumaEpochCursor::<T>::insert(
+            netuid,
+            MultiBlockEpoch {
+                emission_to_drain,
+                phase: state.phase,
+                outputs: state.outputs,
+            },
+        );
+        PendingEmission::<T>::set(netuid, 0);
+        Ok(())
+    })
+}
+
+/// Runs the next phase of the multi block epochs, as many as fit in the `budget` but at least
+/// one, so no epoch is stalled. Epochs that finish their last phase are applied and finalized.
+///
+/// The output is only applied if the subnet modules are still the ones the epoch was computed
+/// for. Otherwise the epoch is dropped, and its emission is returned for the next one.
+///
+/// # Returns
+///
+/// The weight consumed by the phases that were run.
+pub(crate) fn progress_multi_block_epochs<T: Config>(budget: Weight) -> Weight {
+    let mut consumed = T::DbWeight::get().reads(1);
+    let mut ran_any = false;
+
+    for (netuid, epoch) in YumaEpochCursor::<T>::iter().collect::<Vec<_>>() {
+        let modules = N::<T>::get(netuid);
+        let phase_weight = <T as Config>::WeightInfo::run_epoch_yuma_phase(modules.into())
+            .saturating_add(T::DbWeight::get().reads(1));
+        if ran_any && consumed.saturating_add(phase_weight).any_gt(budget) {
+            continue;
+        }
+        consumed = consumed.saturating_add(phase_weight);
+        ran_any = true;
+
+        let emission_to_drain = epoch.emission_to_drain;
+        let res = with_storage_layer(|| run_multi_block_phase::<T>(netuid, epoch, modules));
+
+        if let Err(err) = res {
+            log::error!("dropping multi block epoch for subnet {netuid}: {err}");
+            clear_multi_block_epoch::<T>(netuid);
+            update_pending_emission::<T>(netuid, &emission_to_drain);
+        }
+    }
+
+    consumed
+}
+
+/// Runs the next phase of the multi block epoch of a subnet, writing back only what the phase
+/// computed.
+fn run_multi_block_phase<T: Config>(
+    netuid: u16,
+    epoch: MultiBlockEpoch,
+    modules: u16,
+) -> Result<(), &'static str> {
+    let mut state = YumaEpochState {
+        inputs: YumaEpochInputs::<T>::get(netuid).ok_or("multi block epoch inputs are missing")?,
+        weights: YumaEpochWeights::<T>::get(netuid).unwrap_or_default(),
+        phase: epoch.phase,
+        outputs: epoch.outputs,
+    };
+
+    let phase = state.phase;
+    let output = state.step(consensus_columns_per_step::<T>(modules)).map_err(|err| {
+        log::error!("could not run yuma phase for {netuid}: {err:?}");
+        "could not run yuma phase"
+    })?;
+
+    match phase {
+        YumaPhase::Trust => YumaEpochWeights::<T>::insert(netuid, &state.weights),
+        YumaPhase::Bonds => YumaEpochWeights::<T>::remove(netuid),
+        YumaPhase::Consensus { .. } | YumaPhase::Apply => {}
+    }
+
+    let Some(output) = output else {
+        YumaEpochCursor::<T>::insert(
+            netuid,
+            MultiBlockEpoch {
+                emission_to_drain: epoch.emission_to_drain,
+                phase: state.phase,
+                outputs: state.outputs,
+            },
+        );
+        return Ok(());
+    };
+
+    clear_multi_block_epoch::<T>(netuid);
+    if !modules_unchanged::<T>(netuid, &output.modules.keys) {
+        return Err("subnet modules changed during the epoch");
+    }
+
+    output.apply();
+    // the emission was drained when the epoch started
+    finalize_epoch::<T>(netuid, false);
+    Ok(())
+}
+
+/// Columns of the consensus phase computed per block, so a chunk of it costs about as much as
+/// the consensus of the largest epoch still run in a single block.
+fn consensus_columns_per_step<T: Config>(modules: u16) -> u16 {
+    let threshold = u32::from(T::MultiBlockEpochThreshold::get());
+    let columns = threshold
+        .saturating_mul(threshold)
+        .checked_div(u32::from(modules))
+        .unwrap_or(u32::MAX);
+
+    u16::try_from(columns).unwrap_or(u16::MAX).max(1)
+}
+
+fn clear_multi_block_epoch<T: Config>(netuid: u16) {
+    YumaEpochCursor::<T>::remove(netuid);
+    YumaEpochInputs::<T>::remove(netuid);
+    YumaEpochWeights::<T>::remove(netuid);
+}
+
+fn modules_unchanged<T: Config>(netuid: u16, keys: &[ModuleKey<T::AccountId>]) -> bool {
+    usize::from(N::<T>::get(netuid)) == keys.len()
+        && keys.iter().enumerate().all(|(uid, key)| {
+            pallet_subspace::Keys::<T>::get(netuid, uid as u16).as_ref() == Some(&key.0)
+        })
+}
+
 // ---------------------------------
 // Epoch utils
 // ---------------------------------
@@ -277,13 +519,75 @@ impl<T: Config> Pallet<T> {
     /// * `block_number` - The current block number.
     /// * `emission_per_block` - The total emission to be distributed per block.
     ///
+    /// # Returns
+    ///
+    /// The weight consumed by the subnet pricing and the subnet epochs run in this block.
+    ///
     /// This function calculates the emission distribution across subnets and
     /// processes each subnet accordingly.
-    pub fn process_emission_distribution(block_number: u64, emission_per_block: u64) {
+    pub fn process_emission_distribution(block_number: u64, emission_per_block: u64) -> Weight {
         log::debug!("stepping block {block_number:?}");
 
-        let subnets_emission_distribution = Self::get_subnet_pricing(emission_per_block);
-        process_subnets::<T>(block_number, subnets_emission_distribution);
+        let (subnets_emission_distribution, root_validators) =
+            Self::price_subnets(emission_per_block);
+        let pricing_weight = <T as Config>::WeightInfo::subnet_pricing(
+            subnets_emission_distribution.len() as u32,
+            root_validators.into(),
+        );
+
+        pricing_weight.saturating_add(process_subnets::<T>(
+            block_number,
+            subnets_emission_distribution,
+        ))
+    }
+
+    /// Runs epochs previously deferred because of the epoch weight budget.
+    ///
+    /// # Arguments
+    ///
+    /// * `remaining_weight` - The weight that can still be spent on epochs.
+    /// * `force_first` - Whether to run the first deferred epoch, even if it exceeds the budget.
+    ///
+    /// # Returns
+    ///
+    /// The weight consumed, including the queue bookkeeping.
+    pub fn run_deferred_epochs(remaining_weight: Weight, force_first: bool) -> Weight {
+        let db_weight = T::DbWeight::get();
+        let mut consumed = db_weight.reads(1);
+        if consumed.any_gt(remaining_weight) {
+            return Weight::zero();
+        }
+
+        let mut deferred = DeferredEpochs::<T>::get();
+        if deferred.is_empty() {
+            return consumed;
+        }
+
+        let mut processed = 0usize;
+        let mut ran_any = false;
+        for netuid in deferred.iter().copied() {
+            // Subnet might have been removed in the meantime
+            if !N::<T>::contains_key(netuid) {
+                processed = processed.saturating_add(1);
+                continue;
+            }
+
+            let epoch_weight = estimate_epoch_weight::<T>(netuid);
+            let forced = force_first && !ran_any;
+            if !forced && consumed.saturating_add(epoch_weight).any_gt(remaining_weight) {
+                break;
+            }
+
+            run_epoch::<T>(netuid);
+            consumed = consumed.saturating_add(epoch_weight);
+            processed = processed.saturating_add(1);
+            ran_any = true;
+        }
+
+        deferred.drain(..processed.min(deferred.len()));
+        DeferredEpochs::<T>::set(deferred);
+
+        consumed.saturating_add(db_weight.writes(1))
     }
 
     // ---------------------------------
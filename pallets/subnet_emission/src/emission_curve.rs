@@ -0,0 +1,127 @@
+use super::*;
+use frame_support::{dispatch::DispatchResult, traits::Get};
+use pallet_subnet_emission_api::EmissionCurve;
+use pallet_subspace::Error;
+
+/// Fixed point scale used when computing the exponential decay.
+const DECAY_SCALE: u128 = 1_000_000_000_000_000_000;
+
+/// `2^(-2^-i) * DECAY_SCALE` for `i` in `1..=20`.
+const DECAY_FACTORS: [u128; 20] = [
+    707_106_781_186_547_524,
+    840_896_415_253_714_543,
+    917_004_043_204_671_232,
+    957_603_280_698_573_647,
+    978_572_062_087_700_135,
+    989_228_013_193_975_484,
+    994_599_423_483_633_176,
+    997_296_056_085_470_126,
+    998_647_112_890_970_174,
+    999_323_327_502_650_752,
+    999_661_606_496_243_684,
+    999_830_788_931_929_063,
+    999_915_390_886_613_498,
+    999_957_694_548_431_133,
+    999_978_847_050_491_930,
+    999_989_423_469_314_464,
+    999_994_711_720_674_283,
+    999_997_355_856_841_395,
+    999_998_677_927_546_760,
+    999_999_338_963_554_895,
+];
+
+impl<T: Config> Pallet<T> {
+    /// The active emission curve. Falls back to the step halving described by the
+    /// `HalvingInterval` and `MaxSupply` constants if governance never set one.
+    pub fn emission_curve() -> EmissionCurve {
+        ActiveEmissionCurve::<T>::get().unwrap_or_else(Self::legacy_emission_curve)
+    }
+
+    /// The step halving schedule the chain used before the curve became configurable.
+    pub fn legacy_emission_curve() -> EmissionCurve {
+        let unit = 10_u64.saturating_pow(T::Decimals::get() as u32);
+        EmissionCurve::StepHalving {
+            interval: T::HalvingInterval::get().saturating_mul(unit),
+            max_supply: T::MaxSupply::get().saturating_mul(unit),
+        }
+    }
+
+    pub fn set_emission_curve(curve: EmissionCurve) -> DispatchResult {
+        curve.validate().map_err(|err| {
+            log::debug!("invalid emission curve: {err}");
+            Error::<T>::InvalidEmissionCurve
+        })?;
+
+        ActiveEmissionCurve::<T>::put(&curve);
+        Self::deposit_event(Event::<T>::EmissionCurveUpdated(curve));
+        Ok(())
+    }
+
+    /// Calculates the emission per block for the given total issuance using the
+    /// active emission curve and `UnitEmission`.
+    pub fn emission_at_issuance(total_issuance: u64) -> u64 {
+        let curve = Self::emission_curve();
+        let unit_emission = UnitEmission::<T>::get();
+
+        if total_issuance >= curve.max_supply() {
+            return 0;
+        }
+
+        match curve {
+            EmissionCurve::StepHalving { interval, .. } => total_issuance
+                .checked_div(interval)
+                .and_then(|halvings| u32::try_from(halvings).ok())
+                .and_then(|halvings| unit_emission.checked_shr(halvings))
+                .unwrap_or_default(),
+            EmissionCurve::ExponentialDecay { half_life, .. } => {
+                exponential_decay(unit_emission, total_issuance, half_life)
+            }
+            EmissionCurve::Piecewise { steps, .. } => steps
+                .iter()
+                .rev()
+                .find(|(threshold, _)| *threshold <= total_issuance)
+                .map(|(_, multiplier)| multiplier.mul_floor(unit_emission))
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Forecasts the emission per block for each of the given issuance levels.
+    pub fn forecast_emission(issuance_levels: Vec<u64>) -> Vec<u64> {
+        issuance_levels.into_iter().map(Self::emission_at_issuance).collect()
+    }
+}
+
+/// Computes `unit_emission * 2^(-issuance / half_life)`. Whole halvings are applied
+/// as shifts, the remaining fraction is resolved bit by bit with `DECAY_FACTORS`.
+fn exponential_decay(unit_emission: u64, issuance: u64, half_life: u64) -> u64 {
+    let (Some(halvings), Some(remainder)) =
+        (issuance.checked_div(half_life), issuance.checked_rem(half_life))
+    else {
+        return 0;
+    };
+
+    let Some(base) =
+        u32::try_from(halvings).ok().and_then(|halvings| unit_emission.checked_shr(halvings))
+    else {
+        return 0;
+    };
+
+    let precision = DECAY_FACTORS.len() as u32;
+    let fraction = (remainder as u128)
+        .saturating_mul(1_u128.checked_shl(precision).unwrap_or_default())
+        .checked_div(half_life as u128)
+        .unwrap_or_default();
+
+    let mut emission = base as u128;
+    for (bit, factor) in DECAY_FACTORS.iter().enumerate() {
+        let shift = precision.saturating_sub(1).saturating_sub(bit as u32);
+        if fraction.checked_shr(shift).unwrap_or_default() & 1 != 0 {
+            emission = emission
+                .saturating_mul(*factor)
+                .checked_div(DECAY_SCALE)
+                .unwrap_or_default();
+        }
+    }
+
+    emission as u64
+}
@@ -0,0 +1,78 @@
+use super::*;
+use crate::types::EpochSummary;
+use frame_support::traits::Get;
+use pallet_subspace::Pallet as PalletSubspace;
+
+impl<T: Config> Pallet<T> {
+    /// Records the summary of a finished epoch in the subnet's `EpochHistory` ring buffer,
+    /// overwriting the oldest entry once the buffer is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `netuid` - The ID of the subnet.
+    /// * `keys` - The module keys, indexed by uid.
+    /// * `emissions` - The emission of every module, indexed by uid.
+    /// * `validator_permits` - The validator permits after the epoch, indexed by uid.
+    /// * `consensus` - The consensus vector produced by the epoch.
+    /// * `total_emission` - The total emission distributed, including the founder share.
+    pub fn record_epoch_summary(
+        netuid: u16,
+        keys: &[T::AccountId],
+        emissions: &[u64],
+        validator_permits: &[bool],
+        consensus: &[u16],
+        total_emission: u64,
+    ) {
+        let history_length = T::EpochHistoryLength::get();
+        if history_length == 0 {
+            return;
+        }
+
+        let mut top_earners: Vec<_> = keys
+            .iter()
+            .zip(emissions)
+            .filter(|(_, emission)| **emission > 0)
+            .map(|(key, emission)| (key.clone(), *emission))
+            .collect();
+        top_earners.sort_by(|(_, a), (_, b)| b.cmp(a));
+        top_earners.truncate(T::EpochHistoryTopEarners::get() as usize);
+
+        let validator_count = validator_permits.iter().filter(|permit| **permit).count();
+
+        let summary = EpochSummary {
+            block: PalletSubspace::<T>::get_current_block_number(),
+            total_emission,
+            top_earners,
+            validator_count: validator_count.try_into().unwrap_or(u16::MAX),
+            consensus_hash: sp_io::hashing::blake2_256(&consensus.encode()),
+        };
+
+        let epoch = EpochHistoryCount::<T>::get(netuid);
+        EpochHistory::<T>::insert(netuid, epoch.checked_rem(history_length).unwrap_or(0), summary);
+        EpochHistoryCount::<T>::insert(netuid, epoch.saturating_add(1));
+    }
+
+    /// Returns up to `count` epoch summaries of the subnet, starting at the epoch number `from`.
+    ///
+    /// Epochs are numbered from 0, in the order they finished. Epochs which were already
+    /// overwritten in the ring buffer are skipped.
+    pub fn get_epoch_history(
+        netuid: u16,
+        from: u32,
+        count: u32,
+    ) -> Vec<(u32, EpochSummary<T::AccountId>)> {
+        let history_length = T::EpochHistoryLength::get();
+        let recorded = EpochHistoryCount::<T>::get(netuid);
+
+        let oldest = recorded.saturating_sub(history_length);
+        let from = from.max(oldest);
+        let to = from.saturating_add(count.min(history_length)).min(recorded);
+
+        (from..to)
+            .filter_map(|epoch| {
+                let index = epoch.checked_rem(history_length)?;
+                EpochHistory::<T>::get(netuid, index).map(|summary| (epoch, summary))
+            })
+            .collect()
+    }
+}
@@ -1,7 +1,11 @@
 #![allow(non_snake_case)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use crate::types::{BlockWeights, PublicKey, SubnetDecryptionInfo};
+use crate::types::{
+    BlockWeights, DecryptionCommitteeInfo, DecryptionNodeBond, DecryptionNodeFault,
+    DecryptionNodeFaultKind, DecryptionShare, DecryptionShareSet, EpochSummary, MultiBlockEpoch,
+    PublicKey, SubnetDecryptionInfo,
+};
 use frame_system::pallet_prelude::OriginFor;
 pub use pallet::*;
 use parity_scale_codec::{Decode, Encode};
@@ -14,7 +18,10 @@ use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 // ==============
 
 pub mod decryption;
+pub mod decryption_bonds;
 pub mod distribute_emission;
+pub mod emission_curve;
+pub mod epoch_history;
 pub mod migrations;
 pub mod subnet_pricing {
     pub mod demo;
@@ -32,23 +39,27 @@ mod benchmarking;
 #[frame_support::pallet]
 pub mod pallet {
     pub use crate::weights::WeightInfo;
-    use crate::{subnet_consensus::util::params::ConsensusParams, *};
+    use crate::{
+        subnet_consensus::{util::params::ConsensusParams, yuma::YumaInputs},
+        *,
+    };
     use frame_support::{
         pallet_prelude::{ValueQuery, *},
-        sp_runtime::SaturatedConversion,
+        sp_runtime::{Percent, SaturatedConversion},
         storage::with_storage_layer,
         traits::{ConstU64, Currency},
     };
     use frame_system::pallet_prelude::BlockNumberFor;
-    use pallet_subnet_emission_api::SubnetConsensus;
+    use pallet_subnet_emission_api::{EmissionCurve, SubnetConsensus};
     use pallet_subspace::{define_module_includes, define_subnet_includes, TotalStake};
     use subnet_pricing::root::RootPricing;
+    use substrate_fixed::types::I32F32;
 
     #[cfg(feature = "testnet")]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(16);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(18);
 
     #[cfg(not(feature = "testnet"))]
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -74,10 +85,11 @@ pub mod pallet {
         #[pallet::constant]
         type Decimals: Get<u8>;
 
+        /// Tokens issued between halvings of the default emission curve.
         #[pallet::constant]
         type HalvingInterval: Get<u64>;
 
-        /// The maximum token supply.
+        /// The maximum token supply of the default emission curve.
         #[pallet::constant]
         type MaxSupply: Get<u64>;
 
@@ -92,6 +104,23 @@ pub mod pallet {
         #[pallet::constant]
         type OffchainWorkerBanDuration: Get<u64>;
 
+        /// The minimum bond a decryption node operator needs to be an authority
+        #[pallet::constant]
+        type MinDecryptionNodeBond: Get<u64>;
+
+        /// The portion of its bond a decryption node loses to the DAO treasury on every fault
+        #[pallet::constant]
+        type DecryptionNodeSlash: Get<Percent>;
+
+        /// The number of blocks an operator waits for its bond after leaving the authorities
+        #[pallet::constant]
+        type DecryptionNodeUnbondingPeriod: Get<u64>;
+
+        /// The number of blocks authorities from before decryption node bonds keep being
+        /// assigned subnets without a bond
+        #[pallet::constant]
+        type DecryptionNodeBondGracePeriod: Get<u64>;
+
         /// The number of consecutive missed pings after which a decryption node is considered
         /// inactive
         #[pallet::constant]
@@ -106,6 +135,24 @@ pub mod pallet {
         #[pallet::constant]
         type EncryptionPeriodBuffer: Get<u64>;
 
+        /// The maximum weight `on_initialize` may spend running subnet epochs. Epochs that would
+        /// exceed it are deferred to `on_idle` or to the following blocks.
+        #[pallet::constant]
+        type MaxEpochWeightPerBlock: Get<Weight>;
+
+        /// Yuma subnets with more modules than this run their epoch across several blocks, one
+        /// phase per block, instead of computing it whole on the block it's due.
+        #[pallet::constant]
+        type MultiBlockEpochThreshold: Get<u16>;
+
+        /// The number of epoch summaries kept in each subnet's `EpochHistory`.
+        #[pallet::constant]
+        type EpochHistoryLength: Get<u32>;
+
+        /// The number of top earning modules recorded in every epoch summary.
+        #[pallet::constant]
+        type EpochHistoryTopEarners: Get<u32>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -121,11 +168,17 @@ pub mod pallet {
         double_maps: {
             Weights,
             WeightEncryptionData,
-            ConsensusParameters
+            ConsensusParameters,
+            EpochHistory,
+            DecryptionShares
         },
         maps: {
             SubnetDecryptionData,
-            SubnetConsensusType
+            SubnetConsensusType,
+            YumaEpochCursor,
+            YumaEpochInputs,
+            YumaEpochWeights,
+            EpochHistoryCount
         }
     );
 
@@ -161,11 +214,46 @@ pub mod pallet {
     #[pallet::storage]
     pub type SubnetConsensusType<T> = StorageMap<_, Identity, u16, SubnetConsensus>;
 
+    /// Yuma epochs currently being computed across several blocks, keyed by netuid
+    #[pallet::storage]
+    pub type YumaEpochCursor<T> = StorageMap<_, Identity, u16, MultiBlockEpoch>;
+
+    /// Values the multi block epoch of a subnet was started with, written once
+    #[pallet::storage]
+    pub type YumaEpochInputs<T: Config> = StorageMap<_, Identity, u16, YumaInputs<T>>;
+
+    /// Weights of the multi block epoch of a subnet, until its bonds phase
+    #[pallet::storage]
+    pub type YumaEpochWeights<T> = StorageMap<_, Identity, u16, Vec<Vec<(u16, I32F32)>>>;
+
+    /// Ring buffer of the subnet's last `EpochHistoryLength` epoch summaries, indexed by the
+    /// epoch number modulo the buffer length
+    #[pallet::storage]
+    pub type EpochHistory<T: Config> =
+        StorageDoubleMap<_, Identity, u16, Identity, u32, EpochSummary<T::AccountId>>;
+
+    /// Number of epochs ever recorded in the subnet's `EpochHistory`
+    #[pallet::storage]
+    pub type EpochHistoryCount<T> = StorageMap<_, Identity, u16, u32, ValueQuery>;
+
     /// Netuid, to block number to consensus parameters
     #[pallet::storage]
     pub type ConsensusParameters<T> =
         StorageDoubleMap<_, Identity, u16, Identity, u64, ConsensusParams<T>, OptionQuery>;
 
+    /// Decryption shares committee members submitted for the subnet's encrypted weights, keyed by
+    /// the (block, uid) of the weights, cleared once the weights are processed
+    #[pallet::storage]
+    pub type DecryptionShares<T: Config> = StorageDoubleMap<
+        _,
+        Identity,
+        u16,
+        Identity,
+        (u64, u16),
+        BoundedVec<(T::AccountId, DecryptionShare), T::MaxAuthorities>,
+        ValueQuery,
+    >;
+
     // --- Storage Maps ---
     // ? Does not have to be removed upon subnet removal
 
@@ -197,15 +285,56 @@ pub mod pallet {
     #[pallet::storage]
     pub type DecryptionNodeCursor<T> = StorageValue<_, u16, ValueQuery>;
 
+    /// Upper bound of `DeferredEpochs`. A subnet is deferred at most once and
+    /// `MaxAllowedSubnets` is a `u16`, so the queue can't grow past the netuid space.
+    pub const MAX_DEFERRED_EPOCHS: u32 = u16::MAX as u32;
+
+    /// Subnets whose epoch was due, but did not fit into the block's epoch weight budget.
+    /// They are run, in order, by `on_idle` or by the `on_initialize` of the following blocks.
+    #[pallet::storage]
+    pub type DeferredEpochs<T> =
+        StorageValue<_, BoundedVec<u16, ConstU32<MAX_DEFERRED_EPOCHS>>, ValueQuery>;
+
     /// Association of signing public keys with associated rsa encryption public keys.
     #[pallet::storage]
     pub type Authorities<T: Config> =
         StorageValue<_, BoundedVec<(T::AccountId, PublicKey), T::MaxAuthorities>, ValueQuery>;
 
+    /// When set, weights are encrypted to the committee key and decrypted by a threshold of its
+    /// members instead of a single node
+    #[pallet::storage]
+    pub type DecryptionCommittee<T: Config> =
+        StorageValue<_, DecryptionCommitteeInfo<T::AccountId>>;
+
+    /// Keys a decryption node rotated away from, oldest first, each with the subnets still holding
+    /// weights encrypted to it. A subnet is released once its weights are processed or dropped,
+    /// the node keeps each key until none are left, see `Pallet::retained_decryption_keys`.
+    #[pallet::storage]
+    pub type DecryptionKeyRotations<T: Config> =
+        StorageMap<_, Identity, T::AccountId, Vec<(PublicKey, Vec<u16>)>>;
+
+    /// Bonds of the decryption node operators.
+    #[pallet::storage]
+    pub type DecryptionNodeBonds<T: Config> =
+        StorageMap<_, Identity, T::AccountId, DecryptionNodeBond, ValueQuery>;
+
+    /// The most recent faults of every decryption node, oldest first.
+    #[pallet::storage]
+    pub type DecryptionNodeFaults<T: Config> =
+        StorageMap<_, Identity, T::AccountId, Vec<DecryptionNodeFault>, ValueQuery>;
+
+    /// Block until which an authority from before decryption node bonds is treated as bonded.
+    #[pallet::storage]
+    pub type DecryptionNodeBondGrace<T: Config> = StorageMap<_, Identity, T::AccountId, u64>;
+
     // Subnet Pricing & Consensus
     #[pallet::storage]
     pub type UnitEmission<T> = StorageValue<_, u64, ValueQuery, ConstU64<23148148148>>;
 
+    /// Emission curve set through governance, see `Pallet::emission_curve`.
+    #[pallet::storage]
+    pub type ActiveEmissionCurve<T> = StorageValue<_, EmissionCurve>;
+
     // Emission Allocation per Block step
     // ==================================
 
@@ -228,21 +357,27 @@ pub mod pallet {
             log::info!("Distributed subnets to nodes");
             Self::assign_activation_blocks(block_number);
             Self::cancel_expired_offchain_workers(block_number);
-            Self::process_ban_queue(block_number);
+            let mut weight = Self::process_ban_queue(block_number);
+            weight = weight.saturating_add(Self::release_decryption_node_bonds(block_number));
             log::info!("Cancelled expired offchain workers");
             let emission_per_block = Self::get_total_emission_per_block();
             log::info!("Emission per block: {:?}", emission_per_block);
             // Make sure to use storage layer,
             // so runtime can never panic in initialization hook
-            let res: Result<(), DispatchError> = with_storage_layer(|| {
-                Self::process_emission_distribution(block_number, emission_per_block);
-                Ok(())
+            let res: Result<Weight, DispatchError> = with_storage_layer(|| {
+                Ok(Self::process_emission_distribution(
+                    block_number,
+                    emission_per_block,
+                ))
             });
-            if let Err(err) = res {
-                log::error!("Error in on_initialize emission: {err:?}, skipping...");
+            match res {
+                Ok(epochs_weight) => weight = weight.saturating_add(epochs_weight),
+                Err(err) => log::error!("Error in on_initialize emission: {err:?}, skipping..."),
             }
 
+            let mut subnet_count: u32 = 0;
             for netuid in pallet_subspace::N::<T>::iter_keys() {
+                subnet_count = subnet_count.saturating_add(1);
                 if pallet_subspace::Pallet::<T>::blocks_until_next_epoch(netuid, block_number) > 0 {
                     continue;
                 }
@@ -251,7 +386,18 @@ pub mod pallet {
                 Self::clear_set_weight_rate_limiter(netuid);
             }
 
-            Weight::zero()
+            // Covers the decryption node upkeep of every subnet as well
+            weight.saturating_add(<T as Config>::WeightInfo::on_initialize(subnet_count))
+        }
+
+        fn on_idle(_block_number: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            let res: Result<Weight, DispatchError> =
+                with_storage_layer(|| Ok(Self::run_deferred_epochs(remaining_weight, false)));
+
+            res.unwrap_or_else(|err| {
+                log::error!("Error running deferred epochs in on_idle: {err:?}, skipping...");
+                Weight::zero()
+            })
         }
     }
 
@@ -260,6 +406,12 @@ pub mod pallet {
     pub enum Event<T: Config> {
         /// Subnets tempo has finished or Snapshot has been taken
         EpochFinalized(u16),
+        /// Subnet epoch did not fit into the block's epoch weight budget and was deferred
+        EpochDeferred(u16),
+        /// Subnet epoch did not run since no weights were set, the pending emission carries over
+        EpochSkipped(u16),
+        /// The emission curve was replaced through governance
+        EmissionCurveUpdated(EmissionCurve),
         /// Weight copying decryption was canceled
         DecryptionNodeCanceled {
             subnet_id: u16,
@@ -283,6 +435,33 @@ pub mod pallet {
             subnet_id: u16,
             node_id: T::AccountId,
         },
+        /// A committee member submitted its decryption shares of the subnet's weights
+        DecryptionSharesReceived {
+            subnet_id: u16,
+            node_id: T::AccountId,
+        },
+        /// Decryption node announced a new key, weights are encrypted to it from now on
+        DecryptionKeyRotated {
+            node_id: T::AccountId,
+            public_key: PublicKey,
+        },
+        /// Operator bonded tokens to back its decryption node
+        DecryptionNodeBonded { node_id: T::AccountId, amount: u64 },
+        /// Decryption node left the authorities, its bond is returned at `release_block`
+        DecryptionNodeUnbonding {
+            node_id: T::AccountId,
+            amount: u64,
+            release_block: u64,
+        },
+        /// Unbonding period is over and the bond was returned to the operator
+        DecryptionNodeBondReleased { node_id: T::AccountId, amount: u64 },
+        /// Part of the decryption node's bond was slashed to the DAO treasury
+        DecryptionNodeSlashed {
+            node_id: T::AccountId,
+            subnet_id: u16,
+            kind: DecryptionNodeFaultKind,
+            amount: u64,
+        },
     }
 
     #[derive(Debug)]
@@ -324,48 +503,10 @@ pub mod pallet {
         // Halving Logic / Emission distributed per block
         // ===============================================
 
-        // Halving occurs every 250 million minted tokens, until reaching a maximum supply of 1
-        // billion tokens.
+        /// Emission per block at the current total issuance, following the active
+        /// emission curve.
         pub fn get_total_emission_per_block() -> u64 {
-            let total_issuance = Self::get_total_issuence_as_u64();
-            let unit_emission = UnitEmission::<T>::get();
-            let halving_interval = T::HalvingInterval::get();
-            let max_supply = T::MaxSupply::get();
-            let decimals = T::Decimals::get() as u32;
-
-            let halving_interval = match halving_interval.checked_mul(10_u64.pow(decimals)) {
-                Some(val) => val,
-                None => {
-                    log::error!(
-                        "Critical error: halving_interval overflow in get_total_emission_per_block"
-                    );
-                    return 0;
-                }
-            };
-
-            let max_supply = match max_supply.checked_mul(10_u64.pow(decimals)) {
-                Some(val) => val,
-                None => {
-                    log::error!(
-                        "Critical error: max_supply overflow in get_total_emission_per_block"
-                    );
-                    return 0;
-                }
-            };
-
-            if total_issuance >= max_supply {
-                0
-            } else {
-                match total_issuance.checked_div(halving_interval) {
-                    Some(halving_count) => unit_emission >> halving_count,
-                    None => {
-                        log::error!(
-                            "Critical error: Division failed in get_total_emission_per_block"
-                        );
-                        0
-                    }
-                }
-            }
+            Self::emission_at_issuance(Self::get_total_issuence_as_u64())
         }
 
         // Emission Distribution per Subnet
@@ -374,7 +515,14 @@ pub mod pallet {
         /// Returns emission for every subnet
         #[must_use]
         pub fn get_subnet_pricing(token_emission: u64) -> PricedSubnets {
+            Self::price_subnets(token_emission).0
+        }
+
+        /// Prices the subnets, returning the number of rootnet validators along with them so the
+        /// pricing can be billed.
+        pub(crate) fn price_subnets(token_emission: u64) -> (PricedSubnets, u16) {
             let rootnet_id = Self::get_consensus_netuid(SubnetConsensus::Root).unwrap_or(0);
+            let root_validators = pallet_subspace::N::<T>::get(rootnet_id);
             let pricing = RootPricing::<T>::new(rootnet_id, token_emission);
             let priced_subnets = match pricing.run() {
                 Ok(priced_subnets) => priced_subnets,
@@ -388,7 +536,7 @@ pub mod pallet {
                 SubnetEmission::<T>::insert(netuid, emission);
             }
 
-            priced_subnets
+            (priced_subnets, root_validators)
         }
     }
 
@@ -1,7 +1,7 @@
 use crate::*;
 use frame_support::{
     pallet_prelude::Weight,
-    traits::{OnRuntimeUpgrade, StorageVersion},
+    traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
 };
 
 pub mod v2 {
@@ -23,3 +23,75 @@ pub mod v2 {
         }
     }
 }
+
+pub mod v3 {
+    use super::*;
+
+    /// Persists the step halving schedule derived from the runtime constants as the
+    /// active emission curve, so the emission does not change with the upgrade.
+    pub struct MigrateToV3<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV3<T> {
+        fn on_runtime_upgrade() -> frame_support::weights::Weight {
+            let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (2, 3);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (16, 17);
+
+            if on_chain_version != from {
+                log::info!("Emission curve already migrated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            if !crate::ActiveEmissionCurve::<T>::exists() {
+                crate::ActiveEmissionCurve::<T>::put(Pallet::<T>::legacy_emission_curve());
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+            log::info!("Migrated emission curve to v{to}");
+            Weight::zero()
+        }
+    }
+}
+
+pub mod v4 {
+    use super::*;
+
+    /// Authorities from before decryption node bonds have no bond. Instead of dropping out of
+    /// the active nodes at their next ping, they are given `DecryptionNodeBondGracePeriod`
+    /// blocks to bond.
+    pub struct MigrateToV4<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let on_chain_version = Pallet::<T>::on_chain_storage_version();
+
+            #[cfg(not(feature = "testnet"))]
+            let (from, to) = (3, 4);
+            #[cfg(feature = "testnet")]
+            let (from, to) = (17, 18);
+
+            if on_chain_version != from {
+                log::info!("Storage v4 already updated or previous migration not applied");
+                return Weight::zero();
+            }
+
+            let grace_end = pallet_subspace::Pallet::<T>::get_current_block_number()
+                .saturating_add(T::DecryptionNodeBondGracePeriod::get());
+
+            let mut count: u64 = 1;
+            for (node_id, _) in Authorities::<T>::get() {
+                if !Pallet::<T>::is_decryption_node_bonded(&node_id) {
+                    count = count.saturating_add(1);
+                    DecryptionNodeBondGrace::<T>::insert(node_id, grace_end);
+                }
+            }
+
+            StorageVersion::new(to).put::<Pallet<T>>();
+            log::info!("Migrated to v{to}, unbonded authorities have until block {grace_end}");
+            T::DbWeight::get().reads_writes(count.saturating_add(1), count)
+        }
+    }
+}
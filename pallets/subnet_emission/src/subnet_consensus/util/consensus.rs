@@ -296,6 +296,17 @@ pub fn compute_consensus_and_trust_yuma<T: Config>(
 
     log::trace!("final consensus: {consensus:?}");
 
+    compute_trust_yuma::<T>(modules, weights, active_stake, consensus)
+}
+
+/// Clips the weights at the given majority consensus and computes the preranks and validator
+/// trust, the part of [`compute_consensus_and_trust_yuma`] after the weighted medians.
+pub fn compute_trust_yuma<T: Config>(
+    modules: &FlattenedModules<T::AccountId>,
+    weights: &mut WeightsVal,
+    active_stake: &ActiveStake,
+    consensus: Vec<I32F32>,
+) -> ConsensusAndTrust {
     // Compute preranks: r_j = SUM(i) w_ij * s_i
     let preranks = matmul_sparse(
         weights.as_ref(),
@@ -836,6 +847,16 @@ impl<T: Config> ConsensusOutput<T> {
 
         log::info!("subnet_id {subnet_id} has active {active:?}, consensus {consensus:?}, dividends {dividends:?}, combined_emissions {combined_emissions:?}, incentives {incentives:?}, pruning_scores {pruning_scores:?}, ranks {ranks:?}, trust {trust:?}, validator_permits {validator_permits:?}, validator_trust {validator_trust:?}, bonds {bonds:?}");
 
+        let keys: Vec<_> = self.modules.keys.iter().map(|key| key.0.clone()).collect();
+        crate::Pallet::<T>::record_epoch_summary(
+            subnet_id,
+            &keys,
+            &combined_emissions,
+            &validator_permits,
+            &consensus,
+            self.total_emitted,
+        );
+
         Active::<T>::insert(subnet_id, active);
         Consensus::<T>::insert(subnet_id, consensus);
         Dividends::<T>::insert(subnet_id, dividends);
@@ -3,7 +3,7 @@ use core::fmt::Debug;
 use crate::{Config, WeightEncryptionData};
 use frame_support::DebugNoBound;
 use pallet_subspace::{
-    math::*, AlphaValues, BalanceOf, Bonds, BondsMovingAverage, Founder, Kappa, Keys, LastUpdate,
+    math::*, AlphaValues, BalanceOf, Bonds, BondsMovingAverage, Kappa, Keys, LastUpdate,
     MaxAllowedValidators, MaxWeightAge, MinValidatorStake, Pallet as PalletSubspace,
     UseWeightsEncryption, ValidatorPermits, Vec, WeightSettingDelegation,
 };
@@ -67,7 +67,7 @@ pub struct ModuleParams<AccountId: Debug> {
     pub weight_hash: Vec<u8>,
 }
 
-#[derive(DebugNoBound, Clone)]
+#[derive(DebugNoBound, Clone, Encode, Decode, TypeInfo)]
 pub struct FlattenedModules<AccountId: Debug> {
     pub keys: Vec<ModuleKey<AccountId>>,
     pub last_update: Vec<u64>,
@@ -191,7 +191,7 @@ impl<T: Config> ConsensusParams<T> {
             )
             .collect::<Result<_, _>>()?;
 
-        let founder_key = AccountKey(Founder::<T>::get(subnet_id));
+        let founder_key = AccountKey(PalletSubspace::<T>::founder_emission_recipient(subnet_id));
         let (token_emission, founder_emission) =
             PalletSubspace::<T>::calculate_founder_emission(subnet_id, token_emission);
 
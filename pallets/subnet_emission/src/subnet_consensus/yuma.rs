@@ -4,7 +4,11 @@ use crate::{
 };
 use core::marker::PhantomData;
 use frame_support::DebugNoBound;
-use sp_std::vec::Vec;
+use pallet_subspace::math::weighted_median_col_sparse_range;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::{mem, vec::Vec};
+use substrate_fixed::types::I32F32;
 
 #[derive(DebugNoBound)]
 pub struct YumaEpoch<T: Config> {
@@ -34,6 +38,21 @@ impl<T: Config> YumaEpoch<T> {
         self,
         input_weights: Vec<(u16, Vec<(u16, u16)>)>,
     ) -> Result<ConsensusOutput<T>, EmissionError> {
+        let mut state = self.start(input_weights)?;
+
+        loop {
+            if let Some(output) = state.step(u16::MAX)? {
+                return Ok(output);
+            }
+        }
+    }
+
+    /// Runs the weights phase of the epoch, returning the state from which the remaining phases
+    /// can be stepped through, either at once or across several blocks.
+    pub fn start(
+        self,
+        input_weights: Vec<(u16, Vec<(u16, u16)>)>,
+    ) -> Result<YumaEpochState<T>, EmissionError> {
         log::info!(
             "running yuma for subnet_id {}, will emit {:?} modules and {:?} to founder",
             self.subnet_id,
@@ -60,7 +79,7 @@ impl<T: Config> YumaEpoch<T> {
             &weights,
         );
 
-        let mut weights = compute_weights(&self.modules, &self.params, weights)
+        let weights = compute_weights(&self.modules, &self.params, weights)
             .ok_or(EmissionError::Other("weights are broken"))?;
 
         let stake = StakeVal::unchecked_from_inner(self.modules.stake_normalized.clone());
@@ -71,50 +90,175 @@ impl<T: Config> YumaEpoch<T> {
         let active_stake = compute_active_stake(&self.modules, &self.params, &inactive, &stake);
         log::info!("final active stake: {active_stake:?}");
 
-        let ConsensusAndTrust {
-            consensus,
-            validator_trust,
-            preranks,
-        } = compute_consensus_and_trust_yuma(
-            &self.modules,
-            &self.params,
-            &mut weights,
-            &active_stake,
-        );
+        Ok(YumaEpochState {
+            inputs: YumaInputs {
+                params: self.params,
+                modules: self.modules,
+                stake: stake.into_inner(),
+                active_stake: active_stake.into_inner(),
+                active,
+                new_permits,
+            },
+            weights: weights.into_inner(),
+            phase: YumaPhase::Consensus { next_column: 0 },
+            outputs: Default::default(),
+        })
+    }
+}
 
-        let IncentivesAndTrust {
-            incentives,
-            ranks,
-            trust,
-        } = compute_incentive_and_trust::<T>(&self.modules, &weights, &active_stake, &preranks);
+/// The next phase a [`YumaEpochState`] will run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo)]
+pub enum YumaPhase {
+    /// Computes the majority consensus of the columns starting at `next_column`, a chunk of them
+    /// at a time
+    Consensus { next_column: u16 },
+    /// Clips weights at majority consensus, computes incentives and trust
+    Trust,
+    /// Computes the EMA bonds and dividends
+    Bonds,
+    /// Computes emissions and produces the consensus output
+    Apply,
+}
 
-        let BondsAndDividends {
-            ema_bonds,
-            dividends,
-        } = compute_bonds_and_dividends_yuma(
-            &self.params,
-            &self.modules,
-            &consensus,
-            &weights,
-            &active_stake,
-            &incentives,
-        )
-        .ok_or(EmissionError::Other("bonds storage is broken"))?;
+/// Values a Yuma epoch was started with, they do not change across its phases.
+#[derive(DebugNoBound, Clone, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct YumaInputs<T: Config> {
+    pub params: params::ConsensusParams<T>,
+    pub modules: params::FlattenedModules<T::AccountId>,
+    pub stake: Vec<I32F32>,
+    pub active_stake: Vec<I32F32>,
+    pub active: Vec<bool>,
+    pub new_permits: Vec<bool>,
+}
 
-        process_consensus_output::<T>(
-            &self.params,
-            &self.modules,
-            stake,
-            active_stake,
-            consensus,
-            incentives,
-            dividends,
-            trust,
-            ranks,
-            active,
-            validator_trust,
-            new_permits,
-            &ema_bonds,
-        )
+/// Values computed by the previous phases of a Yuma epoch.
+#[derive(Debug, Default, Clone, Encode, Decode, TypeInfo)]
+pub struct YumaOutputs {
+    pub consensus: Vec<I32F32>,
+    pub validator_trust: Vec<I32F32>,
+    pub incentives: Vec<I32F32>,
+    pub ranks: Vec<I32F32>,
+    pub trust: Vec<I32F32>,
+    pub ema_bonds: Vec<Vec<(u16, I32F32)>>,
+    pub dividends: Vec<I32F32>,
+}
+
+/// A Yuma epoch that has been started, but not finished yet. Every call to [`Self::step`] runs
+/// a single phase, so the state can be persisted and the epoch continued on a later block.
+///
+/// The weights are only changed by the trust phase, which clips them, and are no longer needed
+/// after the bonds phase.
+#[derive(DebugNoBound, Clone)]
+pub struct YumaEpochState<T: Config> {
+    pub inputs: YumaInputs<T>,
+    pub weights: Vec<Vec<(u16, I32F32)>>,
+    pub phase: YumaPhase,
+    pub outputs: YumaOutputs,
+}
+
+impl<T: Config> YumaEpochState<T> {
+    /// Runs the current phase, computing the consensus of at most `columns_per_step` columns.
+    /// Returns the consensus output once the last phase finishes.
+    pub fn step(
+        &mut self,
+        columns_per_step: u16,
+    ) -> Result<Option<ConsensusOutput<T>>, EmissionError> {
+        let inputs = &mut self.inputs;
+        let outputs = &mut self.outputs;
+
+        match self.phase {
+            YumaPhase::Consensus { next_column } => {
+                let columns = inputs.modules.module_count::<u16>();
+                let end = next_column.saturating_add(columns_per_step).min(columns);
+
+                // Clip weights at majority consensus
+                outputs.consensus.extend(weighted_median_col_sparse_range(
+                    &inputs.active_stake,
+                    &self.weights,
+                    next_column..end,
+                    inputs.params.kappa,
+                ));
+
+                self.phase = if end < columns {
+                    YumaPhase::Consensus { next_column: end }
+                } else {
+                    YumaPhase::Trust
+                };
+                Ok(None)
+            }
+            YumaPhase::Trust => {
+                let mut weights = WeightsVal::unchecked_from_inner(mem::take(&mut self.weights));
+                let active_stake = ActiveStake::unchecked_from_inner(inputs.active_stake.clone());
+
+                let ConsensusAndTrust {
+                    consensus,
+                    validator_trust,
+                    preranks,
+                } = compute_trust_yuma::<T>(
+                    &inputs.modules,
+                    &mut weights,
+                    &active_stake,
+                    mem::take(&mut outputs.consensus),
+                );
+
+                let IncentivesAndTrust {
+                    incentives,
+                    ranks,
+                    trust,
+                } = compute_incentive_and_trust::<T>(
+                    &inputs.modules,
+                    &weights,
+                    &active_stake,
+                    &preranks,
+                );
+
+                self.weights = weights.into_inner();
+                outputs.consensus = consensus.into_inner();
+                outputs.validator_trust = validator_trust.into_inner();
+                outputs.incentives = incentives.into_inner();
+                outputs.ranks = ranks.into_inner();
+                outputs.trust = trust.into_inner();
+
+                self.phase = YumaPhase::Bonds;
+                Ok(None)
+            }
+            YumaPhase::Bonds => {
+                let BondsAndDividends {
+                    ema_bonds,
+                    dividends,
+                } = compute_bonds_and_dividends_yuma(
+                    &inputs.params,
+                    &inputs.modules,
+                    &ConsensusVal::unchecked_from_inner(outputs.consensus.clone()),
+                    &WeightsVal::unchecked_from_inner(mem::take(&mut self.weights)),
+                    &ActiveStake::unchecked_from_inner(inputs.active_stake.clone()),
+                    &IncentivesVal::unchecked_from_inner(outputs.incentives.clone()),
+                )
+                .ok_or(EmissionError::Other("bonds storage is broken"))?;
+
+                outputs.ema_bonds = ema_bonds;
+                outputs.dividends = dividends.into_inner();
+
+                self.phase = YumaPhase::Apply;
+                Ok(None)
+            }
+            YumaPhase::Apply => process_consensus_output::<T>(
+                &inputs.params,
+                &inputs.modules,
+                StakeVal::unchecked_from_inner(mem::take(&mut inputs.stake)),
+                ActiveStake::unchecked_from_inner(mem::take(&mut inputs.active_stake)),
+                ConsensusVal::unchecked_from_inner(mem::take(&mut outputs.consensus)),
+                IncentivesVal::unchecked_from_inner(mem::take(&mut outputs.incentives)),
+                DividendsVal::unchecked_from_inner(mem::take(&mut outputs.dividends)),
+                TrustVal::unchecked_from_inner(mem::take(&mut outputs.trust)),
+                RanksVal::unchecked_from_inner(mem::take(&mut outputs.ranks)),
+                mem::take(&mut inputs.active),
+                ValidatorTrustVal::unchecked_from_inner(mem::take(&mut outputs.validator_trust)),
+                mem::take(&mut inputs.new_permits),
+                &outputs.ema_bonds,
+            )
+            .map(Some),
+        }
     }
 }
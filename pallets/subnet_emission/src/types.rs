@@ -1,4 +1,5 @@
 use super::*;
+use frame_support::{traits::ConstU32, BoundedVec};
 
 pub type PublicKey = (Vec<u8>, Vec<u8>);
 pub type BlockWeights = (u64, Vec<(u16, Vec<(u16, u16)>, Vec<u8>)>);
@@ -18,3 +19,76 @@ where
     pub last_keep_alive: u64,
     pub rotating_from: Option<T::AccountId>,
 }
+
+/// Committee weights are encrypted to instead of a single decryption node. The committee key is
+/// split among its members, any `threshold` of which have to submit their decryption shares
+/// before the subnet's assigned node can combine them into the decrypted weights.
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, PartialEq, Eq)]
+pub struct DecryptionCommitteeInfo<AccountId> {
+    pub public_key: PublicKey,
+    pub threshold: u16,
+    /// Members with the index of their key share
+    pub members: Vec<(AccountId, u16)>,
+}
+
+/// Length of a decryption share, the partially decrypted point with its proof
+pub const DECRYPTION_SHARE_LEN: u32 = 96;
+
+/// Most decryption shares a committee member submits at once, the rest follow in later blocks
+pub const MAX_DECRYPTION_SHARES: u32 = 256;
+
+pub type DecryptionShare = BoundedVec<u8, ConstU32<DECRYPTION_SHARE_LEN>>;
+
+/// Decryption shares of a committee member, as (block, uid, share) of the encrypted weights
+pub type DecryptionShareSet =
+    BoundedVec<(u64, u16, DecryptionShare), ConstU32<MAX_DECRYPTION_SHARES>>;
+
+/// Tokens a decryption node operator bonded to back its node, slashed to the DAO treasury when
+/// the node misbehaves.
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, PartialEq, Eq, Default)]
+pub struct DecryptionNodeBond {
+    /// Bond backing the node while it is an authority
+    pub active: u64,
+    /// Bond on its way back to the operator after it left, still slashable until released
+    pub unbonding: u64,
+    /// Block at which the unbonding amount is returned
+    pub release_block: Option<u64>,
+}
+
+#[derive(Clone, Copy, Encode, Decode, TypeInfo, Debug, PartialEq, Eq)]
+pub enum DecryptionNodeFaultKind {
+    /// The node did not send the decrypted weights back before it was banned
+    MissedDecryption,
+}
+
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, PartialEq, Eq)]
+pub struct DecryptionNodeFault {
+    pub subnet_id: u16,
+    pub block: u64,
+    pub kind: DecryptionNodeFaultKind,
+    /// Amount taken from the bond
+    pub slashed: u64,
+}
+
+/// A Yuma epoch whose phases run across several blocks, one phase per block. Its inputs and
+/// weights are stored apart, so each phase only writes back what it computed.
+#[derive(Clone, Encode, Decode, TypeInfo, Debug)]
+pub struct MultiBlockEpoch {
+    /// The pending emission drained when the epoch started, returned if the epoch is dropped
+    pub emission_to_drain: u64,
+    pub phase: crate::subnet_consensus::yuma::YumaPhase,
+    pub outputs: crate::subnet_consensus::yuma::YumaOutputs,
+}
+
+/// Summary of a finished subnet epoch, kept in the `EpochHistory` ring buffer.
+#[derive(Clone, Encode, Decode, TypeInfo, Debug, PartialEq, Eq)]
+pub struct EpochSummary<AccountId> {
+    pub block: u64,
+    /// Emission distributed by the epoch, including the founder share
+    pub total_emission: u64,
+    /// Modules with the highest emission, in descending order
+    pub top_earners: Vec<(AccountId, u64)>,
+    pub validator_count: u16,
+    /// Blake2-256 hash of the SCALE encoded consensus vector
+    pub consensus_hash: [u8; 32],
+}
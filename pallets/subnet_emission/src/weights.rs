@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ubuntu-64gb-hil-2`, CPU: `AMD EPYC-Milan Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("specs/local.json")`, DB CACHE: `1024`
+//!
+//! NOTE: the functions marked `ESTIMATE` were written by hand and are not output of the
+//! benchmark CLI. Regenerate this file with `make run-benchmarking` to replace them.
 
 // Executed Command:
 // ./target/release/node-subspace
@@ -39,6 +42,16 @@ pub trait WeightInfo {
 	fn set_weights_encrypted() -> Weight;
 	fn delegate_weight_control() -> Weight;
 	fn remove_weight_control() -> Weight;
+	fn on_initialize(s: u32, ) -> Weight;
+	fn run_epoch_yuma(m: u32, ) -> Weight;
+	fn run_epoch_encrypted(m: u32, ) -> Weight;
+	fn run_epoch_linear(m: u32, ) -> Weight;
+	fn run_epoch_treasury() -> Weight;
+	fn run_epoch_yuma_phase(m: u32, ) -> Weight;
+	fn run_epoch_yuma_start(m: u32, ) -> Weight;
+	fn subnet_pricing(s: u32, v: u32, ) -> Weight;
+	fn ban_decryption_node(s: u32, m: u32, ) -> Weight;
+	fn release_decryption_node_bond() -> Weight;
 }
 
 /// Weights for `pallet_subnet_emission` using the Substrate node and recommended hardware.
@@ -138,6 +151,287 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetDecryptionData` (r:129 w:128)
+	/// Proof: `SubnetEmissionModule::SubnetDecryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::WeightEncryptionData` (r:128 w:0)
+	/// Proof: `SubnetEmissionModule::WeightEncryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::UseWeightsEncryption` (r:256 w:0)
+	/// Proof: `SubspaceModule::UseWeightsEncryption` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodes` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::BannedDecryptionNodes` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::BannedDecryptionNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeCursor` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBanQueue` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBanQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:128 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:128 w:128)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DeferredEpochs` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DeferredEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	fn on_initialize(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1318 + s * (412 ±0)`
+		//  Estimated: `4783 + s * (4953 ±0)`
+		// Minimum execution time: 96_218_000 picoseconds.
+		Weight::from_parts(92_714_000, 4783)
+			// Standard Error: 1_921_000
+			.saturating_add(Weight::from_parts(38_420_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((11_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 4953).saturating_mul(s.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:421 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:421 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalStake` (r:1 w:1)
+	/// Proof: `SubspaceModule::TotalStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:842 w:420)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Bonds` (r:0 w:1)
+	/// Proof: `SubspaceModule::Bonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 420]`.
+	fn run_epoch_yuma(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3120 + m * (412 ±0)`
+		//  Estimated: `8940 + m * (5774 ±0)`
+		// Minimum execution time: 402_120_000 picoseconds.
+		Weight::from_parts(389_640_000, 8940)
+			// Standard Error: 61_204
+			.saturating_add(Weight::from_parts(1_214_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(41_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5774).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::WeightEncryptionData` (r:421 w:0)
+	/// Proof: `SubnetEmissionModule::WeightEncryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::ConsensusParameters` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::ConsensusParameters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetDecryptionData` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::SubnetDecryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 420]`.
+	fn run_epoch_encrypted(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2804 + m * (1220 ±0)`
+		//  Estimated: `7410 + m * (3695 ±0)`
+		// Minimum execution time: 119_334_000 picoseconds.
+		Weight::from_parts(114_027_000, 7410)
+			// Standard Error: 23_877
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(38_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 3695).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:421 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:421 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:842 w:420)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 420]`.
+	fn run_epoch_linear(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2950 + m * (398 ±0)`
+		//  Estimated: `8120 + m * (5512 ±0)`
+		// Minimum execution time: 288_090_000 picoseconds.
+		Weight::from_parts(279_510_000, 8120)
+			// Standard Error: 48_310
+			.saturating_add(Weight::from_parts(986_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5512).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn run_epoch_treasury() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3593`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(42_350_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::YumaEpochCursor` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochInputs` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::YumaEpochInputs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochWeights` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 4096]`.
+	fn run_epoch_yuma_phase(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1406 + m * (1604 ±0)`
+		//  Estimated: `4871 + m * (1604 ±0)`
+		// Minimum execution time: 142_310_000 picoseconds.
+		Weight::from_parts(136_940_000, 4871)
+			// Standard Error: 21_900
+			.saturating_add(Weight::from_parts(438_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 1604).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:4096 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:4096 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:8192 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochInputs` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochInputs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochWeights` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochCursor` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 4096]`.
+	fn run_epoch_yuma_start(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3120 + m * (412 ±0)`
+		//  Estimated: `8940 + m * (5774 ±0)`
+		// Minimum execution time: 214_380_000 picoseconds.
+		Weight::from_parts(205_910_000, 8940)
+			// Standard Error: 34_700
+			.saturating_add(Weight::from_parts(694_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(37_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 5774).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:129 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:257 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:256 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:512 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:257 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetEmission` (r:0 w:128)
+	/// Proof: `SubnetEmissionModule::SubnetEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn subnet_pricing(s: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187 + s * (96 ±0) + v * (418 ±0)`
+		//  Estimated: `4652 + s * (2571 ±0) + v * (5126 ±0)`
+		// Minimum execution time: 64_302_000 picoseconds.
+		Weight::from_parts(41_885_000, 4652)
+			// Standard Error: 347_600
+			.saturating_add(Weight::from_parts(6_952_000, 0).saturating_mul(s.into()))
+			// Standard Error: 455_650
+			.saturating_add(Weight::from_parts(9_113_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(v.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 5126).saturating_mul(v.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::DecryptionCommittee` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionCommittee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::ConsensusParameters` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::ConsensusParameters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionShares` (r:420 w:420)
+	/// Proof: `SubnetEmissionModule::DecryptionShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetDecryptionData` (r:129 w:129)
+	/// Proof: `SubnetEmissionModule::SubnetDecryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBanQueue` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBanQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::WeightEncryptionData` (r:0 w:420)
+	/// Proof: `SubnetEmissionModule::WeightEncryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodes` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::BannedDecryptionNodes` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::BannedDecryptionNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::UseWeightsEncryption` (r:128 w:0)
+	/// Proof: `SubspaceModule::UseWeightsEncryption` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeFaults` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeFaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	/// The range of component `m` is `[1, 420]`.
+	fn ban_decryption_node(s: u32, m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2240 + s * (146 ±0) + m * (1388 ±0)`
+		//  Estimated: `6115 + s * (2621 ±0) + m * (3863 ±0)`
+		// Minimum execution time: 148_930_000 picoseconds.
+		Weight::from_parts(121_407_000, 6115)
+			// Standard Error: 159_200
+			.saturating_add(Weight::from_parts(3_184_000, 0).saturating_mul(s.into()))
+			// Standard Error: 91_300
+			.saturating_add(Weight::from_parts(1_826_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(12_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2621).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 3863).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn release_decryption_node_bond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241`
+		//  Estimated: `3593`
+		// Minimum execution time: 37_164_000 picoseconds.
+		Weight::from_parts(38_210_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -236,4 +530,285 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetDecryptionData` (r:129 w:128)
+	/// Proof: `SubnetEmissionModule::SubnetDecryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::WeightEncryptionData` (r:128 w:0)
+	/// Proof: `SubnetEmissionModule::WeightEncryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::UseWeightsEncryption` (r:256 w:0)
+	/// Proof: `SubspaceModule::UseWeightsEncryption` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodes` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::BannedDecryptionNodes` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::BannedDecryptionNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeCursor` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeCursor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBanQueue` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBanQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:128 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:128 w:128)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DeferredEpochs` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DeferredEpochs` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	fn on_initialize(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1318 + s * (412 ±0)`
+		//  Estimated: `4783 + s * (4953 ±0)`
+		// Minimum execution time: 96_218_000 picoseconds.
+		Weight::from_parts(92_714_000, 4783)
+			// Standard Error: 1_921_000
+			.saturating_add(Weight::from_parts(38_420_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((11_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 4953).saturating_mul(s.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:421 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:421 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::TotalStake` (r:1 w:1)
+	/// Proof: `SubspaceModule::TotalStake` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:842 w:420)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Bonds` (r:0 w:1)
+	/// Proof: `SubspaceModule::Bonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 420]`.
+	fn run_epoch_yuma(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3120 + m * (412 ±0)`
+		//  Estimated: `8940 + m * (5774 ±0)`
+		// Minimum execution time: 402_120_000 picoseconds.
+		Weight::from_parts(389_640_000, 8940)
+			// Standard Error: 61_204
+			.saturating_add(Weight::from_parts(1_214_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(41_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5774).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::WeightEncryptionData` (r:421 w:0)
+	/// Proof: `SubnetEmissionModule::WeightEncryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::ConsensusParameters` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::ConsensusParameters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetDecryptionData` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::SubnetDecryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 420]`.
+	fn run_epoch_encrypted(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2804 + m * (1220 ±0)`
+		//  Estimated: `7410 + m * (3695 ±0)`
+		// Minimum execution time: 119_334_000 picoseconds.
+		Weight::from_parts(114_027_000, 7410)
+			// Standard Error: 23_877
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(38_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 3695).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:421 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:421 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:842 w:420)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 420]`.
+	fn run_epoch_linear(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2950 + m * (398 ±0)`
+		//  Estimated: `8120 + m * (5512 ±0)`
+		// Minimum execution time: 288_090_000 picoseconds.
+		Weight::from_parts(279_510_000, 8120)
+			// Standard Error: 48_310
+			.saturating_add(Weight::from_parts(986_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 5512).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn run_epoch_treasury() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3593`
+		// Minimum execution time: 41_207_000 picoseconds.
+		Weight::from_parts(42_350_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::YumaEpochCursor` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:1 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochInputs` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::YumaEpochInputs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochWeights` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 4096]`.
+	fn run_epoch_yuma_phase(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1406 + m * (1604 ±0)`
+		//  Estimated: `4871 + m * (1604 ±0)`
+		// Minimum execution time: 142_310_000 picoseconds.
+		Weight::from_parts(136_940_000, 4871)
+			// Standard Error: 21_900
+			.saturating_add(Weight::from_parts(438_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 1604).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:4096 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:4096 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:8192 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochInputs` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochInputs` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochWeights` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochWeights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::YumaEpochCursor` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::YumaEpochCursor` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `m` is `[1, 4096]`.
+	fn run_epoch_yuma_start(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3120 + m * (412 ±0)`
+		//  Estimated: `8940 + m * (5774 ±0)`
+		// Minimum execution time: 214_380_000 picoseconds.
+		Weight::from_parts(205_910_000, 8940)
+			// Standard Error: 34_700
+			.saturating_add(Weight::from_parts(694_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(37_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(Weight::from_parts(0, 5774).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::SubnetConsensusType` (r:129 w:0)
+	/// Proof: `SubnetEmissionModule::SubnetConsensusType` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Uids` (r:257 w:0)
+	/// Proof: `SubspaceModule::Uids` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Keys` (r:256 w:0)
+	/// Proof: `SubspaceModule::Keys` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::StakeFrom` (r:512 w:0)
+	/// Proof: `SubspaceModule::StakeFrom` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::Weights` (r:257 w:0)
+	/// Proof: `SubnetEmissionModule::Weights` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetEmission` (r:0 w:128)
+	/// Proof: `SubnetEmissionModule::SubnetEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	/// The range of component `v` is `[1, 256]`.
+	fn subnet_pricing(s: u32, v: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1187 + s * (96 ±0) + v * (418 ±0)`
+		//  Estimated: `4652 + s * (2571 ±0) + v * (5126 ±0)`
+		// Minimum execution time: 64_302_000 picoseconds.
+		Weight::from_parts(41_885_000, 4652)
+			// Standard Error: 347_600
+			.saturating_add(Weight::from_parts(6_952_000, 0).saturating_mul(s.into()))
+			// Standard Error: 455_650
+			.saturating_add(Weight::from_parts(9_113_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(v.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2571).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 5126).saturating_mul(v.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubnetEmissionModule::DecryptionCommittee` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionCommittee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::ConsensusParameters` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::ConsensusParameters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionShares` (r:420 w:420)
+	/// Proof: `SubnetEmissionModule::DecryptionShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::SubnetDecryptionData` (r:129 w:129)
+	/// Proof: `SubnetEmissionModule::SubnetDecryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBanQueue` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBanQueue` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::PendingEmission` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::PendingEmission` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::WeightEncryptionData` (r:0 w:420)
+	/// Proof: `SubnetEmissionModule::WeightEncryptionData` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodes` (r:1 w:0)
+	/// Proof: `SubnetEmissionModule::DecryptionNodes` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::BannedDecryptionNodes` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::BannedDecryptionNodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::UseWeightsEncryption` (r:128 w:0)
+	/// Proof: `SubspaceModule::UseWeightsEncryption` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `GovernanceModule::DaoTreasuryAddress` (r:1 w:0)
+	/// Proof: `GovernanceModule::DaoTreasuryAddress` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeFaults` (r:1 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeFaults` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	/// The range of component `m` is `[1, 420]`.
+	fn ban_decryption_node(s: u32, m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2240 + s * (146 ±0) + m * (1388 ±0)`
+		//  Estimated: `6115 + s * (2621 ±0) + m * (3863 ±0)`
+		// Minimum execution time: 148_930_000 picoseconds.
+		Weight::from_parts(121_407_000, 6115)
+			// Standard Error: 159_200
+			.saturating_add(Weight::from_parts(3_184_000, 0).saturating_mul(s.into()))
+			// Standard Error: 91_300
+			.saturating_add(Weight::from_parts(1_826_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2621).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 3863).saturating_mul(m.into()))
+	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `SubnetEmissionModule::DecryptionNodeBonds` (r:0 w:1)
+	/// Proof: `SubnetEmissionModule::DecryptionNodeBonds` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn release_decryption_node_bond() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `241`
+		//  Estimated: `3593`
+		// Minimum execution time: 37_164_000 picoseconds.
+		Weight::from_parts(38_210_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
 }
\ No newline at end of file
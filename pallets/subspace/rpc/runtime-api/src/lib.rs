@@ -41,8 +41,85 @@ pub struct ModuleInfo {
     pub stats: ModuleStats,
 }
 
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct EpochSummary {
+    /// Sequence number of the epoch on its subnet, starting at 0
+    pub epoch: u32,
+    pub block: u64,
+    pub total_emission: u64,
+    /// Modules with the highest emission, in descending order
+    pub top_earners: Vec<(AccountId, u64)>,
+    pub validator_count: u16,
+    pub consensus_hash: [u8; 32],
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, TypeInfo, Serialize, Deserialize)]
+pub enum DecryptionNodeFaultKind {
+    /// The node did not send the decrypted weights of a subnet in time
+    MissedDecryption,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct DecryptionNodeFault {
+    pub subnet_id: u16,
+    pub block: u64,
+    pub kind: DecryptionNodeFaultKind,
+    /// Amount slashed to the DAO treasury
+    pub slashed: u64,
+}
+
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct DecryptionNodeInfo {
+    pub node_id: AccountId,
+    pub is_authority: bool,
+    pub active_bond: u64,
+    pub unbonding: u64,
+    /// Block the unbonding amount is returned at
+    pub release_block: Option<u64>,
+    /// Most recent faults, oldest first
+    pub faults: Vec<DecryptionNodeFault>,
+}
+
+/// Copier simulation of a subnet's encrypted epochs. Fixed-point values are scaled by 10^9.
+///
+/// The encrypted weights are unknown on-chain, so validators are simulated with the last weights
+/// they revealed. The result approximates the decision of the offworker, which simulates the
+/// decrypted weights, and can reveal at a different block.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Debug, TypeInfo, Serialize, Deserialize)]
+pub struct CopierSimulation {
+    /// Stake the simulated copier holds
+    pub copier_stake: u64,
+    pub cumulative_copier_divs: i64,
+    pub cumulative_avg_delegate_divs: i64,
+    pub copier_margin: i64,
+    /// Negative once copying is irrational
+    pub delta: i64,
+    /// Whether copying turns irrational within the simulated epochs, estimated
+    pub is_copying_irrational: bool,
+    pub simulated_epochs: u32,
+    /// Block of the oldest epoch still encrypted
+    pub creation_block: u64,
+    pub max_encryption_period: u64,
+    /// Estimated block the weights are revealed at
+    pub projected_reveal_block: u64,
+}
+
 sp_api::decl_runtime_apis! {
+    #[api_version(2)]
     pub trait SubspaceRuntimeApi {
         fn get_module_info(key: AccountId, netuid: u16) -> ModuleInfo;
+
+        fn get_epoch_history(netuid: u16, from: u32, count: u32) -> Vec<EpochSummary>;
+
+        /// Emission per block the active emission curve yields at each of the given
+        /// total issuance levels.
+        fn forecast_emission(issuance_levels: Vec<u64>) -> Vec<u64>;
+
+        /// Bond and fault history of the decryption nodes.
+        fn get_decryption_nodes() -> Vec<DecryptionNodeInfo>;
+
+        /// Copier simulation of the subnet's encrypted epochs, `None` if none are encrypted.
+        /// Approximated with the last revealed weights, see [`CopierSimulation`].
+        fn get_copier_simulation(netuid: u16) -> Option<CopierSimulation>;
     }
 }
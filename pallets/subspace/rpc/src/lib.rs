@@ -10,7 +10,7 @@ use sp_runtime::{
     MultiSignature,
 };
 use std::sync::Arc;
-use subspace_runtime_api::ModuleInfo;
+use subspace_runtime_api::{CopierSimulation, EpochSummary, ModuleInfo};
 pub use subspace_runtime_api::SubspaceRuntimeApi;
 
 type Signature = MultiSignature;
@@ -30,6 +30,24 @@ pub trait SubspaceApi<BlockHash> {
         netuid: u16,
         at: Option<BlockHash>,
     ) -> RpcResult<ModuleInfo>;
+
+    #[method(name = "subspace_getEpochHistory")]
+    fn get_epoch_history(
+        &self,
+        netuid: u16,
+        from: u32,
+        count: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<EpochSummary>>;
+
+    /// Copier simulation of the subnet's encrypted epochs. It is an approximation based on the
+    /// last revealed weights, not the decision the offworker takes on the decrypted ones.
+    #[method(name = "subspace_getCopierSimulation")]
+    fn get_copier_simulation(
+        &self,
+        netuid: u16,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<CopierSimulation>>;
 }
 
 pub struct SubspacePallet<C, Block> {
@@ -64,6 +82,30 @@ where
         let value = api.get_module_info(at, key, netuid).map_err(runtime_error_into_rpc_err);
         Ok(value.unwrap())
     }
+
+    fn get_epoch_history(
+        &self,
+        netuid: u16,
+        from: u32,
+        count: u32,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<EpochSummary>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_epoch_history(at, netuid, from, count).map_err(runtime_error_into_rpc_err)
+    }
+
+    fn get_copier_simulation(
+        &self,
+        netuid: u16,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Option<CopierSimulation>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_copier_simulation(at, netuid).map_err(runtime_error_into_rpc_err)
+    }
 }
 
 const RUNTIME_ERROR: i32 = 1;
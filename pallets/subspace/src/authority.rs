@@ -3,7 +3,8 @@ use frame_system::{Config, Pallet};
 #[derive(sp_core::Decode, sp_core::Encode, scale_info::TypeInfo)]
 pub struct AuthorityNode {
     pub id: u32,
-    pub encryption_key: (Vec<u8>, Vec<u8>), // (n, e) from RSA 512
+    // (n, e) of an RSA key, or ("x25519", key), see `ow_extensions::envelope`
+    pub encryption_key: (Vec<u8>, Vec<u8>),
 }
 
 #[derive(sp_core::Decode, sp_core::Encode, scale_info::TypeInfo)]
@@ -2,7 +2,8 @@
 
 use crate::{Pallet as SubspaceMod, *};
 use frame_benchmarking::{account, benchmarks};
-use frame_system::RawOrigin;
+use frame_support::traits::Hooks;
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 pub use pallet::*;
 use sp_arithmetic::per_things::Percent;
 use sp_std::vec::Vec;
@@ -224,7 +225,10 @@ benchmarks! {
         params.max_allowed_validators,
         params.use_weights_encryption,
         params.copier_margin,
-        params.max_encryption_period
+        params.max_encryption_period,
+        params.copier_stake_fraction,
+        params.copier_bonds_averaging,
+        params.copier_delegation_fee
     )
     // 11
 
@@ -238,4 +242,27 @@ benchmarks! {
         );
     }: register_subnet(RawOrigin::Signed(key.clone()), "testnet".as_bytes().to_vec(), Some(b"testmetadata".to_vec()))
 
+    // 13
+    on_initialize {
+        let s in 1 .. 128;
+
+        MaxRegistrationsPerBlock::<T>::set(u16::MAX);
+        for i in 0..s {
+            let key: T::AccountId = account("SubnetKey", i, 0);
+            SubspaceMod::<T>::add_balance_to_account(
+                &key,
+                SubspaceMod::<T>::u64_to_balance(SubnetBurn::<T>::get().saturating_mul(2)).unwrap(),
+            );
+            SubnetRegistrationsThisInterval::<T>::set(0);
+
+            let mut name = b"subnet".to_vec();
+            name.extend(i.to_le_bytes());
+            SubspaceMod::<T>::register_subnet(RawOrigin::Signed(key).into(), name, None)?;
+        }
+
+        // Every subnet adjusts its registration parameters on this block
+        let block: BlockNumberFor<T> = 0u32.into();
+    }: {
+        SubspaceMod::<T>::on_initialize(block);
+    }
 }
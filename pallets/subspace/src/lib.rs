@@ -53,7 +53,7 @@ pub use crate::params::{
     burn::{BurnType, GeneralBurnConfiguration},
     global::GlobalParams,
     module::{ModuleChangeset, ModuleParams},
-    subnet::{DefaultSubnetParams, SubnetChangeset, SubnetParams},
+    subnet::{BondsAveraging, DefaultSubnetParams, SubnetChangeset, SubnetParams},
 };
 use selections::{config, dispatches, errors, events, genesis, hooks};
 
@@ -129,6 +129,9 @@ pub mod pallet {
             RegistrationsThisInterval,
             MaxEncryptionPeriod: Option<u64> = Some(10_800),
             CopierMargin: I64F64 = I64F64::from_num(0),
+            CopierStakeFraction: Percent = Percent::from_percent(10),
+            CopierBondsAveraging,
+            CopierDelegationFee,
             UseWeightsEncryption,
             AlphaValues: (u16, u16) = (45875, 58982),
             MinValidatorStake,
@@ -139,6 +142,7 @@ pub mod pallet {
             MaxAllowedWeights: u16 = 420,
             Tempo: u16 = 100,
             FounderShare,
+            FounderShareToTreasury,
             Incentive,
             Trust,
             Dividends,
@@ -273,6 +277,22 @@ pub mod pallet {
     #[pallet::storage]
     pub type UseWeightsEncryption<T: Config> = StorageMap<_, Identity, u16, bool, ValueQuery>;
 
+    /// Share of the validators' stake the simulated copier holds.
+    #[pallet::storage]
+    pub type CopierStakeFraction<T: Config> =
+        StorageMap<_, Identity, u16, Percent, ValueQuery, CopierStakeFractionDefaultValue>;
+
+    /// How the bonds of the simulated copier are derived.
+    #[pallet::storage]
+    pub type CopierBondsAveraging<T: Config> =
+        StorageMap<_, Identity, u16, BondsAveraging, ValueQuery>;
+
+    /// Delegation fee assumed for the delegators the copier is compared to, the minimum stake
+    /// delegation fee if not set.
+    #[pallet::storage]
+    pub type CopierDelegationFee<T: Config> =
+        StorageMap<_, Identity, u16, Option<Percent>, ValueQuery>;
+
     #[pallet::storage]
     pub type AlphaValues<T: Config> =
         StorageMap<_, Identity, u16, (u16, u16), ValueQuery, AlphaValuesDefaultValue>;
@@ -308,6 +328,11 @@ pub mod pallet {
     pub type FounderShare<T: Config> =
         StorageMap<_, Identity, u16, u16, ValueQuery, DefaultFounderShare<T>>;
 
+    /// Whether the founder share of the subnet is paid into the subnet treasury instead of the
+    /// founder key
+    #[pallet::storage]
+    pub type FounderShareToTreasury<T> = StorageMap<_, Identity, u16, bool, ValueQuery>;
+
     // --- Module ---
 
     #[pallet::storage]
@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 // ? Warning keeps poping up that this is unused, when it is not
 use num_traits::float::Float;
-use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, vec, vec::Vec};
+use sp_std::{cmp::Ordering, collections::btree_map::BTreeMap, ops::Range, vec, vec::Vec};
 use substrate_fixed::{
     transcendental::{exp, ln},
     types::{I32F32, I64F64},
@@ -273,6 +273,17 @@ pub fn weighted_median_col_sparse(
     score: &[Vec<(u16, I32F32)>],
     columns: u16,
     majority: I32F32,
+) -> Vec<I32F32> {
+    weighted_median_col_sparse_range(stake, score, 0..columns, majority)
+}
+
+/// Stake-weighted median of the given range of columns only, so the medians of a large matrix
+/// can be computed in chunks.
+pub fn weighted_median_col_sparse_range(
+    stake: &[I32F32],
+    score: &[Vec<(u16, I32F32)>],
+    columns: Range<u16>,
+    majority: I32F32,
 ) -> Vec<I32F32> {
     let rows = stake.len();
     let zero: I32F32 = I32F32::from_num(0);
@@ -282,9 +293,10 @@ pub fn weighted_median_col_sparse(
     let stake_sum: I32F32 = use_stake.iter().sum();
     let stake_idx: Vec<usize> = (0..use_stake.len()).collect();
     let minority: I32F32 = stake_sum.saturating_sub(majority);
-    let mut use_score: Vec<Vec<I32F32>> = vec![vec![zero; use_stake.len()]; columns as usize];
+    let column_count = columns.len();
+    let mut use_score: Vec<Vec<I32F32>> = vec![vec![zero; use_stake.len()]; column_count];
 
-    let mut median: Vec<I32F32> = vec![zero; columns as usize];
+    let mut median: Vec<I32F32> = vec![zero; column_count];
     let mut k: usize = 0;
 
     for r in 0..rows {
@@ -298,7 +310,11 @@ pub fn weighted_median_col_sparse(
             continue;
         }
         for (c, val) in score_r.iter() {
-            let Some(use_score_c) = use_score.get_mut(*c as usize) else {
+            if !columns.contains(c) {
+                continue;
+            }
+            let Some(use_score_c) = use_score.get_mut(c.saturating_sub(columns.start) as usize)
+            else {
                 continue;
             };
             let Some(use_score_c_k) = use_score_c.get_mut(k) else {
@@ -309,7 +325,7 @@ pub fn weighted_median_col_sparse(
         k = k.saturating_add(1);
     }
 
-    for c in 0..columns as usize {
+    for c in 0..column_count {
         let Some(median_c) = median.get_mut(c) else {
             continue;
         };
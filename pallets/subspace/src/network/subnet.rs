@@ -2,7 +2,7 @@ use crate::*;
 use frame_support::pallet_prelude::DispatchResult;
 use pallet_governance_api::GovernanceApi;
 use pallet_subnet_emission_api::{SubnetConsensus, SubnetEmissionApi};
-use sp_runtime::DispatchError;
+use sp_runtime::{traits::AccountIdConversion, DispatchError};
 use substrate_fixed::types::I64F64;
 
 impl<T: Config> Pallet<T> {
@@ -81,6 +81,23 @@ impl<T: Config> Pallet<T> {
         // unregistered with "active" stake storage or "active" delegation fee storage.
         Self::clear_subnet_only_accounts_data(netuid);
 
+        // --- Return The Subnet Treasury Funds To The DAO Treasury ---
+
+        let treasury = Self::subnet_treasury_account(netuid);
+        let treasury_balance = Self::get_balance_u64(&treasury);
+        if treasury_balance > 0 {
+            if let Err(err) = T::Currency::transfer(
+                &treasury,
+                &T::get_dao_treasury_address(),
+                treasury_balance,
+                ExistenceRequirement::AllowDeath,
+            ) {
+                log::error!(
+                    "failed to return the treasury of subnet {netuid} to the dao treasury: {err:?}"
+                );
+            }
+        }
+
         // --- Delete Subnet Includes Storage For All Pallets ---
 
         Self::clear_subnet_includes(netuid);
@@ -117,6 +134,22 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Only enacted through subnet proposals, the founder can not redirect the share on its own.
+    pub fn set_founder_share_routing(netuid: u16, to_treasury: bool) -> DispatchResult {
+        ensure!(
+            SubnetNames::<T>::contains_key(netuid),
+            Error::<T>::NetuidDoesNotExist
+        );
+
+        FounderShareToTreasury::<T>::insert(netuid, to_treasury);
+        Self::deposit_event(Event::FounderShareRoutingUpdated {
+            netuid,
+            to_treasury,
+        });
+
+        Ok(())
+    }
+
     // --- Setters ---
 
     pub fn set_max_allowed_uids(netuid: u16, max_allowed_uids: u16) -> DispatchResult {
@@ -171,6 +204,21 @@ impl<T: Config> Pallet<T> {
             .expect("blockchain will not exceed 2^64 blocks; QED.")
     }
 
+    /// The account holding the subnet treasury. It has no private key, so its funds can only
+    /// be spent through the subnet governance.
+    pub fn subnet_treasury_account(netuid: u16) -> T::AccountId {
+        T::PalletId::get().into_sub_account_truncating(("subnet", netuid))
+    }
+
+    /// The account receiving the founder share of the subnet emission.
+    pub fn founder_emission_recipient(netuid: u16) -> T::AccountId {
+        if FounderShareToTreasury::<T>::get(netuid) {
+            Self::subnet_treasury_account(netuid)
+        } else {
+            Founder::<T>::get(netuid)
+        }
+    }
+
     // --- Util ---
     pub fn calculate_founder_emission(netuid: u16, mut token_emission: u64) -> (u64, u64) {
         let founder_share: u16 = FounderShare::<T>::get(netuid).min(100);
@@ -3,7 +3,7 @@ use crate::*;
 use frame_support::pallet_prelude::DispatchResult;
 use pallet_governance_api::VoteMode;
 use sp_core::Get;
-use sp_runtime::{BoundedVec, DispatchError};
+use sp_runtime::{BoundedVec, DispatchError, Percent};
 use sp_std::ops::{Deref, DerefMut};
 
 use frame_support::pallet_prelude::*;
@@ -37,6 +37,22 @@ pub struct SubnetParams<T: Config> {
     pub use_weights_encryption: bool,
     pub copier_margin: I64F64,
     pub max_encryption_period: Option<u64>,
+    // --- Copier Simulation ---
+    pub copier_stake_fraction: Percent,
+    pub copier_bonds_averaging: BondsAveraging,
+    pub copier_delegation_fee: Option<Percent>,
+}
+
+/// How the bonds the simulated copier starts with are derived from the validators' bonds.
+#[derive(Decode, Encode, PartialEq, Eq, Clone, Copy, Debug, Default, TypeInfo, MaxEncodedLen)]
+pub enum BondsAveraging {
+    /// Average of the validators' bonds weighted by their stake
+    #[default]
+    StakeWeighted,
+    /// Plain average of the validators' bonds
+    Uniform,
+    /// The copier starts without any bonds
+    Empty,
 }
 
 pub struct DefaultSubnetParams<T: Config>(sp_std::marker::PhantomData<((), T)>);
@@ -73,6 +89,11 @@ impl<T: Config> DefaultSubnetParams<T> {
             use_weights_encryption: T::DefaultUseWeightsEncryption::get(),
             copier_margin: CopierMarginDefaultValue::get(),
             max_encryption_period: MaxEncryptionPeriodDefaultValue::get(),
+
+            // --- Copier Simulation ---
+            copier_stake_fraction: CopierStakeFractionDefaultValue::get(),
+            copier_bonds_averaging: BondsAveraging::default(),
+            copier_delegation_fee: None,
         }
     }
 }
@@ -114,6 +135,7 @@ const MAX_COPIER_MARGIN: f64 = 1.0;
 const MIN_ALLOWED_VALIDATORS: u16 = 10;
 const MIN_SET_WEIGHT_CALLS: u16 = 1;
 const MAX_ENCRYPTION_DURATION: u64 = 10_800 * 2; // 2 days
+const MAX_COPIER_STAKE_FRACTION: Percent = Percent::from_percent(50);
 
 impl<T: Config> ValidatedSubnetParams<T> {
     pub fn new(params: SubnetParams<T>, netuid: Option<u16>) -> Result<Self, DispatchError> {
@@ -154,6 +176,9 @@ impl<T: Config> ValidatedSubnetParams<T> {
             use_weights_encryption: _, // complete freedom
             copier_margin,
             max_encryption_period,
+            copier_stake_fraction,
+            copier_bonds_averaging: _, // complete freedom
+            copier_delegation_fee,
         } = params;
 
         // Validate min/max weights relationship
@@ -224,6 +249,19 @@ impl<T: Config> ValidatedSubnetParams<T> {
             Error::<T>::InvalidCopierMargin
         );
 
+        ensure!(
+            copier_stake_fraction.deconstruct() > 0
+                && *copier_stake_fraction <= MAX_COPIER_STAKE_FRACTION,
+            Error::<T>::InvalidCopierStakeFraction
+        );
+
+        if let Some(fee) = copier_delegation_fee {
+            ensure!(
+                *fee >= MinFees::<T>::get().stake_delegation_fee,
+                Error::<T>::InvalidCopierDelegationFee
+            );
+        }
+
         if let Some(max_validators) = max_allowed_validators {
             ensure!(
                 *max_validators >= MIN_ALLOWED_VALIDATORS,
@@ -306,6 +344,9 @@ impl<T: Config> SubnetChangeset<T> {
             use_weights_encryption,
             copier_margin,
             max_encryption_period,
+            copier_stake_fraction,
+            copier_bonds_averaging,
+            copier_delegation_fee,
         } = self.params.into_inner();
 
         Pallet::<T>::set_max_allowed_uids(netuid, max_allowed_uids)?;
@@ -336,6 +377,9 @@ impl<T: Config> SubnetChangeset<T> {
         MaxEncryptionPeriod::<T>::insert(netuid, max_encryption_period);
         UseWeightsEncryption::<T>::insert(netuid, use_weights_encryption);
         CopierMargin::<T>::insert(netuid, copier_margin);
+        CopierStakeFraction::<T>::insert(netuid, copier_stake_fraction);
+        CopierBondsAveraging::<T>::insert(netuid, copier_bonds_averaging);
+        CopierDelegationFee::<T>::insert(netuid, copier_delegation_fee);
 
         Pallet::<T>::deposit_event(Event::SubnetParamsUpdated(netuid));
 
@@ -370,6 +414,11 @@ impl<T: Config> Pallet<T> {
             use_weights_encryption: UseWeightsEncryption::<T>::get(netuid),
             copier_margin: CopierMargin::<T>::get(netuid),
             max_encryption_period: MaxEncryptionPeriod::<T>::get(netuid),
+
+            // --- Copier Simulation ---
+            copier_stake_fraction: CopierStakeFraction::<T>::get(netuid),
+            copier_bonds_averaging: CopierBondsAveraging::<T>::get(netuid),
+            copier_delegation_fee: CopierDelegationFee::<T>::get(netuid),
         }
     }
 }
@@ -146,6 +146,9 @@ pub mod dispatches {
             use_weights_encryption: bool,
             copier_margin: I64F64,
             max_encryption_period: Option<u64>,
+            copier_stake_fraction: Percent,
+            copier_bonds_averaging: BondsAveraging,
+            copier_delegation_fee: Option<Percent>,
         ) -> DispatchResult {
             let params = SubnetParams {
                 founder,
@@ -171,6 +174,9 @@ pub mod dispatches {
                 use_weights_encryption,
                 copier_margin,
                 max_encryption_period,
+                copier_stake_fraction,
+                copier_bonds_averaging,
+                copier_delegation_fee,
             };
 
             let changeset = SubnetChangeset::update(netuid, params)?;
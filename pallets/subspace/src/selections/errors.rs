@@ -189,6 +189,11 @@ pub mod errors {
         UidNotWhitelisted,
         /// The copier margin must be between 0 and 1
         InvalidCopierMargin,
+        /// The simulated copier stake fraction must be above 0% and at most 50%
+        InvalidCopierStakeFraction,
+        /// The delegation fee assumed for the copier simulation is below the minimum stake
+        /// delegation fee
+        InvalidCopierDelegationFee,
         /// Floor Founder Share must be between 0 and 100
         InvalidFloorFounderShare,
         /// Subnet Immunity Period has to be more than 0
@@ -215,5 +220,7 @@ pub mod errors {
         NotEnoughBridgedTokens,
         /// User is trying to bridge tokens in closed period
         OutsideValidBlockRange,
+        /// The emission curve is malformed, see `EmissionCurve::validate`
+        InvalidEmissionCurve,
     }
 }
@@ -28,5 +28,7 @@ pub mod events {
         GlobalParamsUpdated(GlobalParams<T>),
         /// Event created when subnet parameters are updated
         SubnetParamsUpdated(u16),
+        /// Event created when the founder changes where the founder share is paid
+        FounderShareRoutingUpdated { netuid: u16, to_treasury: bool },
     }
 }
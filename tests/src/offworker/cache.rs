@@ -0,0 +1,90 @@
+use crate::mock::{new_test_ext, Test};
+use pallet_offworker::{types::ConsensusSimulationResult, Pallet as OffWorker};
+use sp_core::offchain::{testing, OffchainDbExt};
+use std::cell::Cell;
+use substrate_fixed::types::I64F64;
+
+#[test]
+fn decrypted_weights_are_cached_until_subnet_state_is_deleted() {
+    let mut ext = new_test_ext();
+    let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    ext.execute_with(|| {
+        let netuid = 0;
+        let decrypted = (vec![(0, 10), (1, 20)], vec![1, 2, 3]);
+        let decryptions = Cell::new(0);
+        let decrypt = |_encrypted: Vec<u8>| {
+            decryptions.set(decryptions.get() + 1);
+            Some(decrypted.clone())
+        };
+
+        let cached = |block, uid, encrypted: &[u8]| {
+            OffWorker::<Test>::decrypt_weight_cached(
+                netuid,
+                block,
+                uid,
+                encrypted.to_vec(),
+                decrypt,
+            )
+        };
+
+        assert_eq!(cached(100, 0, &[42]), Some(decrypted.clone()));
+        assert_eq!(cached(100, 0, &[42]), Some(decrypted.clone()));
+        assert_eq!(decryptions.get(), 1);
+
+        // another epoch, module or ciphertext is decrypted again
+        cached(200, 0, &[42]);
+        cached(100, 1, &[42]);
+        cached(100, 0, &[43]);
+        assert_eq!(decryptions.get(), 4);
+
+        // failed decryptions are retried
+        let failed = OffWorker::<Test>::decrypt_weight_cached(netuid, 300, 0, vec![42], |_| None);
+        assert_eq!(failed, None);
+        cached(300, 0, &[42]);
+        assert_eq!(decryptions.get(), 5);
+
+        OffWorker::<Test>::delete_subnet_state(&netuid);
+        cached(100, 0, &[42]);
+        assert_eq!(decryptions.get(), 6);
+    });
+}
+
+#[test]
+fn simulations_are_cached_per_decrypted_weights() {
+    let mut ext = new_test_ext();
+    let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain));
+
+    ext.execute_with(|| {
+        let netuid = 0;
+        let state = ConsensusSimulationResult::<Test>::default();
+        let simulated = (true, I64F64::from_num(1), state.clone());
+        let all_weights = vec![(0, vec![(1, 10)]), (1, vec![(0, 20)])];
+        let missing_share = vec![(0, vec![(1, 10)])];
+
+        OffWorker::<Test>::cache_simulation(netuid, 100, &state, &missing_share, &simulated);
+        assert_eq!(
+            OffWorker::<Test>::cached_simulation(netuid, 100, &state, &missing_share),
+            Some(simulated.clone())
+        );
+
+        // once the missing shares arrive, the epoch is simulated again
+        assert_eq!(
+            OffWorker::<Test>::cached_simulation(netuid, 100, &state, &all_weights),
+            None
+        );
+
+        OffWorker::<Test>::cache_simulation(netuid, 100, &state, &all_weights, &simulated);
+        OffWorker::<Test>::delete_subnet_state(&netuid);
+        assert_eq!(
+            OffWorker::<Test>::cached_simulation(netuid, 100, &state, &missing_share),
+            None
+        );
+        assert_eq!(
+            OffWorker::<Test>::cached_simulation(netuid, 100, &state, &all_weights),
+            None
+        );
+    });
+}
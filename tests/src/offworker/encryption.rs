@@ -1,19 +1,25 @@
-use ow_extensions::OffworkerExtension;
+use crate::mock::{new_test_ext, AccountId, Test};
+use ow_extensions::{
+    envelope::{self, Cipher, DecryptionKey, EnvelopeError},
+    threshold, EncryptionKey, OffworkerExtension,
+};
+use pallet_subnet_emission::{types::SubnetDecryptionInfo, SubnetDecryptionData};
+use parity_scale_codec::Encode;
 use rand::rngs::OsRng;
 use rsa::{
     pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey},
     traits::PublicKeyParts,
-    BigUint, Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey,
+    RsaPrivateKey, RsaPublicKey,
 };
 use sp_core::{sr25519, Pair};
 use sp_keystore::{testing::MemoryKeystore, Keystore};
 use sp_runtime::KeyTypeId;
-use std::io::{Cursor, Read};
+use subspace_weights_crypto::EncryptedWeights;
 
 pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"wcs!");
 
 pub struct MockOffworkerExt {
-    pub key: Option<rsa::RsaPrivateKey>,
+    pub key: Option<DecryptionKey>,
 }
 
 impl Default for MockOffworkerExt {
@@ -45,54 +51,19 @@ impl Default for MockOffworkerExt {
             .expect("Failed to store RSA public key");
 
         Self {
-            key: Some(private_key),
+            key: Some(DecryptionKey::Rsa(private_key)),
         }
     }
 }
 
 impl ow_extensions::OffworkerExtension for MockOffworkerExt {
-    // 1. Here we switch the RSA for elgamal
     fn decrypt_weight(&self, encrypted: Vec<u8>) -> Option<(Vec<(u16, u16)>, Vec<u8>)> {
         let Some(key) = &self.key else {
             return None;
         };
 
-        let Some(vec) = encrypted
-            .chunks(key.size())
-            .map(|chunk| match key.decrypt(Pkcs1v15Encrypt, &chunk) {
-                Ok(decrypted) => Some(decrypted),
-                Err(_) => None,
-            })
-            .collect::<Option<Vec<Vec<u8>>>>()
-        else {
-            return None;
-        };
-
-        let decrypted = vec.into_iter().flatten().collect::<Vec<_>>();
-
-        let mut weights = Vec::new();
-
-        let mut cursor = Cursor::new(&decrypted);
-
-        let Some(length) = read_u32(&mut cursor) else {
-            return None;
-        };
-        for _ in 0..length {
-            let Some(uid) = read_u16(&mut cursor) else {
-                return None;
-            };
-
-            let Some(weight) = read_u16(&mut cursor) else {
-                return None;
-            };
-
-            weights.push((uid, weight));
-        }
-
-        let mut key = Vec::new();
-        cursor.read_to_end(&mut key).ok()?;
-
-        Some((weights, key))
+        let decrypted = key.open(&encrypted).ok()?;
+        envelope::decode_weights(&decrypted)
     }
 
     fn is_decryption_node(&self) -> bool {
@@ -100,28 +71,15 @@ impl ow_extensions::OffworkerExtension for MockOffworkerExt {
     }
 
     fn get_encryption_key(&self) -> Option<(Vec<u8>, Vec<u8>)> {
-        let Some(key) = &self.key else {
-            return None;
-        };
-
-        let public = rsa::RsaPublicKey::from(key);
-        Some((public.n().to_bytes_be(), public.e().to_bytes_be()))
+        self.key.as_ref().map(DecryptionKey::public_key)
     }
-}
 
-fn read_u32(cursor: &mut Cursor<&Vec<u8>>) -> Option<u32> {
-    let mut buf: [u8; 4] = [0u8; 4];
-    match cursor.read_exact(&mut buf[..]) {
-        Ok(()) => Some(u32::from_be_bytes(buf)),
-        Err(_) => None,
-    }
-}
+    fn decryption_share(&self, encrypted: Vec<u8>) -> Option<Vec<u8>> {
+        let Some(DecryptionKey::ThresholdShare(share)) = &self.key else {
+            return None;
+        };
 
-fn read_u16(cursor: &mut Cursor<&Vec<u8>>) -> Option<u16> {
-    let mut buf = [0u8; 2];
-    match cursor.read_exact(&mut buf[..]) {
-        Ok(()) => Some(u16::from_be_bytes(buf)),
-        Err(_) => None,
+        share.decryption_share(&encrypted).ok()
     }
 }
 
@@ -143,31 +101,17 @@ fn weights_to_blob(weights: &[(u16, u16)]) -> Vec<u8> {
 
 // the key needs to be retrieved from the blockchain
 pub fn encrypt(key: (Vec<u8>, Vec<u8>), data: Vec<(u16, u16)>, validator_key: Vec<u8>) -> Vec<u8> {
-    let rsa_key = RsaPublicKey::new(
-        BigUint::from_bytes_be(&key.0),
-        BigUint::from_bytes_be(&key.1),
-    )
-    .expect("Failed to create RSA key");
-
-    let encoded = [
-        (data.len() as u32).to_be_bytes().to_vec(),
-        data.into_iter()
-            .flat_map(|(uid, weight)| {
-                uid.to_be_bytes().into_iter().chain(weight.to_be_bytes().into_iter())
-            })
-            .collect(),
-        validator_key,
-    ]
-    .concat();
-
-    let max_chunk_size = rsa_key.size() - 11; // 11 bytes for PKCS1v15 padding
+    let encoded = envelope::encode_weights(&data, &validator_key);
+    envelope::seal(&key, Cipher::ChaCha20Poly1305, &encoded).expect("Encryption failed")
+}
 
-    encoded
-        .chunks(max_chunk_size)
-        .flat_map(|chunk| {
-            rsa_key.encrypt(&mut OsRng, Pkcs1v15Encrypt, chunk).expect("Encryption failed")
-        })
-        .collect()
+pub fn encrypt_legacy(
+    key: (Vec<u8>, Vec<u8>),
+    data: Vec<(u16, u16)>,
+    validator_key: Vec<u8>,
+) -> Vec<u8> {
+    let encoded = envelope::encode_weights(&data, &validator_key);
+    envelope::seal_legacy(&key, &encoded).expect("Encryption failed")
 }
 
 #[test]
@@ -179,7 +123,7 @@ fn encrypt_and_decrypt() {
 
     let rsa_key = RsaPrivateKey::new(&mut OsRng, 1024).unwrap();
     let mock_offworker_ext = MockOffworkerExt {
-        key: Some(rsa_key.clone()),
+        key: Some(DecryptionKey::Rsa(rsa_key.clone())),
     };
 
     let rsa_key_pem = rsa_key.to_pkcs1_pem(rsa::pkcs8::LineEnding::LF).unwrap().to_string();
@@ -236,7 +180,9 @@ fvRuW5JF+WZtGddyU4751JNNNhmwbwGmsmphy7EOHHaC
 -----END RSA PRIVATE KEY-----";
 
     let rsa_key = RsaPrivateKey::from_pkcs1_pem(rsa_key_pem).unwrap();
-    let mock_offworker_ext = MockOffworkerExt { key: Some(rsa_key) };
+    let mock_offworker_ext = MockOffworkerExt {
+        key: Some(DecryptionKey::Rsa(rsa_key)),
+    };
 
     println!("weights = {:?}", weights);
     println!("validator_key = {:?}", validator_key);
@@ -246,3 +192,191 @@ fvRuW5JF+WZtGddyU4751JNNNhmwbwGmsmphy7EOHHaC
     assert_eq!(decrypted_weights, weights.clone());
     assert_eq!(decrypted_key, validator_key.clone());
 }
+
+#[test]
+fn envelope_round_trips_for_every_scheme() {
+    let weights: Vec<(u16, u16)> = (0..1_000).map(|uid| (uid, uid.wrapping_mul(7))).collect();
+    let validator_key = vec![11, 22, 33, 44];
+
+    let keys = [
+        DecryptionKey::Rsa(RsaPrivateKey::new(&mut OsRng, 1024).unwrap()),
+        DecryptionKey::generate_x25519(),
+    ];
+
+    for key in keys {
+        let public = key.public_key();
+        let plaintext = envelope::encode_weights(&weights, &validator_key);
+        let ext = MockOffworkerExt {
+            key: Some(key.clone()),
+        };
+
+        for cipher in [Cipher::ChaCha20Poly1305, Cipher::Aes256Gcm] {
+            let encrypted = envelope::seal(&public, cipher, &plaintext).unwrap();
+            // a single wrapped key instead of one RSA block per chunk
+            assert!(encrypted.len() < plaintext.len() + 256);
+
+            let (decrypted_weights, decrypted_key) = ext.decrypt_weight(encrypted).unwrap();
+            assert_eq!(decrypted_weights, weights);
+            assert_eq!(decrypted_key, validator_key);
+        }
+    }
+}
+
+#[test]
+fn envelope_keeps_legacy_ciphertexts_decodable() {
+    let weights = vec![(1, 2), (3, 4)];
+    let validator_key = vec![11, 22, 33, 44];
+
+    let rsa_key = RsaPrivateKey::new(&mut OsRng, 1024).unwrap();
+    let public = rsa_key.to_public_key();
+    let public = (public.n().to_bytes_be(), public.e().to_bytes_be());
+    let ext = MockOffworkerExt {
+        key: Some(DecryptionKey::Rsa(rsa_key)),
+    };
+
+    let encrypted = encrypt_legacy(public, weights.clone(), validator_key.clone());
+    let (decrypted_weights, decrypted_key) = ext.decrypt_weight(encrypted).unwrap();
+
+    assert_eq!(decrypted_weights, weights);
+    assert_eq!(decrypted_key, validator_key);
+}
+
+#[test]
+fn envelope_rejects_tampering_and_wrong_keys() {
+    let key = DecryptionKey::generate_x25519();
+    let plaintext = envelope::encode_weights(&[(1, 2)], &[11, 22]);
+
+    let mut encrypted =
+        envelope::seal(&key.public_key(), Cipher::ChaCha20Poly1305, &plaintext).unwrap();
+    assert_eq!(key.open(&encrypted).unwrap(), plaintext);
+
+    let other = DecryptionKey::generate_x25519();
+    assert_eq!(other.open(&encrypted), Err(EnvelopeError::Decryption));
+
+    let rsa = DecryptionKey::Rsa(RsaPrivateKey::new(&mut OsRng, 1024).unwrap());
+    assert!(rsa.open(&encrypted).is_err());
+
+    // the header is authenticated, switching the cipher breaks the tag
+    if let Some(cipher) = encrypted.get_mut(5) {
+        *cipher = Cipher::Aes256Gcm as u8;
+    }
+    assert_eq!(key.open(&encrypted), Err(EnvelopeError::Decryption));
+}
+
+#[test]
+fn threshold_committee_decrypts_with_enough_shares() {
+    let weights = vec![(1, 2), (3, 4)];
+    let validator_key = vec![11, 22, 33, 44];
+    let plaintext = envelope::encode_weights(&weights, &validator_key);
+
+    let (committee_key, shares) = threshold::deal(3, 5).unwrap();
+    let encrypted = envelope::seal(&committee_key, Cipher::Aes256Gcm, &plaintext).unwrap();
+
+    let decryption_shares: Vec<(EncryptionKey, Vec<u8>)> = shares
+        .iter()
+        .map(|share| {
+            let member = DecryptionKey::parse(&share.serialize()).unwrap();
+            // a single member can not decrypt on its own
+            assert!(member.open(&encrypted).is_err());
+
+            let ext = MockOffworkerExt { key: Some(member) };
+            (
+                share.public_key(),
+                ext.decryption_share(encrypted.clone()).unwrap(),
+            )
+        })
+        .collect();
+
+    let pick = |indices: &[u16]| -> Vec<(EncryptionKey, Vec<u8>)> {
+        shares
+            .iter()
+            .zip(&decryption_shares)
+            .filter(|(share, _)| indices.contains(&share.index))
+            .map(|(_, decryption_share)| decryption_share.clone())
+            .collect()
+    };
+
+    for indices in [[1, 2, 3], [1, 3, 5], [2, 4, 5]] {
+        let decrypted = threshold::combine(&committee_key, &encrypted, &pick(&indices), 3);
+        assert_eq!(decrypted, Ok(plaintext.clone()));
+    }
+
+    assert_eq!(
+        threshold::combine(&committee_key, &encrypted, &pick(&[1, 4]), 3),
+        Err(EnvelopeError::Decryption)
+    );
+
+    // a share that does not match its member's key is dropped, the others still decrypt
+    let mut forged = pick(&[1, 2, 3, 4]);
+    forged[2].1 = decryption_shares[0].1.clone();
+    assert_eq!(
+        threshold::combine(&committee_key, &encrypted, &forged, 3),
+        Ok(plaintext.clone())
+    );
+    assert_eq!(
+        threshold::combine(&committee_key, &encrypted, &forged[..3], 3),
+        Err(EnvelopeError::Decryption)
+    );
+
+    // as is a share claimed by another member
+    let mut stolen = pick(&[1, 2]);
+    stolen.push((shares[2].public_key(), decryption_shares[0].1.clone()));
+    assert_eq!(
+        threshold::combine(&committee_key, &encrypted, &stolen, 3),
+        Err(EnvelopeError::Decryption)
+    );
+}
+
+#[test]
+fn weights_crypto_round_trips_through_decryption_node() {
+    new_test_ext().execute_with(|| {
+        let netuid = 0;
+        let node_key = DecryptionKey::generate_x25519();
+        SubnetDecryptionData::<Test>::set(
+            netuid,
+            Some(SubnetDecryptionInfo {
+                node_id: 1001,
+                node_public_key: node_key.public_key(),
+                validity_block: None,
+                last_keep_alive: 0,
+                rotating_from: None,
+            }),
+        );
+
+        let storage_key =
+            subspace_weights_crypto::decryption_data_key_for(b"SubnetEmissionMod", netuid);
+        assert_eq!(
+            storage_key,
+            SubnetDecryptionData::<Test>::hashed_key_for(netuid)
+        );
+
+        let value = sp_io::storage::get(&storage_key).unwrap();
+        let public_key =
+            subspace_weights_crypto::decode_node_public_key::<AccountId>(&value).unwrap();
+        assert_eq!(public_key, node_key.public_key());
+
+        let weights = vec![(0, 10), (3, u16::MAX), (7, 1)];
+        let validator_key = 42u32.encode();
+        let EncryptedWeights {
+            encrypted,
+            decrypted_weights_hash,
+        } = subspace_weights_crypto::encrypt_weights(
+            &public_key,
+            &weights,
+            &validator_key,
+            Cipher::Aes256Gcm,
+        )
+        .unwrap();
+
+        // the hash the runtime checks the decrypted weights against
+        assert_eq!(decrypted_weights_hash, hash(weights.clone()));
+
+        let ext = MockOffworkerExt {
+            key: Some(node_key),
+        };
+        assert_eq!(
+            ext.decrypt_weight(encrypted),
+            Some((weights, validator_key))
+        );
+    });
+}
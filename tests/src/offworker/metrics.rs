@@ -0,0 +1,99 @@
+use ow_extensions::{offworker, OffworkerExt, OffworkerMetric};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Records the metrics the runtime reports instead of exporting them.
+#[derive(Clone, Default)]
+struct MetricsRecorder(Arc<Mutex<Vec<OffworkerMetric>>>);
+
+impl MetricsRecorder {
+    fn take(&self) -> Vec<OffworkerMetric> {
+        std::mem::take(&mut *self.0.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl ow_extensions::OffworkerExtension for MetricsRecorder {
+    fn decrypt_weight(&self, _encrypted: Vec<u8>) -> Option<ow_extensions::DecryptedWeights> {
+        None
+    }
+
+    fn is_decryption_node(&self) -> bool {
+        true
+    }
+
+    fn get_encryption_key(&self) -> Option<ow_extensions::EncryptionKey> {
+        None
+    }
+
+    fn report_metric(&self, metric: OffworkerMetric) {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).push(metric);
+    }
+}
+
+#[test]
+fn report_host_functions_reach_the_node() {
+    let recorder = MetricsRecorder::default();
+    let mut ext = sp_io::TestExternalities::default();
+    ext.register_extension(OffworkerExt::new(recorder.clone()));
+
+    ext.execute_with(|| {
+        offworker::report_subnets_assigned(3);
+        offworker::report_blocks_behind(2, 40);
+        offworker::report_ping(1_000);
+        offworker::report_irrationality_delta(2, (-0.5f64).to_bits());
+        offworker::report_submission_error();
+    });
+
+    assert_eq!(
+        recorder.take(),
+        vec![
+            OffworkerMetric::SubnetsAssigned(3),
+            OffworkerMetric::BlocksBehind {
+                subnet_id: 2,
+                blocks: 40
+            },
+            OffworkerMetric::Ping { block: 1_000 },
+            OffworkerMetric::IrrationalityDelta {
+                subnet_id: 2,
+                delta: -0.5
+            },
+            OffworkerMetric::SubmissionError,
+        ]
+    );
+}
+
+#[test]
+fn failed_share_combinations_are_reported() {
+    let recorder = MetricsRecorder::default();
+    let mut ext = sp_io::TestExternalities::default();
+    ext.register_extension(OffworkerExt::new(recorder.clone()));
+
+    let decrypted = ext.execute_with(|| {
+        offworker::combine_decryption_shares(
+            (b"x25519".to_vec(), vec![1; 32]),
+            vec![42],
+            Vec::new(),
+            1,
+        )
+    });
+    assert_eq!(decrypted, None);
+
+    let metrics = recorder.take();
+    assert!(matches!(
+        metrics.as_slice(),
+        [OffworkerMetric::Decryption {
+            succeeded: false,
+            ..
+        }]
+    ));
+}
+
+#[test]
+fn metrics_are_dropped_without_the_extension() {
+    sp_io::TestExternalities::default().execute_with(|| {
+        offworker::report_subnets_assigned(3);
+        offworker::report_blocks_behind(2, 40);
+        offworker::report_ping(1_000);
+        offworker::report_irrationality_delta(2, 0.5f64.to_bits());
+        offworker::report_submission_error();
+    });
+}
@@ -1,8 +1,12 @@
+mod cache;
 #[cfg(feature = "testing-offworker")]
 mod data;
 // #[cfg(feature = "testing-offworker")]
 pub mod encryption;
+mod metrics;
 #[cfg(feature = "testing-offworker")]
 mod offworker;
+mod process;
+mod profitability;
 #[cfg(feature = "testing-offworker")]
 mod util;
@@ -0,0 +1,70 @@
+use crate::mock::*;
+use pallet_offworker::{types::SubnetDecision, Pallet as OffWorker};
+use pallet_subnet_emission::{
+    subnet_consensus::util::params::ConsensusParams, types::DecryptionCommitteeInfo,
+    ConsensusParameters, DecryptionCommittee, WeightEncryptionData,
+};
+use pallet_subspace::SubnetRegistrationBlock;
+use sp_core::offchain::{testing, OffchainDbExt};
+
+fn new_process_test_ext() -> sp_io::TestExternalities {
+    let mut ext = new_test_ext();
+    let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext
+}
+
+#[test]
+fn subnet_decision_follows_the_subnet_state() {
+    new_process_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let netuid = 0;
+        let node = 1001;
+        register_module(netuid, 1, 10000, false).unwrap();
+        step_block(10);
+        let block = SubspaceMod::get_current_block_number();
+
+        // nothing was encrypted yet
+        assert_eq!(
+            OffWorker::<Test>::process_subnet(netuid, &node, block),
+            SubnetDecision::UpToDate
+        );
+
+        WeightEncryptionData::<Test>::set(
+            netuid,
+            0,
+            Some(pallet_subnet_emission::EncryptionMechanism {
+                encrypted: vec![42],
+                decrypted_hashes: vec![123],
+            }),
+        );
+        ConsensusParameters::<Test>::insert(
+            netuid,
+            block,
+            ConsensusParams::<Test>::new(netuid, 0).unwrap(),
+        );
+        DecryptionCommittee::<Test>::put(DecryptionCommitteeInfo {
+            public_key: (b"x25519".to_vec(), vec![1; 32]),
+            threshold: 1,
+            members: vec![(node, 1)],
+        });
+
+        // committee encrypted weights wait for the shares of the members
+        assert_eq!(
+            OffWorker::<Test>::process_subnet(netuid, &node, block),
+            SubnetDecision::AwaitingShares
+        );
+
+        // the netuid was registered again after the weights were encrypted
+        SubnetRegistrationBlock::<Test>::insert(netuid, block + 1);
+        assert_eq!(
+            OffWorker::<Test>::process_subnet(netuid, &node, block),
+            SubnetDecision::Deregistered
+        );
+        assert_eq!(
+            OffWorker::<Test>::process_subnets(vec![netuid], node, block),
+            vec![netuid]
+        );
+    });
+}
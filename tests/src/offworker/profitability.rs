@@ -0,0 +1,74 @@
+use crate::mock::*;
+use pallet_offworker::Pallet as OffWorker;
+use pallet_subnet_emission::{
+    subnet_consensus::util::params::ConsensusParams, ConsensusParameters,
+};
+use pallet_subspace::{Consensus, CopierStakeFraction, ValidatorPermits};
+use substrate_fixed::types::I64F64;
+
+#[test]
+fn copier_simulation_projects_reveal_from_onchain_params() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let netuid = 0;
+        register_module(netuid, 0, to_nano(10_000), false).unwrap();
+        let miner = register_module(netuid, 1, to_nano(1_000), false).unwrap();
+        set_weights(netuid, 0, vec![miner], vec![1]);
+        ValidatorPermits::<Test>::set(netuid, vec![true, false]);
+
+        // nothing encrypted, nothing to simulate
+        assert_eq!(
+            OffWorker::<Test>::simulate_copier_profitability(netuid),
+            None
+        );
+
+        ConsensusParameters::<Test>::insert(
+            netuid,
+            10,
+            ConsensusParams::<Test>::new(netuid, to_nano(1)).unwrap(),
+        );
+        ConsensusParameters::<Test>::insert(
+            netuid,
+            20,
+            ConsensusParams::<Test>::new(netuid, to_nano(1)).unwrap(),
+        );
+
+        // without a public consensus to copy, the weights are revealed right away
+        let simulation = OffWorker::<Test>::simulate_copier_profitability(netuid).unwrap();
+        assert!(simulation.is_copying_irrational);
+        assert_eq!(simulation.simulated_epochs, 1);
+        assert_eq!(simulation.creation_block, 10);
+        assert_eq!(simulation.projected_reveal_block, 10);
+
+        Consensus::<Test>::set(netuid, vec![0, u16::MAX]);
+
+        let validator_stake: u64 = ConsensusParameters::<Test>::get(netuid, 20)
+            .unwrap()
+            .modules
+            .values()
+            .filter(|module| module.validator_permit)
+            .map(|module| module.stake_original.to_num::<u64>())
+            .sum();
+
+        let simulation = OffWorker::<Test>::simulate_copier_profitability(netuid).unwrap();
+        assert_eq!(
+            simulation.copier_stake,
+            CopierStakeFraction::<Test>::get(netuid).mul_floor(validator_stake)
+        );
+        assert_eq!(
+            simulation.max_encryption_period,
+            SubnetEmissionMod::get_max_encryption_interval(&netuid)
+        );
+        if simulation.is_copying_irrational {
+            assert!(simulation.delta <= I64F64::from_num(0));
+            assert!([10, 20].contains(&simulation.projected_reveal_block));
+        } else {
+            assert_eq!(simulation.simulated_epochs, 2);
+            assert_eq!(
+                simulation.projected_reveal_block,
+                10 + simulation.max_encryption_period
+            );
+        }
+    });
+}
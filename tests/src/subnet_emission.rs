@@ -4,26 +4,36 @@ use pallet_subnet_emission::{
         consensus::EmissionMap,
         params::{AccountKey, ModuleKey},
     },
-    types::SubnetDecryptionInfo,
-    BannedDecryptionNodes, Weights,
+    types::{
+        DecryptionCommitteeInfo, DecryptionNodeBond, DecryptionNodeFaultKind, DecryptionShare,
+        DecryptionShareSet, SubnetDecryptionInfo,
+    },
+    Authorities, BannedDecryptionNodes, DecryptionCommittee, DecryptionNodeBanQueue, Weights,
 };
 
 use pallet_subspace::{Active, Consensus, Founder, PruningScores, Rank, Trust, ValidatorTrust};
 use parity_scale_codec::Encode;
 use rand::rngs::OsRng;
 use rsa::{traits::PublicKeyParts, RsaPrivateKey};
-use sp_runtime::Percent;
+use sp_runtime::{Perbill, Percent};
 use std::collections::BTreeMap;
 
-use frame_support::{assert_ok, traits::Currency};
+use frame_support::{
+    assert_err, assert_ok,
+    traits::{Currency, Hooks, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+    BoundedVec,
+};
 use log::info;
 use pallet_governance::DaoTreasuryAddress;
 use pallet_subnet_emission::{
+    migrations::{v3::MigrateToV3, v4::MigrateToV4},
     subnet_consensus::{util::params::ConsensusParams, yuma::YumaEpoch},
-    PendingEmission, SubnetConsensusType, SubnetEmission, UnitEmission,
+    ActiveEmissionCurve, DeferredEpochs, EpochHistoryCount, PendingEmission, SubnetConsensusType,
+    SubnetEmission, UnitEmission, YumaEpochCursor,
 };
 
-use pallet_subnet_emission_api::SubnetConsensus;
+use pallet_subnet_emission_api::{EmissionCurve, SubnetConsensus};
 use pallet_subspace::{
     Dividends, Emission, FloorFounderShare, FounderShare, ImmunityPeriod, Incentive,
     MaxAllowedModules, MaxAllowedSubnets, MaxAllowedValidators, MaxRegistrationsPerBlock,
@@ -1156,6 +1166,128 @@ fn test_halving() {
     });
 }
 
+#[test]
+fn test_emission_curves() {
+    new_test_ext().execute_with(|| {
+        let unit_emission = 1_000_000_000_000;
+        UnitEmission::<Test>::put(unit_emission);
+
+        // Exact halvings at multiples of the half life, smooth decay in between
+        assert_ok!(SubnetEmissionMod::set_emission_curve(
+            EmissionCurve::ExponentialDecay {
+                half_life: 1_000,
+                max_supply: 10_000,
+            }
+        ));
+        assert_eq!(
+            SubnetEmissionMod::forecast_emission(vec![0, 500, 1_000, 1_500, 2_000, 10_000]),
+            vec![
+                unit_emission,
+                707_106_781_186,
+                unit_emission / 2,
+                353_553_390_593,
+                unit_emission / 4,
+                0
+            ]
+        );
+
+        let steps = vec![
+            (0, Perbill::one()),
+            (100, Perbill::from_percent(50)),
+            (400, Perbill::from_percent(10)),
+        ];
+        assert_ok!(SubnetEmissionMod::set_emission_curve(
+            EmissionCurve::Piecewise {
+                steps: BoundedVec::truncate_from(steps),
+                max_supply: 1_000,
+            }
+        ));
+        assert_eq!(
+            SubnetEmissionMod::forecast_emission(vec![0, 99, 100, 399, 400, 999, 1_000]),
+            vec![
+                unit_emission,
+                unit_emission,
+                unit_emission / 2,
+                unit_emission / 2,
+                unit_emission / 10,
+                unit_emission / 10,
+                0
+            ]
+        );
+
+        let invalid_curves = [
+            EmissionCurve::StepHalving {
+                interval: 0,
+                max_supply: 1_000,
+            },
+            EmissionCurve::ExponentialDecay {
+                half_life: 100,
+                max_supply: 0,
+            },
+            EmissionCurve::Piecewise {
+                steps: BoundedVec::truncate_from(vec![(10, Perbill::one())]),
+                max_supply: 1_000,
+            },
+            EmissionCurve::Piecewise {
+                steps: BoundedVec::truncate_from(vec![
+                    (0, Perbill::one()),
+                    (500, Perbill::from_percent(50)),
+                    (500, Perbill::from_percent(10)),
+                ]),
+                max_supply: 1_000,
+            },
+            EmissionCurve::Piecewise {
+                steps: BoundedVec::truncate_from(vec![
+                    (0, Perbill::one()),
+                    (1_000, Perbill::from_percent(50)),
+                ]),
+                max_supply: 1_000,
+            },
+        ];
+        for curve in invalid_curves {
+            assert_err!(
+                SubnetEmissionMod::set_emission_curve(curve),
+                pallet_subspace::Error::<Test>::InvalidEmissionCurve
+            );
+        }
+    });
+}
+
+#[test]
+fn emission_curve_migration_preserves_schedule() {
+    new_test_ext().execute_with(|| {
+        let multiplier = 10_u64.pow(9);
+        set_emission_config(9, 250_000_000, 1_000_000_000);
+        assert_eq!(ActiveEmissionCurve::<Test>::get(), None);
+
+        let levels = vec![
+            0,
+            250_000_000 * multiplier,
+            750_000_000 * multiplier,
+            1_000_000_000 * multiplier,
+        ];
+        let forecast_before = SubnetEmissionMod::forecast_emission(levels.clone());
+
+        StorageVersion::new(2).put::<SubnetEmissionMod>();
+        MigrateToV3::<Test>::on_runtime_upgrade();
+
+        assert_eq!(
+            ActiveEmissionCurve::<Test>::get(),
+            Some(EmissionCurve::StepHalving {
+                interval: 250_000_000 * multiplier,
+                max_supply: 1_000_000_000 * multiplier,
+            })
+        );
+
+        // The stored curve no longer depends on the runtime constants
+        set_emission_config(9, 1, 1);
+        assert_eq!(
+            SubnetEmissionMod::forecast_emission(levels),
+            forecast_before
+        );
+    });
+}
+
 /// This test is aimed at subnet deregistration based on emission
 /// 1. Set MaxAllowedSubnets to 3
 /// 2. Register 3 subnets, using the function `register_named_subnet`
@@ -1656,3 +1788,546 @@ fn weight_setting_delegation() {
 //         panic!("a");
 //     });
 // }
+
+#[test]
+fn epochs_over_weight_budget_are_deferred() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+        zero_min_validator_stake();
+        set_max_epoch_weight_per_block(Weight::zero());
+
+        assert_ok!(register_module(1, 1, to_nano(1_000), false));
+        assert_ok!(register_module(2, 2, to_nano(1_000), false));
+        let first = SubspaceMod::<Test>::get_netuid_for_name(b"test1").unwrap();
+        let second = SubspaceMod::<Test>::get_netuid_for_name(b"test2").unwrap();
+
+        // Both epochs are due on the same block
+        let tempo: u16 = 100;
+        Tempo::<Test>::insert(first, tempo);
+        Tempo::<Test>::insert(second, tempo + second - first);
+        let epoch_block = u64::from(tempo - first);
+
+        run_to_block(epoch_block - 1);
+        assert!(DeferredEpochs::<Test>::get().is_empty());
+
+        // Only one epoch fits, subnets run in netuid order so the second one is deferred
+        step_block(1);
+        let deferred = DeferredEpochs::<Test>::get();
+        assert_eq!(deferred.to_vec(), vec![second]);
+
+        // No weight left in the block
+        SubnetEmissionMod::on_idle(System::block_number(), Weight::zero());
+        assert_eq!(DeferredEpochs::<Test>::get(), deferred);
+
+        SubnetEmissionMod::on_idle(System::block_number(), Weight::MAX);
+        assert!(DeferredEpochs::<Test>::get().is_empty());
+
+        // The next block always runs at least one deferred epoch
+        DeferredEpochs::<Test>::set(deferred);
+        step_block(1);
+        assert!(DeferredEpochs::<Test>::get().is_empty());
+    });
+}
+
+#[test]
+fn multi_block_epoch_matches_single_block_epoch() {
+    let run_epochs = |multi_block: bool| {
+        new_test_ext().execute_with(|| {
+            zero_min_validator_stake();
+            let netuid: u16 = 1;
+
+            assert_ok!(register_named_subnet(u32::MAX, 0, "Rootnet"));
+            SubnetConsensusType::<Test>::insert(0, SubnetConsensus::Root);
+            assert_ok!(register_root_validator(u32::MAX, to_nano(10_000)));
+
+            zero_min_burn();
+            MaxRegistrationsPerBlock::<Test>::set(1000);
+
+            register_n_modules(netuid, 10, to_nano(10_000), false);
+            step_block(1);
+            MaxWeightAge::<Test>::insert(netuid, 20_000);
+            MinValidatorStake::<Test>::insert(netuid, 0);
+            set_weights(0, u32::MAX, vec![netuid], vec![1]);
+
+            if multi_block {
+                set_multi_block_epoch_threshold(0);
+            }
+
+            let keys = get_keys(netuid);
+            set_weights(netuid, keys[0], vec![2, 3, 4], vec![3, 2, 1]);
+            set_weights(netuid, keys[1], vec![2, 3], vec![1, 1]);
+            set_weights(netuid, keys[5], vec![4, 6], vec![1, 5]);
+
+            for _ in 0..2 {
+                step_block(1);
+                while SubspaceMod::<Test>::blocks_until_next_epoch(netuid, System::block_number())
+                    != 0
+                {
+                    step_block(1);
+                }
+                assert_eq!(YumaEpochCursor::<Test>::contains_key(netuid), multi_block);
+
+                // Consensus one column per block, then the trust, bonds and apply phases
+                step_block(N::<Test>::get(netuid) + 3);
+                assert!(!YumaEpochCursor::<Test>::contains_key(netuid));
+            }
+
+            (
+                Incentive::<Test>::get(netuid),
+                Dividends::<Test>::get(netuid),
+                Emission::<Test>::get(netuid),
+                Consensus::<Test>::get(netuid),
+                Trust::<Test>::get(netuid),
+                ValidatorTrust::<Test>::get(netuid),
+                pallet_subspace::Bonds::<Test>::iter_prefix(netuid).collect::<BTreeMap<_, _>>(),
+                get_stakes(netuid),
+                PendingEmission::<Test>::get(netuid),
+            )
+        })
+    };
+
+    let single_block = run_epochs(false);
+    assert!(single_block.0.iter().any(|incentive| *incentive > 0));
+    assert_eq!(single_block, run_epochs(true));
+}
+
+#[test]
+fn epoch_history_keeps_last_epochs() {
+    new_test_ext().execute_with(|| {
+        zero_min_validator_stake();
+        let netuid: u16 = 1;
+
+        assert_ok!(register_named_subnet(u32::MAX, 0, "Rootnet"));
+        SubnetConsensusType::<Test>::insert(0, SubnetConsensus::Root);
+        assert_ok!(register_root_validator(u32::MAX, to_nano(10_000)));
+
+        zero_min_burn();
+        MaxRegistrationsPerBlock::<Test>::set(1000);
+
+        register_n_modules(netuid, 5, to_nano(10_000), false);
+        step_block(1);
+        MaxWeightAge::<Test>::insert(netuid, 20_000);
+        MinValidatorStake::<Test>::insert(netuid, 0);
+        set_weights(0, u32::MAX, vec![netuid], vec![1]);
+
+        let keys = get_keys(netuid);
+        set_weights(netuid, keys[0], vec![1, 2, 3], vec![3, 2, 1]);
+
+        let recorded_before = EpochHistoryCount::<Test>::get(netuid);
+        for _ in 0..6 {
+            step_epoch(netuid);
+        }
+        let recorded = EpochHistoryCount::<Test>::get(netuid);
+        assert_eq!(recorded, recorded_before + 6);
+
+        // Only the last 4 epochs are kept
+        let history = SubnetEmissionMod::get_epoch_history(netuid, 0, 10);
+        let epochs: Vec<u32> = history.iter().map(|(epoch, _)| *epoch).collect();
+        assert_eq!(epochs, (recorded - 4..recorded).collect::<Vec<_>>());
+
+        let (_, last) = history.last().unwrap();
+        assert!(last.block > System::block_number() - u64::from(Tempo::<Test>::get(netuid)));
+        assert!(last.total_emission > 0);
+        assert!(last.validator_count > 0);
+        assert!(!last.top_earners.is_empty() && last.top_earners.len() <= 3);
+        assert!(last.top_earners.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+
+        let page = SubnetEmissionMod::get_epoch_history(netuid, recorded - 2, 1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].0, recorded - 2);
+    });
+}
+
+#[test]
+fn decryption_committee_needs_a_threshold_of_shares() {
+    new_test_ext().execute_with(|| {
+        let netuid: u16 = 1;
+        let members: Vec<AccountId> = vec![1001, 1002, 1003];
+        let (committee_key, _) = ow_extensions::threshold::deal(2, 3).unwrap();
+        let member_key = |member: AccountId| (b"member".to_vec(), member.to_be_bytes().to_vec());
+
+        Authorities::<Test>::set(BoundedVec::truncate_from(
+            members.iter().map(|member| (*member, member_key(*member))).collect(),
+        ));
+
+        let committee = |threshold, members: Vec<(AccountId, u16)>| DecryptionCommitteeInfo {
+            public_key: committee_key.clone(),
+            threshold,
+            members,
+        };
+
+        assert_err!(
+            OffWorkerMod::set_decryption_committee(
+                RuntimeOrigin::root(),
+                Some(committee(4, vec![(1001, 1), (1002, 2), (1003, 3)]))
+            ),
+            pallet_offworker::Error::<Test>::InvalidCommittee
+        );
+        assert_err!(
+            OffWorkerMod::set_decryption_committee(
+                RuntimeOrigin::root(),
+                Some(committee(2, vec![(1001, 1), (1002, 1)]))
+            ),
+            pallet_offworker::Error::<Test>::InvalidCommittee
+        );
+        assert_err!(
+            OffWorkerMod::set_decryption_committee(
+                RuntimeOrigin::root(),
+                Some(committee(2, vec![(1001, 1), (2000, 2)]))
+            ),
+            pallet_offworker::Error::<Test>::CommitteeMemberNotAuthority
+        );
+        assert_ok!(OffWorkerMod::set_decryption_committee(
+            RuntimeOrigin::root(),
+            Some(committee(2, vec![(1001, 1), (1002, 2), (1003, 3)]))
+        ));
+
+        // the weights of uids 0 and 1 wait for decryption
+        for (uid, key) in [(0u16, 1), (1, 2)] {
+            register_module(netuid, key, 10000, false).unwrap();
+            pallet_subnet_emission::WeightEncryptionData::<Test>::set(
+                netuid,
+                uid,
+                Some(pallet_subnet_emission::EncryptionMechanism {
+                    encrypted: vec![42],
+                    decrypted_hashes: vec![123],
+                }),
+            );
+        }
+        pallet_subnet_emission::ConsensusParameters::<Test>::insert(
+            netuid,
+            5,
+            ConsensusParams::<Test>::new(netuid, 0).unwrap(),
+        );
+
+        // shares are only released once the assigned node was called back
+        assert!(!SubnetEmissionMod::is_decryption_due(netuid));
+        let ban_block = SubspaceMod::<Test>::get_current_block_number().saturating_add(1);
+        DecryptionNodeBanQueue::<Test>::insert(netuid, 1001, ban_block);
+        assert!(SubnetEmissionMod::is_decryption_due(netuid));
+
+        let shares = |block, uid, share: u8| {
+            DecryptionShareSet::truncate_from(vec![(
+                block,
+                uid,
+                DecryptionShare::truncate_from(vec![share]),
+            )])
+        };
+
+        // a threshold of shares is needed for each of the weights
+        assert!(!SubnetEmissionMod::has_decryption_quorum(netuid));
+        SubnetEmissionMod::handle_decryption_shares(netuid, 1001, shares(5, 0, 1));
+        assert!(!SubnetEmissionMod::has_decryption_quorum(netuid));
+        SubnetEmissionMod::handle_decryption_shares(netuid, 1003, shares(5, 0, 3));
+        assert!(!SubnetEmissionMod::has_decryption_quorum(netuid));
+        SubnetEmissionMod::handle_decryption_shares(netuid, 1002, shares(5, 1, 2));
+        SubnetEmissionMod::handle_decryption_shares(netuid, 1002, shares(5, 1, 2));
+        assert!(!SubnetEmissionMod::has_decryption_quorum(netuid));
+
+        // the node can't send the weights without them, its ban is postponed
+        step_block(1);
+        assert!(!BannedDecryptionNodes::<Test>::contains_key(1001));
+        assert!(DecryptionNodeBanQueue::<Test>::get(netuid, 1001) > ban_block);
+
+        SubnetEmissionMod::handle_decryption_shares(netuid, 1003, shares(5, 1, 3));
+        assert!(SubnetEmissionMod::has_decryption_quorum(netuid));
+
+        assert_eq!(
+            SubnetEmissionMod::decryption_shares_for(netuid, 5, 0),
+            vec![(member_key(1001), vec![1]), (member_key(1003), vec![3])]
+        );
+        assert!(SubnetEmissionMod::decryption_shares_for(netuid, 5, 1).is_empty());
+
+        assert_ok!(OffWorkerMod::set_decryption_committee(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert!(!DecryptionCommittee::<Test>::exists());
+        assert!(SubnetEmissionMod::has_decryption_quorum(netuid));
+    });
+}
+
+#[test]
+fn decryption_key_rotation_keeps_previous_key_until_processed() {
+    new_test_ext().execute_with(|| {
+        let netuid = 0;
+        let node_id = 1001;
+        let old_key = (b"x25519".to_vec(), vec![1; 32]);
+        let new_key = (b"x25519".to_vec(), vec![2; 32]);
+
+        let acc_id = 1;
+        let _ = register_module(netuid, acc_id, 10000, false).unwrap();
+        let uid = SubspaceMod::<Test>::get_uid_for_key(netuid, &acc_id).unwrap();
+
+        Authorities::<Test>::set(BoundedVec::truncate_from(vec![(node_id, old_key.clone())]));
+        pallet_subnet_emission::DecryptionNodeBonds::<Test>::insert(
+            node_id,
+            DecryptionNodeBond {
+                active: to_nano(10_000),
+                ..Default::default()
+            },
+        );
+        pallet_subnet_emission::SubnetDecryptionData::<Test>::set(
+            netuid,
+            Some(SubnetDecryptionInfo {
+                validity_block: Some(0),
+                node_id,
+                node_public_key: old_key.clone(),
+                last_keep_alive: 0,
+                rotating_from: None,
+            }),
+        );
+
+        // weights encrypted to the old key wait for decryption
+        pallet_subnet_emission::WeightEncryptionData::<Test>::set(
+            netuid,
+            uid,
+            Some(pallet_subnet_emission::EncryptionMechanism {
+                encrypted: vec![42],
+                decrypted_hashes: vec![123],
+            }),
+        );
+        pallet_subnet_emission::ConsensusParameters::<Test>::insert(
+            netuid,
+            0,
+            ConsensusParams::<Test>::new(netuid, 0).unwrap(),
+        );
+
+        // a ping with the current key changes nothing
+        SubnetEmissionMod::handle_authority_node_ping(node_id, old_key.clone());
+        assert!(!pallet_subnet_emission::DecryptionKeyRotations::<Test>::contains_key(node_id));
+
+        step_block(1);
+        SubnetEmissionMod::handle_authority_node_ping(node_id, new_key.clone());
+
+        assert_eq!(
+            Authorities::<Test>::get().into_inner(),
+            vec![(node_id, new_key.clone())]
+        );
+        assert_eq!(
+            pallet_subnet_emission::SubnetDecryptionData::<Test>::get(netuid)
+                .map(|info| info.node_public_key),
+            Some(new_key.clone())
+        );
+        assert!(pallet_subnet_emission::DecryptionNodes::<Test>::get()
+            .iter()
+            .any(|node| node.node_id == node_id && node.node_public_key == new_key));
+        assert_eq!(
+            SubnetEmissionMod::retained_decryption_keys(&node_id),
+            vec![old_key.clone()]
+        );
+
+        assert_eq!(
+            pallet_subnet_emission::DecryptionKeyRotations::<Test>::get(node_id),
+            Some(vec![(old_key.clone(), vec![netuid])])
+        );
+
+        // further pings keep the old key until the weights are gone
+        SubnetEmissionMod::handle_authority_node_ping(node_id, new_key.clone());
+        assert_eq!(
+            SubnetEmissionMod::retained_decryption_keys(&node_id),
+            vec![old_key.clone()]
+        );
+
+        // another subnet gets weights encrypted to the new key, then the node rotates again
+        let other_netuid = 1;
+        let other_acc_id = 2;
+        let _ = register_module(other_netuid, other_acc_id, 10000, false).unwrap();
+        let other_uid = SubspaceMod::<Test>::get_uid_for_key(other_netuid, &other_acc_id).unwrap();
+        pallet_subnet_emission::SubnetDecryptionData::<Test>::set(
+            other_netuid,
+            Some(SubnetDecryptionInfo {
+                validity_block: Some(0),
+                node_id,
+                node_public_key: new_key.clone(),
+                last_keep_alive: 0,
+                rotating_from: None,
+            }),
+        );
+        pallet_subnet_emission::WeightEncryptionData::<Test>::set(
+            other_netuid,
+            other_uid,
+            Some(pallet_subnet_emission::EncryptionMechanism {
+                encrypted: vec![42],
+                decrypted_hashes: vec![123],
+            }),
+        );
+        pallet_subnet_emission::ConsensusParameters::<Test>::insert(
+            other_netuid,
+            0,
+            ConsensusParams::<Test>::new(other_netuid, 0).unwrap(),
+        );
+
+        let newest_key = (b"x25519".to_vec(), vec![3; 32]);
+        SubnetEmissionMod::handle_authority_node_ping(node_id, newest_key);
+        assert_eq!(
+            SubnetEmissionMod::retained_decryption_keys(&node_id),
+            vec![old_key, new_key.clone()]
+        );
+
+        // dropping the first subnet's weights only releases the key it was waiting on
+        pallet_subspace::UseWeightsEncryption::<Test>::set(netuid, false);
+        assert_eq!(SubnetEmissionMod::clear_hanging_subnet_state(), 1);
+        assert_eq!(
+            SubnetEmissionMod::retained_decryption_keys(&node_id),
+            vec![new_key]
+        );
+
+        pallet_subspace::UseWeightsEncryption::<Test>::set(other_netuid, false);
+        assert_eq!(SubnetEmissionMod::clear_hanging_subnet_state(), 1);
+        assert!(SubnetEmissionMod::retained_decryption_keys(&node_id).is_empty());
+        assert!(!pallet_subnet_emission::DecryptionKeyRotations::<Test>::contains_key(node_id));
+    });
+}
+
+#[test]
+fn decryption_node_bond_is_slashed_and_released() {
+    use sp_core::Get;
+    new_test_ext().execute_with(|| {
+        let netuid = 0;
+        let node_id = 1001;
+        let key = (b"x25519".to_vec(), vec![1; 32]);
+        let bond = to_nano(10_000);
+        let treasury = DaoTreasuryAddress::<Test>::get();
+
+        add_balance(node_id, to_nano(20_000));
+
+        // authorities have to be bonded
+        assert_err!(
+            OffWorkerMod::add_authorities(RuntimeOrigin::root(), vec![(node_id, key.clone())]),
+            pallet_offworker::Error::<Test>::DecryptionNodeNotBonded
+        );
+        assert_err!(
+            OffWorkerMod::bond_decryption_node(get_origin(node_id), to_nano(30_000)),
+            pallet_subspace::Error::<Test>::BalanceCouldNotBeRemoved
+        );
+        assert_ok!(OffWorkerMod::bond_decryption_node(
+            get_origin(node_id),
+            bond
+        ));
+        assert_eq!(get_balance(node_id), to_nano(10_000));
+        assert_ok!(OffWorkerMod::add_authorities(
+            RuntimeOrigin::root(),
+            vec![(node_id, key.clone())]
+        ));
+
+        SubnetEmissionMod::handle_authority_node_ping(node_id, key.clone());
+        assert!(pallet_subnet_emission::DecryptionNodes::<Test>::get()
+            .iter()
+            .any(|node| node.node_id == node_id));
+
+        // a missed decryption costs 10% of the bond, paid to the treasury
+        let treasury_balance = get_balance(treasury);
+        SubnetEmissionMod::slash_decryption_node(
+            &node_id,
+            netuid,
+            DecryptionNodeFaultKind::MissedDecryption,
+        );
+
+        let slashed = bond / 10;
+        assert_eq!(get_balance(treasury), treasury_balance + slashed);
+        assert_eq!(
+            pallet_subnet_emission::DecryptionNodeBonds::<Test>::get(node_id).active,
+            bond - slashed
+        );
+        let faults = pallet_subnet_emission::DecryptionNodeFaults::<Test>::get(node_id);
+        assert_eq!(faults.len(), 1);
+        assert_eq!(faults[0].kind, DecryptionNodeFaultKind::MissedDecryption);
+        assert_eq!(faults[0].slashed, slashed);
+
+        // below the minimum bond the node gets no new subnets until it tops up
+        assert!(!SubnetEmissionMod::is_decryption_node_bonded(&node_id));
+        assert!(pallet_subnet_emission::DecryptionNodes::<Test>::get().is_empty());
+        SubnetEmissionMod::handle_authority_node_ping(node_id, key.clone());
+        assert!(pallet_subnet_emission::DecryptionNodes::<Test>::get().is_empty());
+
+        assert_ok!(OffWorkerMod::bond_decryption_node(
+            get_origin(node_id),
+            slashed
+        ));
+        SubnetEmissionMod::handle_authority_node_ping(node_id, key);
+        assert_eq!(
+            pallet_subnet_emission::DecryptionNodes::<Test>::get().len(),
+            1
+        );
+
+        // leaving starts the unbonding period, the bond stays slashable until released
+        assert_ok!(OffWorkerMod::unbond_decryption_node(get_origin(node_id)));
+        assert!(Authorities::<Test>::get().is_empty());
+        assert!(pallet_subnet_emission::DecryptionNodes::<Test>::get().is_empty());
+
+        let release_block = SubspaceMod::<Test>::get_current_block_number()
+            + <Test as pallet_subnet_emission::Config>::DecryptionNodeUnbondingPeriod::get();
+        assert_eq!(
+            pallet_subnet_emission::DecryptionNodeBonds::<Test>::get(node_id),
+            DecryptionNodeBond {
+                active: 0,
+                unbonding: bond,
+                release_block: Some(release_block),
+            }
+        );
+        assert_err!(
+            OffWorkerMod::unbond_decryption_node(get_origin(node_id)),
+            pallet_offworker::Error::<Test>::DecryptionNodeNotBonded
+        );
+
+        SubnetEmissionMod::slash_decryption_node(
+            &node_id,
+            netuid,
+            DecryptionNodeFaultKind::MissedDecryption,
+        );
+        let unbonding = bond - bond / 10;
+
+        System::set_block_number(release_block - 2);
+        step_block(1);
+        assert_eq!(get_balance(node_id), to_nano(10_000) - slashed);
+
+        step_block(1);
+        assert_eq!(get_balance(node_id), to_nano(10_000) - slashed + unbonding);
+        assert!(!pallet_subnet_emission::DecryptionNodeBonds::<Test>::contains_key(node_id));
+        assert_eq!(
+            pallet_subnet_emission::DecryptionNodeFaults::<Test>::get(node_id).len(),
+            2
+        );
+    });
+}
+
+#[test]
+fn unbonded_authorities_get_a_grace_period_and_can_leave() {
+    use sp_core::Get;
+    new_test_ext().execute_with(|| {
+        let node_id = 1001;
+        let key = (b"x25519".to_vec(), vec![1; 32]);
+
+        // an authority added before decryption node bonds existed
+        Authorities::<Test>::set(BoundedVec::truncate_from(vec![(node_id, key.clone())]));
+
+        StorageVersion::new(3).put::<SubnetEmissionMod>();
+        MigrateToV4::<Test>::on_runtime_upgrade();
+
+        let grace_end = SubspaceMod::<Test>::get_current_block_number()
+            + <Test as pallet_subnet_emission::Config>::DecryptionNodeBondGracePeriod::get();
+        assert_eq!(
+            pallet_subnet_emission::DecryptionNodeBondGrace::<Test>::get(node_id),
+            Some(grace_end)
+        );
+
+        // it keeps receiving subnets during the grace period
+        SubnetEmissionMod::handle_authority_node_ping(node_id, key.clone());
+        assert_eq!(
+            pallet_subnet_emission::DecryptionNodes::<Test>::get().len(),
+            1
+        );
+
+        // and can leave without ever bonding
+        assert_ok!(OffWorkerMod::unbond_decryption_node(get_origin(node_id)));
+        assert!(Authorities::<Test>::get().is_empty());
+        assert!(pallet_subnet_emission::DecryptionNodes::<Test>::get().is_empty());
+        assert!(!pallet_subnet_emission::DecryptionNodeBondGrace::<Test>::contains_key(node_id));
+        assert!(!pallet_subnet_emission::DecryptionNodeBonds::<Test>::contains_key(node_id));
+        assert_err!(
+            OffWorkerMod::unbond_decryption_node(get_origin(node_id)),
+            pallet_offworker::Error::<Test>::DecryptionNodeNotBonded
+        );
+    });
+}
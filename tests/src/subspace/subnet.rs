@@ -68,6 +68,8 @@ fn subnet_update_changes_all_parameter_values() {
                 proposal_reward_treasury_allocation: Percent::from_parts(20),
                 max_proposal_reward_treasury_allocation: 21,
                 proposal_reward_interval: 22,
+                quorum: Percent::from_parts(23),
+                approval_thresholds: Default::default(),
             },
             module_burn_config: GeneralBurnConfiguration {
                 min_burn: to_nano(15),
@@ -81,6 +83,9 @@ fn subnet_update_changes_all_parameter_values() {
             use_weights_encryption: true,
             copier_margin: I64F64::from_num(0),
             max_encryption_period: Some(1000),
+            copier_stake_fraction: Percent::from_percent(29),
+            copier_bonds_averaging: BondsAveraging::Uniform,
+            copier_delegation_fee: Some(Percent::from_percent(30)),
         };
 
         let SubnetParams {
@@ -104,6 +109,9 @@ fn subnet_update_changes_all_parameter_values() {
             use_weights_encryption,
             copier_margin,
             max_encryption_period,
+            copier_stake_fraction,
+            copier_bonds_averaging,
+            copier_delegation_fee,
         } = params.clone();
 
         SubnetChangeset::<Test>::update(netuid, params).unwrap().apply(netuid).unwrap();
@@ -147,6 +155,54 @@ fn subnet_update_changes_all_parameter_values() {
             MaxEncryptionPeriod::<Test>::get(netuid),
             max_encryption_period
         );
+        assert_eq!(
+            CopierStakeFraction::<Test>::get(netuid),
+            copier_stake_fraction
+        );
+        assert_eq!(
+            CopierBondsAveraging::<Test>::get(netuid),
+            copier_bonds_averaging
+        );
+        assert_eq!(
+            CopierDelegationFee::<Test>::get(netuid),
+            copier_delegation_fee
+        );
+    });
+}
+
+#[test]
+fn subnet_update_validates_copier_simulation_params() {
+    new_test_ext().execute_with(|| {
+        zero_min_burn();
+
+        let netuid = 0;
+        assert_ok!(register_module(netuid, 0, to_nano(10), false));
+
+        let update = |change: fn(&mut SubnetParams<Test>)| {
+            let mut params = SubspaceMod::subnet_params(netuid);
+            change(&mut params);
+            SubnetChangeset::<Test>::update(netuid, params).map(|_| ())
+        };
+
+        assert_err!(
+            update(|params| params.copier_stake_fraction = Percent::from_percent(0)),
+            Error::<Test>::InvalidCopierStakeFraction
+        );
+        assert_err!(
+            update(|params| params.copier_stake_fraction = Percent::from_percent(51)),
+            Error::<Test>::InvalidCopierStakeFraction
+        );
+        assert_ok!(update(
+            |params| params.copier_stake_fraction = Percent::from_percent(50)
+        ));
+
+        assert!(MinFees::<Test>::get().stake_delegation_fee > Percent::from_percent(0));
+        assert_err!(
+            update(|params| params.copier_delegation_fee = Some(Percent::from_percent(0))),
+            Error::<Test>::InvalidCopierDelegationFee
+        );
+        assert_ok!(update(|params| params.copier_delegation_fee =
+            Some(MinFees::<Test>::get().stake_delegation_fee)));
     });
 }
 
@@ -253,6 +309,9 @@ fn update_subnet_verifies_names_uniquiness_integrity() {
                 params.use_weights_encryption,
                 params.copier_margin,
                 params.max_encryption_period,
+                params.copier_stake_fraction,
+                params.copier_bonds_averaging,
+                params.copier_delegation_fee,
             )
         };
 
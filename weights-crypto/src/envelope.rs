@@ -0,0 +1,479 @@
+//! Versioned envelope for encrypted weights.
+//!
+//! Weights used to be encrypted by splitting the payload into RSA PKCS#1 v1.5 chunks, which
+//! produces one RSA block per ~50 bytes of weights. The envelope instead encrypts the payload once
+//! with a symmetric AEAD cipher and only wraps (or derives) the 32-byte symmetric key with the
+//! decryption node's public key:
+//!
+//! ```text
+//! | "SWE" | version: u8 | key scheme: u8 | cipher: u8 | key material | nonce: [u8; 12] | body |
+//! ```
+//!
+//! - [`KeyScheme::RsaOaep`]: key material is a big-endian `u16` length followed by the symmetric
+//!   key encrypted with RSA-OAEP (SHA-256).
+//! - [`KeyScheme::X25519`]: key material is an ephemeral X25519 public key, the symmetric key is
+//!   derived with HKDF-SHA256 from the shared secret.
+//! - [`KeyScheme::Threshold`]: key material is an ephemeral Ristretto point, the symmetric key is
+//!   derived from the ElGamal shared secret of a committee key, see [`crate::threshold`].
+//!
+//! The header is authenticated as associated data of the body. Ciphertexts without the header are
+//! decrypted with the legacy chunked RSA format, so weights submitted before the migration stay
+//! decodable.
+
+use std::io::{Cursor, Read};
+
+use aes_gcm::Aes256Gcm;
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+};
+use hkdf::Hkdf;
+use rand::{rngs::OsRng, RngCore};
+use rsa::{
+    pkcs1::{DecodeRsaPrivateKey, EncodeRsaPrivateKey},
+    pkcs8::LineEnding,
+    traits::PublicKeyParts,
+    BigUint, Oaep, Pkcs1v15Encrypt, RsaPrivateKey, RsaPublicKey,
+};
+use sha2::Sha256;
+
+use crate::{threshold::KeyShare, DecryptedWeights, EncryptionKey};
+
+pub const MAGIC: [u8; 3] = *b"SWE";
+pub const VERSION: u8 = 1;
+
+/// First element of an [`EncryptionKey`] holding an X25519 key. RSA keys store their modulus
+/// there, which is never this short.
+pub const X25519_KEY_TAG: &[u8] = b"x25519";
+/// First element of an [`EncryptionKey`] holding a decryption committee key.
+pub const THRESHOLD_KEY_TAG: &[u8] = b"threshold";
+
+const HEADER_LEN: usize = 6;
+const NONCE_LEN: usize = 12;
+const SYMMETRIC_KEY_LEN: usize = 32;
+const KDF_INFO: &[u8] = b"subspace-weights-envelope-v1";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyScheme {
+    RsaOaep = 1,
+    X25519 = 2,
+    Threshold = 3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cipher {
+    ChaCha20Poly1305 = 1,
+    Aes256Gcm = 2,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EnvelopeError {
+    InvalidPublicKey,
+    UnsupportedVersion(u8),
+    UnsupportedKeyScheme(u8),
+    UnsupportedCipher(u8),
+    /// The envelope was sealed for a different kind of key than the one decrypting it
+    KeySchemeMismatch,
+    Truncated,
+    Encryption,
+    Decryption,
+}
+
+impl KeyScheme {
+    fn from_id(id: u8) -> Result<Self, EnvelopeError> {
+        match id {
+            1 => Ok(Self::RsaOaep),
+            2 => Ok(Self::X25519),
+            3 => Ok(Self::Threshold),
+            id => Err(EnvelopeError::UnsupportedKeyScheme(id)),
+        }
+    }
+
+    /// The scheme a node announced through its public key. Keys nothing can be encrypted to,
+    /// like the verification key of a committee member, have none.
+    pub fn of(public_key: &EncryptionKey) -> Option<Self> {
+        match public_key.0.as_slice() {
+            X25519_KEY_TAG => Some(Self::X25519),
+            THRESHOLD_KEY_TAG => Some(Self::Threshold),
+            crate::threshold::SHARE_KEY_TAG => None,
+            _ => Some(Self::RsaOaep),
+        }
+    }
+}
+
+impl Cipher {
+    fn from_id(id: u8) -> Result<Self, EnvelopeError> {
+        match id {
+            1 => Ok(Self::ChaCha20Poly1305),
+            2 => Ok(Self::Aes256Gcm),
+            id => Err(EnvelopeError::UnsupportedCipher(id)),
+        }
+    }
+
+    fn seal(
+        self,
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        msg: &[u8],
+    ) -> Result<Vec<u8>, EnvelopeError> {
+        let payload = Payload { msg, aad };
+        match self {
+            Self::ChaCha20Poly1305 => ChaCha20Poly1305::new_from_slice(key)
+                .map_err(|_| EnvelopeError::Encryption)?
+                .encrypt(nonce.into(), payload),
+            Self::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+                .map_err(|_| EnvelopeError::Encryption)?
+                .encrypt(nonce.into(), payload),
+        }
+        .map_err(|_| EnvelopeError::Encryption)
+    }
+
+    fn open(
+        self,
+        key: &[u8],
+        nonce: &[u8; NONCE_LEN],
+        aad: &[u8],
+        msg: &[u8],
+    ) -> Result<Vec<u8>, EnvelopeError> {
+        let payload = Payload { msg, aad };
+        match self {
+            Self::ChaCha20Poly1305 => ChaCha20Poly1305::new_from_slice(key)
+                .map_err(|_| EnvelopeError::Decryption)?
+                .decrypt(nonce.into(), payload),
+            Self::Aes256Gcm => Aes256Gcm::new_from_slice(key)
+                .map_err(|_| EnvelopeError::Decryption)?
+                .decrypt(nonce.into(), payload),
+        }
+        .map_err(|_| EnvelopeError::Decryption)
+    }
+}
+
+/// Private key of a decryption node.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)] // a node holds a handful of keys at most
+pub enum DecryptionKey {
+    Rsa(RsaPrivateKey),
+    X25519(x25519_dalek::StaticSecret),
+    /// Share of a committee key, it can only decrypt together with other members
+    ThresholdShare(KeyShare),
+}
+
+impl DecryptionKey {
+    pub fn generate_x25519() -> Self {
+        Self::X25519(x25519_dalek::StaticSecret::random_from_rng(OsRng))
+    }
+
+    /// Parses a PKCS#1 PEM RSA key, a hex encoded X25519 secret or a committee key share.
+    pub fn parse(content: &str) -> Option<Self> {
+        if let Ok(key) = RsaPrivateKey::from_pkcs1_pem(content) {
+            return Some(Self::Rsa(key));
+        }
+        if let Some(share) = KeyShare::parse(content) {
+            return Some(Self::ThresholdShare(share));
+        }
+
+        let bytes: [u8; 32] = hex::decode(content.trim()).ok()?.try_into().ok()?;
+        Some(Self::X25519(bytes.into()))
+    }
+
+    /// Inverse of [`DecryptionKey::parse`].
+    pub fn serialize(&self) -> Option<String> {
+        match self {
+            Self::Rsa(key) => key.to_pkcs1_pem(LineEnding::LF).ok().map(|pem| pem.to_string()),
+            Self::X25519(secret) => Some(hex::encode(secret.to_bytes())),
+            Self::ThresholdShare(share) => Some(share.serialize()),
+        }
+    }
+
+    pub fn public_key(&self) -> EncryptionKey {
+        match self {
+            Self::Rsa(key) => {
+                let public = RsaPublicKey::from(key);
+                (public.n().to_bytes_be(), public.e().to_bytes_be())
+            }
+            Self::X25519(secret) => (
+                X25519_KEY_TAG.to_vec(),
+                x25519_dalek::PublicKey::from(secret).as_bytes().to_vec(),
+            ),
+            Self::ThresholdShare(share) => share.public_key(),
+        }
+    }
+
+    /// Decrypts an envelope, falling back to the legacy chunked RSA format.
+    pub fn open(&self, data: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+        if data.starts_with(&MAGIC) {
+            match self.open_envelope(data) {
+                Ok(plaintext) => return Ok(plaintext),
+                // legacy ciphertexts can start with the magic by chance
+                Err(err) if !matches!(self, Self::Rsa(_)) => return Err(err),
+                Err(_) => {}
+            }
+        }
+
+        match self {
+            Self::Rsa(key) => open_legacy(key, data),
+            Self::X25519(_) | Self::ThresholdShare(_) => Err(EnvelopeError::KeySchemeMismatch),
+        }
+    }
+
+    fn open_envelope(&self, data: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+        let Parsed {
+            header,
+            scheme,
+            cipher,
+            rest,
+        } = parse(data)?;
+
+        let (symmetric_key, rest) = match (scheme, self) {
+            (KeyScheme::RsaOaep, Self::Rsa(key)) => {
+                let len: [u8; 2] = rest
+                    .get(..2)
+                    .ok_or(EnvelopeError::Truncated)?
+                    .try_into()
+                    .map_err(|_| EnvelopeError::Truncated)?;
+                let len = usize::from(u16::from_be_bytes(len));
+                let rest = rest.get(2..).ok_or(EnvelopeError::Truncated)?;
+                let wrapped = rest.get(..len).ok_or(EnvelopeError::Truncated)?;
+
+                let symmetric_key = key
+                    .decrypt(Oaep::new::<Sha256>(), wrapped)
+                    .map_err(|_| EnvelopeError::Decryption)?;
+                (
+                    symmetric_key,
+                    rest.get(len..).ok_or(EnvelopeError::Truncated)?,
+                )
+            }
+            (KeyScheme::X25519, Self::X25519(secret)) => {
+                let ephemeral: [u8; 32] = rest
+                    .get(..32)
+                    .ok_or(EnvelopeError::Truncated)?
+                    .try_into()
+                    .map_err(|_| EnvelopeError::Truncated)?;
+                let ephemeral = x25519_dalek::PublicKey::from(ephemeral);
+                let recipient = x25519_dalek::PublicKey::from(secret);
+
+                let shared = secret.diffie_hellman(&ephemeral);
+                let symmetric_key = derive_key(
+                    shared.as_bytes(),
+                    ephemeral.as_bytes(),
+                    recipient.as_bytes(),
+                )?;
+                (
+                    symmetric_key.to_vec(),
+                    rest.get(32..).ok_or(EnvelopeError::Truncated)?,
+                )
+            }
+            _ => return Err(EnvelopeError::KeySchemeMismatch),
+        };
+
+        open_body(cipher, &symmetric_key, header, rest)
+    }
+}
+
+/// An envelope split at its header.
+pub(crate) struct Parsed<'a> {
+    pub header: &'a [u8],
+    pub scheme: KeyScheme,
+    pub cipher: Cipher,
+    /// Key material, nonce and body
+    pub rest: &'a [u8],
+}
+
+pub(crate) fn parse(data: &[u8]) -> Result<Parsed<'_>, EnvelopeError> {
+    let header = data.get(..HEADER_LEN).ok_or(EnvelopeError::Truncated)?;
+    let rest = data.get(HEADER_LEN..).ok_or(EnvelopeError::Truncated)?;
+    let [m0, m1, m2, version, scheme, cipher] = header else {
+        return Err(EnvelopeError::Truncated);
+    };
+
+    if [*m0, *m1, *m2] != MAGIC {
+        return Err(EnvelopeError::Truncated);
+    }
+    if *version != VERSION {
+        return Err(EnvelopeError::UnsupportedVersion(*version));
+    }
+
+    Ok(Parsed {
+        header,
+        scheme: KeyScheme::from_id(*scheme)?,
+        cipher: Cipher::from_id(*cipher)?,
+        rest,
+    })
+}
+
+/// Decrypts the nonce and body following the key material.
+pub(crate) fn open_body(
+    cipher: Cipher,
+    symmetric_key: &[u8],
+    header: &[u8],
+    rest: &[u8],
+) -> Result<Vec<u8>, EnvelopeError> {
+    let nonce: [u8; NONCE_LEN] = rest
+        .get(..NONCE_LEN)
+        .ok_or(EnvelopeError::Truncated)?
+        .try_into()
+        .map_err(|_| EnvelopeError::Truncated)?;
+    let body = rest.get(NONCE_LEN..).ok_or(EnvelopeError::Truncated)?;
+
+    cipher.open(symmetric_key, &nonce, header, body)
+}
+
+/// Encrypts the plaintext to the public key announced by a decryption node.
+pub fn seal(
+    public_key: &EncryptionKey,
+    cipher: Cipher,
+    plaintext: &[u8],
+) -> Result<Vec<u8>, EnvelopeError> {
+    let scheme = KeyScheme::of(public_key).ok_or(EnvelopeError::InvalidPublicKey)?;
+    let header = [
+        MAGIC[0],
+        MAGIC[1],
+        MAGIC[2],
+        VERSION,
+        scheme as u8,
+        cipher as u8,
+    ];
+
+    let mut symmetric_key = [0u8; SYMMETRIC_KEY_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce);
+
+    let mut envelope = header.to_vec();
+    match scheme {
+        KeyScheme::RsaOaep => {
+            let key = RsaPublicKey::new(
+                BigUint::from_bytes_be(&public_key.0),
+                BigUint::from_bytes_be(&public_key.1),
+            )
+            .map_err(|_| EnvelopeError::InvalidPublicKey)?;
+
+            OsRng.fill_bytes(&mut symmetric_key);
+            let wrapped = key
+                .encrypt(&mut OsRng, Oaep::new::<Sha256>(), &symmetric_key)
+                .map_err(|_| EnvelopeError::Encryption)?;
+            let len = u16::try_from(wrapped.len()).map_err(|_| EnvelopeError::InvalidPublicKey)?;
+
+            envelope.extend(len.to_be_bytes());
+            envelope.extend(wrapped);
+        }
+        KeyScheme::X25519 => {
+            let recipient: [u8; 32] =
+                public_key.1.clone().try_into().map_err(|_| EnvelopeError::InvalidPublicKey)?;
+            let recipient = x25519_dalek::PublicKey::from(recipient);
+
+            let secret = x25519_dalek::EphemeralSecret::random_from_rng(OsRng);
+            let ephemeral = x25519_dalek::PublicKey::from(&secret);
+            let shared = secret.diffie_hellman(&recipient);
+
+            symmetric_key = derive_key(
+                shared.as_bytes(),
+                ephemeral.as_bytes(),
+                recipient.as_bytes(),
+            )?;
+
+            envelope.extend(ephemeral.as_bytes());
+        }
+        KeyScheme::Threshold => {
+            let (key, ephemeral) = crate::threshold::encapsulate(&public_key.1)?;
+            symmetric_key = key;
+
+            envelope.extend(ephemeral);
+        }
+    }
+
+    let body = cipher.seal(&symmetric_key, &nonce, &header, plaintext)?;
+    envelope.extend(nonce);
+    envelope.extend(body);
+
+    Ok(envelope)
+}
+
+pub(crate) fn derive_key(
+    shared_secret: &[u8],
+    ephemeral: &[u8],
+    recipient: &[u8],
+) -> Result<[u8; SYMMETRIC_KEY_LEN], EnvelopeError> {
+    let salt = [ephemeral, recipient].concat();
+
+    let mut key = [0u8; SYMMETRIC_KEY_LEN];
+    Hkdf::<Sha256>::new(Some(&salt), shared_secret)
+        .expand(KDF_INFO, &mut key)
+        .map_err(|_| EnvelopeError::Encryption)?;
+
+    Ok(key)
+}
+
+/// Decrypts the pre-envelope format: the payload split into RSA PKCS#1 v1.5 blocks.
+pub fn open_legacy(key: &RsaPrivateKey, data: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+    data.chunks(key.size())
+        .map(|chunk| key.decrypt(Pkcs1v15Encrypt, chunk))
+        .collect::<Result<Vec<Vec<u8>>, _>>()
+        .map(|chunks| chunks.concat())
+        .map_err(|_| EnvelopeError::Decryption)
+}
+
+/// Encrypts with the pre-envelope format. Only kept so clients and tests can produce legacy
+/// ciphertexts during the migration.
+pub fn seal_legacy(public_key: &EncryptionKey, plaintext: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+    let key = RsaPublicKey::new(
+        BigUint::from_bytes_be(&public_key.0),
+        BigUint::from_bytes_be(&public_key.1),
+    )
+    .map_err(|_| EnvelopeError::InvalidPublicKey)?;
+
+    // 11 bytes for PKCS1v15 padding
+    let max_chunk_size = key.size().saturating_sub(11).max(1);
+
+    plaintext
+        .chunks(max_chunk_size)
+        .map(|chunk| key.encrypt(&mut OsRng, Pkcs1v15Encrypt, chunk))
+        .collect::<Result<Vec<Vec<u8>>, _>>()
+        .map(|chunks| chunks.concat())
+        .map_err(|_| EnvelopeError::Encryption)
+}
+
+/// Plaintext layout of encrypted weights: a big-endian `u32` length, the `(uid, weight)` pairs and
+/// the validator key in the remaining bytes.
+pub fn encode_weights(weights: &[(u16, u16)], validator_key: &[u8]) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    encoded.extend((weights.len() as u32).to_be_bytes());
+    encoded.extend(
+        weights
+            .iter()
+            .flat_map(|(uid, weight)| uid.to_be_bytes().into_iter().chain(weight.to_be_bytes())),
+    );
+    encoded.extend(validator_key);
+
+    encoded
+}
+
+pub fn decode_weights(plaintext: &[u8]) -> Option<DecryptedWeights> {
+    let mut cursor = Cursor::new(plaintext);
+    let length = read_u32(&mut cursor)?;
+
+    let mut weights = Vec::with_capacity(length.min(u16::MAX as u32) as usize);
+    for _ in 0..length {
+        let uid = read_u16(&mut cursor)?;
+        let weight = read_u16(&mut cursor)?;
+
+        weights.push((uid, weight));
+    }
+
+    let mut key = Vec::new();
+    cursor.read_to_end(&mut key).ok()?;
+
+    Some((weights, key))
+}
+
+fn read_u32(cursor: &mut Cursor<&[u8]>) -> Option<u32> {
+    let mut buf = [0u8; 4];
+    cursor.read_exact(&mut buf[..]).ok()?;
+    Some(u32::from_be_bytes(buf))
+}
+
+fn read_u16(cursor: &mut Cursor<&[u8]>) -> Option<u16> {
+    let mut buf = [0u8; 2];
+    cursor.read_exact(&mut buf[..]).ok()?;
+    Some(u16::from_be_bytes(buf))
+}
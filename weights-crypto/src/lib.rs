@@ -0,0 +1,110 @@
+//! Client side of weight encryption.
+//!
+//! Validators of subnets with weight encryption enabled submit their weights encrypted to the
+//! subnet's decryption node, together with the hash of the plaintext weights the runtime checks
+//! the decrypted weights against. This crate builds both, and does not depend on the runtime, so
+//! it can be used by native clients as well as compiled to wasm for browsers.
+//!
+//! ```ignore
+//! let key = subspace_weights_crypto::fetch_node_public_key::<AccountId, _, _, _>(netuid, |key| {
+//!     rpc.request("state_getStorage", (hex_key(&key),))
+//! })
+//! .await?
+//! .ok_or("subnet has no decryption node")?;
+//!
+//! let EncryptedWeights { encrypted, decrypted_weights_hash } =
+//!     encrypt_weights(&key, &weights, &validator.encode(), Cipher::ChaCha20Poly1305)?;
+//! ```
+
+use std::future::Future;
+
+use parity_scale_codec::{Decode, Encode};
+
+pub mod envelope;
+pub mod threshold;
+
+pub use envelope::{Cipher, EnvelopeError};
+
+/// (Decrypted Weights, Key)
+pub type DecryptedWeights = (Vec<(u16, u16)>, Vec<u8>);
+
+pub type EncryptionKey = (Vec<u8>, Vec<u8>);
+
+/// Name the subnet emission pallet has in the runtime.
+pub const PALLET_PREFIX: &[u8] = b"SubnetEmissionModule";
+
+/// Storage item holding the decryption node assigned to each subnet.
+pub const DECRYPTION_DATA_STORAGE: &[u8] = b"SubnetDecryptionData";
+
+/// Weights ready for `set_weights_encrypted`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EncryptedWeights {
+    pub encrypted: Vec<u8>,
+    pub decrypted_weights_hash: Vec<u8>,
+}
+
+/// Storage key of the subnet's entry in `SubnetDecryptionData`, to query with `state_getStorage`.
+pub fn decryption_data_key(netuid: u16) -> Vec<u8> {
+    decryption_data_key_for(PALLET_PREFIX, netuid)
+}
+
+/// Same as [`decryption_data_key`], for runtimes that name the subnet emission pallet differently.
+pub fn decryption_data_key_for(pallet_prefix: &[u8], netuid: u16) -> Vec<u8> {
+    [
+        sp_crypto_hashing::twox_128(pallet_prefix).as_slice(),
+        sp_crypto_hashing::twox_128(DECRYPTION_DATA_STORAGE).as_slice(),
+        // the map is keyed with the `Identity` hasher
+        netuid.encode().as_slice(),
+    ]
+    .concat()
+}
+
+/// Decodes the node public key out of a raw `SubnetDecryptionInfo` value.
+///
+/// Only the leading `node_id` and `node_public_key` fields are read, so later additions to the
+/// struct do not break clients.
+pub fn decode_node_public_key<AccountId: Decode>(mut value: &[u8]) -> Option<EncryptionKey> {
+    let (_node_id, public_key) = <(AccountId, EncryptionKey)>::decode(&mut value).ok()?;
+    Some(public_key)
+}
+
+/// Fetches the public key of the subnet's decryption node.
+///
+/// `get_storage` reads a raw storage value by key, usually through the node's `state_getStorage`
+/// RPC. Returns `None` if no decryption node is assigned to the subnet.
+pub async fn fetch_node_public_key<AccountId, F, Fut, E>(
+    netuid: u16,
+    get_storage: F,
+) -> Result<Option<EncryptionKey>, E>
+where
+    AccountId: Decode,
+    F: FnOnce(Vec<u8>) -> Fut,
+    Fut: Future<Output = Result<Option<Vec<u8>>, E>>,
+{
+    let value = get_storage(decryption_data_key(netuid)).await?;
+    Ok(value.and_then(|value| decode_node_public_key::<AccountId>(&value)))
+}
+
+/// Hash the runtime expects for the decrypted weights: sha256 of the weights encoded like the
+/// encrypted payload, without the validator key.
+pub fn weights_hash(weights: &[(u16, u16)]) -> Vec<u8> {
+    sp_crypto_hashing::sha2_256(&envelope::encode_weights(weights, &[])).to_vec()
+}
+
+/// Encrypts the weights of a validator to the decryption node key.
+///
+/// `validator_key` is the SCALE encoded account the weights are set for, the runtime discards
+/// weights whose decrypted key does not match the submitter.
+pub fn encrypt_weights(
+    node_public_key: &EncryptionKey,
+    weights: &[(u16, u16)],
+    validator_key: &[u8],
+    cipher: Cipher,
+) -> Result<EncryptedWeights, EnvelopeError> {
+    let payload = envelope::encode_weights(weights, validator_key);
+
+    Ok(EncryptedWeights {
+        encrypted: envelope::seal(node_public_key, cipher, &payload)?,
+        decrypted_weights_hash: weights_hash(weights),
+    })
+}
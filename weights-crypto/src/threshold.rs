@@ -0,0 +1,289 @@
+//! t-of-n threshold decryption of weight envelopes.
+//!
+//! Weights are encrypted to a committee key `Y = s·G` on Ristretto, whose secret `s` is split with
+//! Shamir's scheme among the committee members, member `i` holding `s_i = f(i)`. Sealing picks an
+//! ephemeral `r` and derives the symmetric key from `r·Y`, publishing `R = r·G` as the envelope's
+//! key material.
+//!
+//! No member can open an envelope alone. Each one publishes a decryption share `s_i·R`, and any
+//! `threshold` of them are combined with Lagrange interpolation at zero into `s·R = r·Y`.
+//!
+//! Every share carries a Chaum-Pedersen proof that it uses the same secret as the member's
+//! verification key `s_i·G`. Shares failing it are dropped before combining, so a single bad
+//! member can not keep the others from decrypting.
+#![allow(clippy::arithmetic_side_effects)] // group and field arithmetic is modular
+
+use curve25519_dalek::{
+    constants::RISTRETTO_BASEPOINT_POINT,
+    ristretto::{CompressedRistretto, RistrettoPoint},
+    scalar::Scalar,
+    traits::Identity,
+};
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha512};
+
+use crate::{
+    envelope::{self, EnvelopeError, KeyScheme, Parsed, THRESHOLD_KEY_TAG},
+    EncryptionKey,
+};
+
+/// First element of the [`EncryptionKey`] a committee member announces: its share index and
+/// verification key `s_i·G`.
+pub const SHARE_KEY_TAG: &[u8] = b"threshold-share";
+
+const POINT_LEN: usize = 32;
+const SCALAR_LEN: usize = 32;
+
+/// Domain separator of the share proofs' challenges.
+const PROOF_DOMAIN: &[u8] = b"subspace-threshold-share-proof";
+
+/// Secret share of a committee key.
+#[derive(Clone)]
+pub struct KeyShare {
+    /// Evaluation point of the share, never zero
+    pub index: u16,
+    pub secret: Scalar,
+}
+
+impl KeyShare {
+    /// Parses a share in the `threshold:<index>:<hex encoded scalar>` format.
+    pub fn parse(content: &str) -> Option<Self> {
+        let mut parts = content.trim().split(':');
+        if parts.next()? != "threshold" {
+            return None;
+        }
+
+        let index: u16 = parts.next()?.parse().ok()?;
+        let bytes: [u8; 32] = hex::decode(parts.next()?).ok()?.try_into().ok()?;
+        let secret = Option::from(Scalar::from_canonical_bytes(bytes))?;
+
+        (index != 0 && parts.next().is_none()).then_some(Self { index, secret })
+    }
+
+    /// Inverse of [`KeyShare::parse`].
+    pub fn serialize(&self) -> String {
+        format!(
+            "threshold:{}:{}",
+            self.index,
+            hex::encode(self.secret.to_bytes())
+        )
+    }
+
+    pub fn public_key(&self) -> EncryptionKey {
+        let verification = (RISTRETTO_BASEPOINT_POINT * self.secret).compress();
+        (
+            SHARE_KEY_TAG.to_vec(),
+            [self.index.to_be_bytes().as_slice(), verification.as_bytes()].concat(),
+        )
+    }
+
+    /// Computes this member's decryption share of a committee envelope, followed by the proof
+    /// that it matches the member's verification key.
+    pub fn decryption_share(&self, data: &[u8]) -> Result<Vec<u8>, EnvelopeError> {
+        let Parsed { scheme, rest, .. } = envelope::parse(data)?;
+        if scheme != KeyScheme::Threshold {
+            return Err(EnvelopeError::KeySchemeMismatch);
+        }
+
+        let ephemeral = decompress(rest.get(..POINT_LEN).ok_or(EnvelopeError::Truncated)?)
+            .ok_or(EnvelopeError::Decryption)?;
+
+        let verification = RISTRETTO_BASEPOINT_POINT * self.secret;
+        let share = ephemeral * self.secret;
+
+        let nonce = Scalar::random(&mut OsRng);
+        let challenge = proof_challenge(
+            &verification,
+            &ephemeral,
+            &share,
+            &(RISTRETTO_BASEPOINT_POINT * nonce),
+            &(ephemeral * nonce),
+        );
+        let response = nonce + challenge * self.secret;
+
+        Ok([
+            share.compress().as_bytes().as_slice(),
+            challenge.as_bytes().as_slice(),
+            response.as_bytes().as_slice(),
+        ]
+        .concat())
+    }
+}
+
+/// Splits a fresh committee key into `members` shares, any `threshold` of which can decrypt.
+///
+/// This is a trusted dealer setup, the dealer has to discard the secret afterwards.
+pub fn deal(threshold: u16, members: u16) -> Option<(EncryptionKey, Vec<KeyShare>)> {
+    if threshold == 0 || threshold > members {
+        return None;
+    }
+
+    let coefficients: Vec<Scalar> = (0..threshold).map(|_| Scalar::random(&mut OsRng)).collect();
+    let secret = *coefficients.first()?;
+
+    let shares = (1..=members)
+        .map(|index| {
+            let x = Scalar::from(u64::from(index));
+            // Horner's method, from the highest coefficient down
+            let secret = coefficients.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c);
+            KeyShare { index, secret }
+        })
+        .collect();
+
+    Some((committee_key(&(RISTRETTO_BASEPOINT_POINT * secret)), shares))
+}
+
+fn committee_key(point: &RistrettoPoint) -> EncryptionKey {
+    (
+        THRESHOLD_KEY_TAG.to_vec(),
+        point.compress().as_bytes().to_vec(),
+    )
+}
+
+/// Derives the symmetric key of a new committee envelope, returning it with the ephemeral point
+/// to publish.
+pub(crate) fn encapsulate(committee_key: &[u8]) -> Result<([u8; 32], [u8; 32]), EnvelopeError> {
+    let committee = decompress(committee_key).ok_or(EnvelopeError::InvalidPublicKey)?;
+
+    let r = Scalar::random(&mut OsRng);
+    let ephemeral = (RISTRETTO_BASEPOINT_POINT * r).compress();
+    let shared = (committee * r).compress();
+
+    let key = envelope::derive_key(shared.as_bytes(), ephemeral.as_bytes(), committee_key)?;
+    Ok((key, ephemeral.to_bytes()))
+}
+
+/// Opens a committee envelope with the decryption shares of at least `threshold` members, given
+/// with the [`KeyShare::public_key`] of the member that computed them. Shares that do not prove
+/// to match their member's key are ignored.
+pub fn combine(
+    committee_key: &EncryptionKey,
+    data: &[u8],
+    shares: &[(EncryptionKey, Vec<u8>)],
+    threshold: u16,
+) -> Result<Vec<u8>, EnvelopeError> {
+    let Parsed {
+        header,
+        scheme,
+        cipher,
+        rest,
+    } = envelope::parse(data)?;
+    if scheme != KeyScheme::Threshold || committee_key.0 != THRESHOLD_KEY_TAG {
+        return Err(EnvelopeError::KeySchemeMismatch);
+    }
+
+    let ephemeral = rest.get(..POINT_LEN).ok_or(EnvelopeError::Truncated)?;
+    let rest = rest.get(POINT_LEN..).ok_or(EnvelopeError::Truncated)?;
+    let ephemeral_point = decompress(ephemeral).ok_or(EnvelopeError::Decryption)?;
+
+    let mut points: Vec<(u16, RistrettoPoint)> = Vec::new();
+    for (member_key, share) in shares {
+        let Some((index, verification)) = parse_share_key(member_key) else {
+            continue;
+        };
+        if points.iter().any(|(other, _)| *other == index) {
+            continue;
+        }
+        if let Some(point) = verify_share(&verification, &ephemeral_point, share) {
+            points.push((index, point));
+        }
+    }
+    points.truncate(usize::from(threshold));
+    if threshold == 0 || points.len() < usize::from(threshold) {
+        return Err(EnvelopeError::Decryption);
+    }
+
+    let shared = points
+        .iter()
+        .map(|(index, point)| point * lagrange_at_zero(*index, &points))
+        .fold(RistrettoPoint::identity(), |acc, point| acc + point)
+        .compress();
+
+    let key = envelope::derive_key(shared.as_bytes(), ephemeral, &committee_key.1)?;
+    envelope::open_body(cipher, &key, header, rest)
+}
+
+/// Splits a member's [`KeyShare::public_key`] into its share index and verification key.
+fn parse_share_key(member_key: &EncryptionKey) -> Option<(u16, RistrettoPoint)> {
+    if member_key.0 != SHARE_KEY_TAG {
+        return None;
+    }
+
+    let (index, verification) = member_key.1.split_first_chunk::<2>()?;
+    let index = u16::from_be_bytes(*index);
+    if index == 0 {
+        return None;
+    }
+
+    Some((index, decompress(verification)?))
+}
+
+/// Returns the share point if its proof shows it was computed with the secret behind the
+/// verification key.
+fn verify_share(
+    verification: &RistrettoPoint,
+    ephemeral: &RistrettoPoint,
+    share: &[u8],
+) -> Option<RistrettoPoint> {
+    let (point, proof) = share.split_at_checked(POINT_LEN)?;
+    let (challenge, response) = proof.split_at_checked(SCALAR_LEN)?;
+    if response.len() != SCALAR_LEN {
+        return None;
+    }
+
+    let point = decompress(point)?;
+    let challenge: Scalar = Option::from(Scalar::from_canonical_bytes(challenge.try_into().ok()?))?;
+    let response: Scalar = Option::from(Scalar::from_canonical_bytes(response.try_into().ok()?))?;
+
+    // z·G - c·V = k·G and z·R - c·S = k·R for an honest share
+    let base_commitment = RISTRETTO_BASEPOINT_POINT * response - verification * challenge;
+    let ephemeral_commitment = ephemeral * response - point * challenge;
+    let expected = proof_challenge(
+        verification,
+        ephemeral,
+        &point,
+        &base_commitment,
+        &ephemeral_commitment,
+    );
+
+    (expected == challenge).then_some(point)
+}
+
+fn proof_challenge(
+    verification: &RistrettoPoint,
+    ephemeral: &RistrettoPoint,
+    share: &RistrettoPoint,
+    base_commitment: &RistrettoPoint,
+    ephemeral_commitment: &RistrettoPoint,
+) -> Scalar {
+    let mut hasher = Sha512::new();
+    hasher.update(PROOF_DOMAIN);
+    for point in [
+        verification,
+        ephemeral,
+        share,
+        base_commitment,
+        ephemeral_commitment,
+    ] {
+        hasher.update(point.compress().as_bytes());
+    }
+
+    let mut wide = [0u8; 64];
+    wide.copy_from_slice(&hasher.finalize());
+    Scalar::from_bytes_mod_order_wide(&wide)
+}
+
+fn lagrange_at_zero(index: u16, points: &[(u16, RistrettoPoint)]) -> Scalar {
+    let x_i = Scalar::from(u64::from(index));
+    points
+        .iter()
+        .filter(|(other, _)| *other != index)
+        .fold(Scalar::ONE, |acc, (other, _)| {
+            let x_j = Scalar::from(u64::from(*other));
+            acc * x_j * (x_j - x_i).invert()
+        })
+}
+
+fn decompress(bytes: &[u8]) -> Option<RistrettoPoint> {
+    CompressedRistretto::from_slice(bytes).ok()?.decompress()
+}
@@ -3,53 +3,78 @@ use std::path::PathBuf;
 xflags::xflags! {
     src "src/flags.rs"
 
-    /// Runs a substrate node.
-    cmd run {
-        /// Overrides the base path of the node. If not set,
-        /// a temporary directory will be used.
-        optional -p, --path path: PathBuf
-
-        /// Initiates the node with the Alice account.
-        /// The Alice node listens on TCP 30341 and RPC 9951.
-        optional --alice
-        /// Initiates the node with the Bob account.
-        /// The Bob node listens on TCP 30342 and RPC 9952.
-        optional --bob
-
-        /// If set, the node will be set to 0 Out/In peers.
-        optional --isolated
-
-        /// Overrides the default node name.
-        optional --node-name node_name: String
-        /// Overrides the default node key.
-        optional --node-key node_key: String
-        /// Overrides whether this node should run as a validator.
-        optional --node-validator node_validator: bool
-
-        /// Overrides the default TCP port.
-        optional --tcp-port tcp_port: u16
-        /// Overrides the default RPC port.
-        optional --rpc-port rpc_port: u16
-        /// Overrides the default bootnode list.
-        repeated --bootnodes bootnodes: String
-
-        /// Starts a local node based on the given chain spec.
-        cmd local {
-            /// The chain spec file. If missing, it will search
-            /// for a `spec.json` file on the current directory.
-            optional -c, --chain-spec chain_spec: PathBuf
-
-            /// The account SURI. The pattern is: `<mnemonic>//<seed>`.
-            optional --account-suri account_suri: String
+    cmd xtask {
+        /// Runs a substrate node.
+        cmd run {
+            /// Overrides the base path of the node. If not set,
+            /// a temporary directory will be used.
+            optional -p, --path path: PathBuf
+
+            /// Initiates the node with the Alice account.
+            /// The Alice node listens on TCP 30341 and RPC 9951.
+            optional --alice
+            /// Initiates the node with the Bob account.
+            /// The Bob node listens on TCP 30342 and RPC 9952.
+            optional --bob
+
+            /// If set, the node will be set to 0 Out/In peers.
+            optional --isolated
+
+            /// Overrides the default node name.
+            optional --node-name node_name: String
+            /// Overrides the default node key.
+            optional --node-key node_key: String
+            /// Overrides whether this node should run as a validator.
+            optional --node-validator node_validator: bool
+
+            /// Overrides the default TCP port.
+            optional --tcp-port tcp_port: u16
+            /// Overrides the default RPC port.
+            optional --rpc-port rpc_port: u16
+            /// Overrides the default bootnode list.
+            repeated --bootnodes bootnodes: String
+
+            /// Starts a local node based on the given chain spec.
+            cmd local {
+                /// The chain spec file. If missing, it will search
+                /// for a `spec.json` file on the current directory.
+                optional -c, --chain-spec chain_spec: PathBuf
+
+                /// The account SURI. The pattern is: `<mnemonic>//<seed>`.
+                optional --account-suri account_suri: String
+            }
+
+            /// Runs a mainnet replica with the latest state.
+            cmd replica {
+                /// Also write the generated spec file to disk.
+                optional -o, --output output: PathBuf
+
+                /// The Sudo address to use. SS58 Address.
+                optional --sudo sudo: String
+            }
         }
 
-        /// Runs a mainnet replica with the latest state.
-        cmd replica {
-            /// Also write the generated spec file to disk.
-            optional -o, --output output: PathBuf
-
-            /// The Sudo address to use. SS58 Address.
-            optional --sudo sudo: String
+        /// Replays the offchain worker of a decryption node against exported chain state and
+        /// reports when and why it would have sent the decrypted weights.
+        cmd offworker-replay {
+            /// The decryption key of the node: a PKCS#1 PEM RSA key, a hex encoded X25519 secret
+            /// or a committee key share.
+            required -k, --key key: PathBuf
+            /// The decryption node to replay. SS58 Address.
+            required --node node: String
+
+            /// State snapshots, as created by `try-runtime create-snapshot`, replayed in order.
+            repeated -s, --snapshot snapshot: PathBuf
+
+            /// The RPC endpoint of the chain to replay a block range from.
+            optional --uri uri: String
+            /// The first block of the range.
+            optional --from from: u64
+            /// The last block of the range. Defaults to `--from`.
+            optional --to to: u64
+
+            /// Only replays the given subnets.
+            repeated --subnet subnet: u16
         }
     }
 }
@@ -57,6 +82,17 @@ xflags::xflags! {
 // generated start
 // The following code is generated by `xflags` macro.
 // Run `env UPDATE_XFLAGS=1 cargo build` to regenerate.
+#[derive(Debug)]
+pub struct Xtask {
+    pub subcommand: XtaskCmd,
+}
+
+#[derive(Debug)]
+pub enum XtaskCmd {
+    Run(Run),
+    OffworkerReplay(OffworkerReplay),
+}
+
 #[derive(Debug)]
 pub struct Run {
     pub path: Option<PathBuf>,
@@ -90,7 +126,18 @@ pub struct Replica {
     pub sudo: Option<String>,
 }
 
-impl Run {
+#[derive(Debug)]
+pub struct OffworkerReplay {
+    pub key: PathBuf,
+    pub node: String,
+    pub snapshot: Vec<PathBuf>,
+    pub uri: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub subnet: Vec<u16>,
+}
+
+impl Xtask {
     #[allow(dead_code)]
     pub fn from_env_or_exit() -> Self {
         Self::from_env_or_exit_()
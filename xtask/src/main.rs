@@ -2,11 +2,15 @@ use std::{borrow::Cow, net::IpAddr};
 
 mod flags;
 mod mainnet_spec;
+mod offworker_replay;
 mod run;
 
 fn main() {
-    let flags = flags::Run::from_env_or_exit();
-    run::run(flags);
+    let flags = flags::Xtask::from_env_or_exit();
+    match flags.subcommand {
+        flags::XtaskCmd::Run(r) => run::run(r),
+        flags::XtaskCmd::OffworkerReplay(r) => offworker_replay::offworker_replay(r),
+    }
 }
 
 #[derive(Clone)]
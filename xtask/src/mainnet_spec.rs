@@ -1,6 +1,5 @@
 use std::{
     collections::{BTreeMap, HashMap},
-    io::Cursor,
     path::{Path, PathBuf},
 };
 
@@ -51,26 +50,21 @@ fn sudo(genesis: &mut Value, sudo: Option<&String>) {
                 .expect("invalid SS58 sudo address")
                 .0
         })
-        .unwrap_or(KEYS[0]);
+        .unwrap_or(ALICE);
 
     genesis[&key] = Value::String(format!("0x{}", hex::encode(sudo)));
 }
 
-const KEYS: &[[u8; 32]] = &[
-    // Alice
-    hex_literal::hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d"),
-    // Bob
-    hex_literal::hex!("8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48"),
-];
+const ALICE: [u8; 32] =
+    hex_literal::hex!("d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d");
+const BOB: [u8; 32] =
+    hex_literal::hex!("8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48");
+const KEYS: &[[u8; 32]] = &[ALICE, BOB];
 
 fn aura(genesis: &mut Value) {
     let key = key_name(b"Aura", b"Authorities");
 
-    let mut buf = Cursor::new(vec![0; KEYS.size_hint()]);
-    KEYS.encode_to(&mut buf);
-
-    let buf = &buf.get_ref()[..buf.position() as usize];
-    genesis[&key] = Value::String(format!("0x{}", hex::encode(buf)));
+    genesis[&key] = Value::String(format!("0x{}", hex::encode(KEYS.encode())));
 }
 
 fn grandpa(genesis: &mut Value) {
@@ -87,11 +81,7 @@ fn grandpa(genesis: &mut Value) {
     let key = key_name(b"Grandpa", b"Authorities");
     dbg!(&key);
 
-    let mut buf = Cursor::new(vec![0; grandpa.size_hint()]);
-    grandpa.encode_to(&mut buf);
-
-    let buf = &buf.get_ref()[..buf.position() as usize];
-    genesis[&key] = Value::String(format!("0x{}", hex::encode(buf)));
+    genesis[&key] = Value::String(format!("0x{}", hex::encode(grandpa.encode())));
 }
 
 // fn balance(genesis: &mut Value, sudo: Option<&String>) {
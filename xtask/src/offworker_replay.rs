@@ -0,0 +1,229 @@
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use frame_remote_externalities::{
+    Builder, Mode, OfflineConfig, OnlineConfig, RemoteExternalities, SnapshotConfig, Transport,
+};
+use node_subspace_runtime::{AccountId, Block, Runtime};
+use ow_extensions::{
+    envelope::{self, DecryptionKey},
+    DecryptedWeights, EncryptionKey, OffworkerExt,
+};
+use pallet_offworker::types::{SendReason, SubnetDecision};
+use pallet_subnet_emission::DecryptionNodeBanQueue;
+use sp_core::{
+    crypto::Ss58Codec,
+    offchain::{testing::TestOffchainExt, OffchainDbExt, OffchainWorkerExt},
+    H256,
+};
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+
+use super::*;
+
+type Offworker = pallet_offworker::Pallet<Runtime>;
+type SubnetEmission = pallet_subnet_emission::Pallet<Runtime>;
+
+/// Pallets read by the offchain worker, the only ones fetched for each block of a range.
+const PALLETS: &[&str] = &[
+    "SubspaceModule",
+    "GovernanceModule",
+    "SubnetEmissionModule",
+    "Offworker",
+];
+
+pub(super) fn offworker_replay(flags: flags::OffworkerReplay) {
+    let key = std::fs::read_to_string(&flags.key).expect("failed to read the decryption key");
+    let key = DecryptionKey::parse(&key).expect("unsupported decryption key");
+    let node = AccountId::from_ss58check(&flags.node).expect("invalid SS58 node address");
+
+    let runtime = tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap();
+
+    let replay = Replay {
+        key,
+        node,
+        subnets: flags.subnet,
+        // Like the node's offchain database, it outlives the blocks, so the subnet states and
+        // caches of a block are seen by the next one
+        offchain: TestOffchainExt::new().0,
+        decryptions: Default::default(),
+    };
+
+    if !flags.snapshot.is_empty() {
+        for snapshot in flags.snapshot {
+            replay.block(runtime.block_on(offline(snapshot)));
+        }
+        return;
+    }
+
+    let uri = flags.uri.expect("either --snapshot or --uri is required");
+    let from = flags.from.expect("--from is required with --uri");
+    let to = flags.to.unwrap_or(from);
+
+    let client = runtime.block_on(ws_client(&uri)).unwrap();
+    for number in from..=to {
+        let hash: Option<H256> = runtime
+            .block_on(client.request("chain_getBlockHash", rpc_params![number]))
+            .unwrap();
+        let hash = hash.unwrap_or_else(|| panic!("block {number} not found"));
+
+        replay.block(runtime.block_on(online(&uri, hash)));
+    }
+}
+
+async fn offline(snapshot: PathBuf) -> RemoteExternalities<Block> {
+    Builder::<Block>::new()
+        .mode(Mode::Offline(OfflineConfig {
+            state_snapshot: SnapshotConfig::new(snapshot),
+        }))
+        .build()
+        .await
+        .unwrap()
+}
+
+async fn online(uri: &str, at: H256) -> RemoteExternalities<Block> {
+    let block_number_key = [
+        sp_crypto_hashing::twox_128(b"System"),
+        sp_crypto_hashing::twox_128(b"Number"),
+    ]
+    .concat();
+
+    Builder::<Block>::new()
+        .mode(Mode::Online(OnlineConfig {
+            at: Some(at),
+            state_snapshot: None,
+            pallets: PALLETS.iter().map(|pallet| pallet.to_string()).collect(),
+            transport: Transport::Uri(uri.to_string()),
+            child_trie: false,
+            hashed_prefixes: vec![],
+            hashed_keys: vec![block_number_key],
+        }))
+        .build()
+        .await
+        .unwrap()
+}
+
+#[derive(Default)]
+struct Decryptions {
+    attempted: u32,
+    failed: u32,
+}
+
+struct Replay {
+    key: DecryptionKey,
+    node: AccountId,
+    subnets: Vec<u16>,
+    offchain: TestOffchainExt,
+    decryptions: Arc<Mutex<Decryptions>>,
+}
+
+impl Replay {
+    fn block(&self, mut ext: RemoteExternalities<Block>) {
+        ext.register_extension(OffworkerExt::new(ReplayDecrypter {
+            key: self.key.clone(),
+            decryptions: self.decryptions.clone(),
+        }));
+        ext.register_extension(OffchainDbExt::new(self.offchain.clone()));
+        ext.register_extension(OffchainWorkerExt::new(self.offchain.clone()));
+
+        ext.execute_with(|| self.replay_block());
+    }
+
+    /// Mirrors the offchain worker hook, without submitting anything.
+    fn replay_block(&self) {
+        let block = frame_system::Pallet::<Runtime>::block_number();
+        let (valid_subnets, hanging_subnets) = SubnetEmission::get_valid_subnets(Some(&self.node));
+
+        hanging_subnets.iter().for_each(Offworker::delete_subnet_state);
+
+        let subnets: Vec<_> = valid_subnets
+            .into_iter()
+            .filter(|subnet_id| self.subnets.is_empty() || self.subnets.contains(subnet_id))
+            .collect();
+        println!("block {block}: subnets {subnets:?}");
+
+        for subnet_id in subnets {
+            if DecryptionNodeBanQueue::<Runtime>::contains_key(subnet_id, &self.node) {
+                println!(
+                    "  subnet {subnet_id}: sends the weights early, the node is queued for a ban"
+                );
+                continue;
+            }
+
+            *self.decryptions.lock().unwrap_or_else(PoisonError::into_inner) = Default::default();
+
+            let decision = Offworker::process_subnet(subnet_id, &self.node, block);
+            match decision {
+                SubnetDecision::Deregistered => {
+                    println!("  subnet {subnet_id}: deregistered since its weights were encrypted");
+                    Offworker::delete_subnet_state(&subnet_id);
+                }
+                SubnetDecision::UpToDate => {
+                    println!("  subnet {subnet_id}: no new epochs to simulate");
+                }
+                SubnetDecision::AwaitingShares => {
+                    println!("  subnet {subnet_id}: waits for the committee's decryption shares");
+                }
+                SubnetDecision::Hold { delta } => {
+                    println!(
+                        "  subnet {subnet_id}: holds the weights, copying is still profitable \
+                         (delta {delta})"
+                    );
+                }
+                SubnetDecision::Send { delta, reason } => {
+                    let reason = match reason {
+                        SendReason::CopyingIrrational => {
+                            format!("copying is irrational (delta {delta})")
+                        }
+                        SendReason::EncryptionPeriodOver => "the encryption period is over".into(),
+                        SendReason::Rotation => "the node rotates away from the subnet".into(),
+                    };
+                    println!("  subnet {subnet_id}: sends the weights, {reason}");
+                    // the worker starts over once the weights are sent
+                    Offworker::delete_subnet_state(&subnet_id);
+                }
+            }
+
+            let decryptions = self.decryptions.lock().unwrap_or_else(PoisonError::into_inner);
+            if decryptions.failed > 0 {
+                println!(
+                    "    {} of {} weights could not be decrypted with the supplied key",
+                    decryptions.failed, decryptions.attempted
+                );
+            }
+        }
+    }
+}
+
+/// Decrypts with the supplied key instead of the node keystore.
+struct ReplayDecrypter {
+    key: DecryptionKey,
+    decryptions: Arc<Mutex<Decryptions>>,
+}
+
+impl ow_extensions::OffworkerExtension for ReplayDecrypter {
+    fn decrypt_weight(&self, encrypted: Vec<u8>) -> Option<DecryptedWeights> {
+        let decrypted = self
+            .key
+            .open(&encrypted)
+            .ok()
+            .and_then(|decrypted| envelope::decode_weights(&decrypted));
+
+        let mut decryptions = self.decryptions.lock().unwrap_or_else(PoisonError::into_inner);
+        decryptions.attempted = decryptions.attempted.saturating_add(1);
+        if decrypted.is_none() {
+            decryptions.failed = decryptions.failed.saturating_add(1);
+        }
+
+        decrypted
+    }
+
+    fn is_decryption_node(&self) -> bool {
+        true
+    }
+
+    fn get_encryption_key(&self) -> Option<EncryptionKey> {
+        Some(self.key.public_key())
+    }
+}